@@ -169,6 +169,16 @@ impl Into<originsrv::OriginPackageIdent> for PackageIdent {
     }
 }
 
+#[derive(Clone, Deserialize)]
+pub struct PackageVersion {
+    pub origin: String,
+    pub name: String,
+    pub version: String,
+    #[serde(with = "json_u64")]
+    pub release_count: u64,
+    pub latest: String,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct PackageResults<T> {
     pub range_start: isize,
@@ -414,6 +424,27 @@ impl Client {
         Ok(package)
     }
 
+    /// Returns the versions of a package known to the Depot, in no particular order.
+    ///
+    /// # Failures
+    ///
+    /// * Package cannot be found
+    /// * Remote Depot is not available
+    pub fn show_package_versions<I>(&self, package: &I) -> Result<Vec<PackageVersion>>
+        where I: Identifiable
+    {
+        let mut res = self.0.get(&package_versions_path(package)).send()?;
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        debug!("Body: {:?}", encoded);
+        let versions = try!(serde_json::from_str::<Vec<PackageVersion>>(&encoded));
+        Ok(versions)
+    }
+
     /// Upload a package to a remote Depot.
     ///
     /// # Failures
@@ -613,6 +644,12 @@ fn package_path<I>(package: &I) -> String
     format!("pkgs/{}", package)
 }
 
+fn package_versions_path<I>(package: &I) -> String
+    where I: Identifiable
+{
+    format!("pkgs/{}/{}/versions", package.origin(), package.name())
+}
+
 fn package_search(term: &str) -> String {
     format!("pkgs/search/{}", term)
 }
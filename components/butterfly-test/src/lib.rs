@@ -27,6 +27,7 @@ use std::str::FromStr;
 
 use time::SteadyTime;
 
+use habitat_butterfly::auth::Auth;
 use habitat_butterfly::server::{Server, Suitability};
 use habitat_butterfly::member::{Member, Health};
 use habitat_butterfly::server::timing::Timing;
@@ -34,8 +35,10 @@ use habitat_butterfly::rumor::service::{Service, SysInfo};
 use habitat_butterfly::rumor::service_config::ServiceConfig;
 use habitat_butterfly::rumor::service_file::ServiceFile;
 use habitat_butterfly::message::swim::Election_Status;
+use habitat_butterfly::ring_keys::RingKeys;
 use habitat_core::service::ServiceGroup;
 use habitat_core::package::{Identifiable, PackageIdent};
+use habitat_core::crypto::{MemberKey, SigKeyPair};
 use habitat_core::crypto::keys::sym_key::SymKey;
 use habitat_butterfly::trace::Trace;
 
@@ -50,6 +53,15 @@ impl Suitability for NSuitability {
 }
 
 pub fn start_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> Server {
+    start_server_with_ring_ca(name, ring_key, None, suitability)
+}
+
+/// Starts a server which authenticates its messages with a member key issued by the ring CA.
+pub fn start_server_with_ring_ca(name: &str,
+                                 ring_key: Option<SymKey>,
+                                 ring_ca: Option<&SigKeyPair>,
+                                 suitability: u64)
+                                 -> Server {
     SERVER_PORT.compare_and_swap(0, 6666, Ordering::Relaxed);
     let swim_port = SERVER_PORT.fetch_add(1, Ordering::Relaxed);
     let gossip_port = SERVER_PORT.fetch_add(1, Ordering::Relaxed);
@@ -58,11 +70,17 @@ pub fn start_server(name: &str, ring_key: Option<SymKey>, suitability: u64) -> S
     let mut member = Member::default();
     member.set_swim_port(swim_port as i32);
     member.set_gossip_port(gossip_port as i32);
+    let auth = ring_ca.map(|ca| {
+        let member_key = MemberKey::generate(member.get_id(), ca)
+            .expect("Cannot issue a member key");
+        Auth::new(member_key, vec![ca.clone()]).expect("Cannot authenticate with the ring CA")
+    });
     let mut server = Server::new(&listen_swim[..],
                                  &listen_gossip[..],
                                  member,
                                  Trace::default(),
-                                 ring_key,
+                                 RingKeys::from(ring_key),
+                                 auth,
                                  Some(String::from(name)),
                                  None::<PathBuf>,
                                  Box::new(NSuitability(suitability)))
@@ -128,6 +146,22 @@ impl SwimNet {
         SwimNet { members: members }
     }
 
+    pub fn new_ring_ca(count: usize, ring_ca: &SigKeyPair) -> SwimNet {
+        SwimNet::new_ring_encryption_and_ca(count, None, ring_ca)
+    }
+
+    pub fn new_ring_encryption_and_ca(count: usize,
+                                      ring_key: Option<SymKey>,
+                                      ring_ca: &SigKeyPair)
+                                      -> SwimNet {
+        let mut members = Vec::with_capacity(count);
+        for x in 0..count {
+            let rk = ring_key.clone();
+            members.push(start_server_with_ring_ca(&format!("{}", x), rk, Some(ring_ca), 0));
+        }
+        SwimNet { members: members }
+    }
+
     pub fn connect(&mut self, from_entry: usize, to_entry: usize) {
         let to = member_from_server(&self.members[to_entry]);
         trace_it!(TEST: &self.members[from_entry], format!("Connected {} {}", self.members[to_entry].name(), self.members[to_entry].member_id()));
@@ -0,0 +1,368 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Authentication of the messages exchanged by the members of a ring.
+//!
+//! When a ring has a CA key, every member holds a member key issued by that CA. Each message a
+//! member puts on the wire carries a `MemberSignature`: its member ID, its public key, the CA's
+//! certificate of both, and its signature of the payload. Receivers check the certificate against
+//! every revision of the ring CA they know, so a host can only speak for the member ID its key
+//! was issued to.
+//!
+//! Rumors which are never changed once created (services, configuration, files, secrets, release
+//! blacklists, departures and ring keys) are also signed by the one who originated them, and keep
+//! that signature as they are gossiped around the ring. Configuration, files, secrets, departures
+//! and ring keys change the whole ring, so they must be signed by an operator key: a key the ring
+//! CA issued to a person rather than to a Supervisor, and marked as such in its certificate.
+//!
+//! Membership rumors are rewritten by every member as it receives them, and are authenticated by
+//! the signature of the member which sent them. Elections are rewritten too, but the parts that
+//! decide who leads are signed: the candidate signs its candidacy, and signs it again once it has
+//! won, and every member signs its own vote. A member can't vote on behalf of another, nor declare
+//! another member the winner.
+
+use std::fmt;
+
+use habitat_core::crypto::{MemberKey, SigKeyPair};
+use habitat_core::crypto::keys::member_key::verify_member_signature;
+use protobuf::{Message, RepeatedField};
+
+use error::{Error, Result};
+use message::swim::{Election_Status, MemberSignature, Rumor};
+
+/// The member key of this server, along with the ring CA keys used to verify everyone else's.
+#[derive(Clone)]
+pub struct Auth {
+    member_key: MemberKey,
+    ring_ca: Vec<SigKeyPair>,
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ring_ca: Vec<String> = self.ring_ca.iter().map(|k| k.name_with_rev()).collect();
+        write!(f,
+               "Auth {{ member_key: {:?}, ring_ca: {:?} }}",
+               self.member_key,
+               ring_ca)
+    }
+}
+
+impl Auth {
+    /// Create a new Auth. Fails if the member key was not issued by any of the given ring CA
+    /// keys, or if it is an operator key.
+    pub fn new(member_key: MemberKey, ring_ca: Vec<SigKeyPair>) -> Result<Auth> {
+        if member_key.operator {
+            return Err(Error::Unauthenticated(format!("{} is an operator key, not a member key",
+                                                      member_key.member_id)));
+        }
+        let auth = Auth {
+            member_key: member_key,
+            ring_ca: ring_ca,
+        };
+        let signature = sign(&auth.member_key, auth.member_key.public_key());
+        try!(auth.verify(auth.member_key.public_key(), &signature));
+        Ok(auth)
+    }
+
+    /// The member ID the member key was issued to.
+    pub fn member_id(&self) -> &str {
+        &self.member_key.member_id
+    }
+
+    /// The member key of this server.
+    pub fn member_key(&self) -> &MemberKey {
+        &self.member_key
+    }
+
+    /// Verifies the signature of the given data, returning an error if it was not made by a
+    /// member key issued by the ring CA.
+    pub fn verify(&self, data: &[u8], signature: &MemberSignature) -> Result<()> {
+        verify_member_signature(&self.ring_ca,
+                                signature.get_member_id(),
+                                signature.get_operator(),
+                                signature.get_public_key(),
+                                signature.get_certificate(),
+                                data,
+                                signature.get_signature())
+                .map_err(|e| Error::Unauthenticated(format!("{}", e)))
+    }
+
+    /// Verifies the origin signature of a rumor, returning the ID of the member which signed it.
+    pub fn verify_rumor<'a>(&self, rumor: &'a Rumor) -> Result<&'a str> {
+        if !rumor.has_signature() {
+            return Err(Error::Unauthenticated(format!("{:?} rumor from {} is not signed",
+                                                      rumor.get_field_type(),
+                                                      rumor.get_from_id())));
+        }
+        let mut unsigned = rumor.clone();
+        unsigned.clear_signature();
+        let bytes = try!(unsigned.write_to_bytes());
+        try!(self.verify(&bytes, rumor.get_signature()));
+        Ok(rumor.get_signature().get_member_id())
+    }
+
+    /// Verifies that a rumor was signed by an operator key, returning the name of the operator.
+    pub fn verify_operator_rumor<'a>(&self, rumor: &'a Rumor) -> Result<&'a str> {
+        let signer = try!(self.verify_rumor(rumor));
+        if !rumor.get_signature().get_operator() {
+            return Err(Error::Unauthenticated(format!("{:?} rumor was signed by {}, which is \
+                                                       not an operator",
+                                                      rumor.get_field_type(),
+                                                      signer)));
+        }
+        Ok(signer)
+    }
+
+    /// Verifies an election rumor: the candidacy must be signed by the candidate, and every vote
+    /// by the member which cast it.
+    pub fn verify_election(&self, rumor: &Rumor) -> Result<()> {
+        let election = rumor.get_election();
+        if !election.has_candidate_signature() {
+            return Err(Error::Unauthenticated(format!("election of {} in {} is not signed",
+                                                      election.get_member_id(),
+                                                      election.get_service_group())));
+        }
+        let signature = election.get_candidate_signature();
+        if signature.get_member_id() != election.get_member_id() {
+            return Err(Error::Unauthenticated(format!("candidacy of {} in {} was signed by {}",
+                                                      election.get_member_id(),
+                                                      election.get_service_group(),
+                                                      signature.get_member_id())));
+        }
+        try!(self.verify(candidacy(rumor).as_bytes(), signature));
+        for vote in election.get_votes() {
+            let signature = match election
+                      .get_vote_signatures()
+                      .iter()
+                      .find(|s| s.get_member_id() == *vote) {
+                Some(signature) => signature,
+                None => {
+                    return Err(Error::Unauthenticated(format!("vote of {} in {} is not signed",
+                                                              vote,
+                                                              election.get_service_group())))
+                }
+            };
+            try!(self.verify(ballot(rumor, vote).as_bytes(), signature));
+        }
+        Ok(())
+    }
+}
+
+/// Signs the given data with a member key.
+pub fn sign(member_key: &MemberKey, data: &[u8]) -> MemberSignature {
+    let mut signature = MemberSignature::new();
+    signature.set_member_id(member_key.member_id.clone());
+    signature.set_public_key(member_key.public_key().to_vec());
+    signature.set_certificate(member_key.certificate().to_vec());
+    signature.set_signature(member_key.sign(data));
+    signature.set_operator(member_key.operator);
+    signature
+}
+
+/// Signs a rumor with a member key, replacing any signature it already had. The signature covers
+/// the whole rumor, serialized without a signature.
+pub fn sign_rumor(member_key: &MemberKey, rumor: &mut Rumor) -> Result<()> {
+    rumor.clear_signature();
+    let bytes = try!(rumor.write_to_bytes());
+    rumor.set_signature(sign(member_key, &bytes));
+    Ok(())
+}
+
+/// Signs an election rumor for the candidate holding the member key: its candidacy, which also
+/// covers whether it won, and its own vote.
+pub fn sign_election(member_key: &MemberKey, rumor: &mut Rumor) {
+    let candidate_signature = sign(member_key, candidacy(rumor).as_bytes());
+    let vote_signature = sign(member_key, ballot(rumor, &member_key.member_id).as_bytes());
+    let election = rumor.mut_election();
+    let mut vote_signatures = election.take_vote_signatures().into_vec();
+    vote_signatures.retain(|s| s.get_member_id() != member_key.member_id);
+    vote_signatures.push(vote_signature);
+    election.set_candidate_signature(candidate_signature);
+    election.set_vote_signatures(RepeatedField::from_vec(vote_signatures));
+}
+
+/// The part of an election the candidate signs.
+fn candidacy(rumor: &Rumor) -> String {
+    let election = rumor.get_election();
+    format!("{:?}\n{}\n{}\n{}\n{}\n{}",
+            rumor.get_field_type(),
+            election.get_service_group(),
+            election.get_term(),
+            election.get_member_id(),
+            election.get_suitability(),
+            election.get_status() == Election_Status::Finished)
+}
+
+/// The part of an election a voter signs.
+fn ballot(rumor: &Rumor, voter: &str) -> String {
+    let election = rumor.get_election();
+    format!("{:?}\n{}\n{}\n{}",
+            rumor.get_field_type(),
+            election.get_service_group(),
+            election.get_term(),
+            voter)
+}
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::{MemberKey, SigKeyPair};
+    use habitat_core::crypto::keys::member_key::ring_ca_name;
+    use habitat_core::service::ServiceGroup;
+
+    use message::swim::Rumor as ProtoRumor;
+    use rumor::election::Election;
+    use rumor::service_config::ServiceConfig;
+    use super::*;
+
+    fn ring_ca() -> SigKeyPair {
+        SigKeyPair::generate_in_memory(ring_ca_name("beyonce")).unwrap()
+    }
+
+    fn election(member_id: &str, suitability: u64) -> ProtoRumor {
+        Election::new(member_id,
+                      ServiceGroup::new("neurosis", "production", None).unwrap(),
+                      suitability)
+                .into()
+    }
+
+    fn service_config() -> ProtoRumor {
+        ServiceConfig::new("adam",
+                           ServiceGroup::new("neurosis", "production", None).unwrap(),
+                           "yep=1".as_bytes().to_vec())
+                .into()
+    }
+
+    #[test]
+    fn new_refuses_a_member_key_from_another_ca() {
+        let ca = ring_ca();
+        let other_ca = SigKeyPair::generate_in_memory(ring_ca_name("jayz")).unwrap();
+        let member_key = MemberKey::generate("adam", &other_ca).unwrap();
+        assert!(Auth::new(member_key, vec![ca]).is_err());
+    }
+
+    #[test]
+    fn new_refuses_an_operator_key() {
+        let ca = ring_ca();
+        let operator_key = MemberKey::generate_operator("scott", &ca).unwrap();
+        assert!(Auth::new(operator_key, vec![ca]).is_err());
+    }
+
+    #[test]
+    fn signed_rumors_verify() {
+        let ca = ring_ca();
+        let auth = Auth::new(MemberKey::generate("adam", &ca).unwrap(), vec![ca]).unwrap();
+        let mut rumor = service_config();
+        sign_rumor(auth.member_key(), &mut rumor).unwrap();
+        assert_eq!(auth.verify_rumor(&rumor).unwrap(), "adam");
+    }
+
+    #[test]
+    fn unsigned_rumors_do_not_verify() {
+        let ca = ring_ca();
+        let auth = Auth::new(MemberKey::generate("adam", &ca).unwrap(), vec![ca]).unwrap();
+        assert!(auth.verify_rumor(&service_config()).is_err());
+    }
+
+    #[test]
+    fn altered_rumors_do_not_verify() {
+        let ca = ring_ca();
+        let auth = Auth::new(MemberKey::generate("adam", &ca).unwrap(), vec![ca]).unwrap();
+        let mut rumor = service_config();
+        sign_rumor(auth.member_key(), &mut rumor).unwrap();
+        rumor.mut_service_config().set_incarnation(10);
+        assert!(auth.verify_rumor(&rumor).is_err());
+    }
+
+    #[test]
+    fn operator_rumors_verify() {
+        let ca = ring_ca();
+        let auth = Auth::new(MemberKey::generate("adam", &ca).unwrap(), vec![ca.clone()]).unwrap();
+        let mut rumor = service_config();
+        sign_rumor(&MemberKey::generate_operator("scott", &ca).unwrap(), &mut rumor).unwrap();
+        assert_eq!(auth.verify_operator_rumor(&rumor).unwrap(), "scott");
+    }
+
+    #[test]
+    fn rumors_signed_by_a_member_are_not_operator_rumors() {
+        let ca = ring_ca();
+        let auth = Auth::new(MemberKey::generate("adam", &ca).unwrap(), vec![ca]).unwrap();
+        let mut rumor = service_config();
+        sign_rumor(auth.member_key(), &mut rumor).unwrap();
+        assert!(auth.verify_operator_rumor(&rumor).is_err());
+    }
+
+    #[test]
+    fn rumors_claiming_an_operator_signature_do_not_verify() {
+        let ca = ring_ca();
+        let auth = Auth::new(MemberKey::generate("adam", &ca).unwrap(), vec![ca]).unwrap();
+        let mut rumor = service_config();
+        sign_rumor(auth.member_key(), &mut rumor).unwrap();
+        rumor.mut_signature().set_operator(true);
+        assert!(auth.verify_operator_rumor(&rumor).is_err());
+    }
+
+    #[test]
+    fn signed_elections_verify() {
+        let ca = ring_ca();
+        let adam = MemberKey::generate("adam", &ca).unwrap();
+        let auth = Auth::new(MemberKey::generate("tom", &ca).unwrap(), vec![ca]).unwrap();
+        let mut rumor = election("adam", 1);
+        sign_election(&adam, &mut rumor);
+        let mut tom = election("tom", 0);
+        sign_election(auth.member_key(), &mut tom);
+        let mut winner = Election::from(rumor);
+        winner.steal_votes(&mut Election::from(tom));
+        assert_eq!(winner.get_votes().len(), 2);
+        let rumor: ProtoRumor = winner.into();
+        assert!(auth.verify_election(&rumor).is_ok());
+    }
+
+    #[test]
+    fn unsigned_elections_do_not_verify() {
+        let ca = ring_ca();
+        let auth = Auth::new(MemberKey::generate("adam", &ca).unwrap(), vec![ca]).unwrap();
+        assert!(auth.verify_election(&election("adam", 0)).is_err());
+    }
+
+    #[test]
+    fn elections_signed_by_another_member_do_not_verify() {
+        let ca = ring_ca();
+        let auth = Auth::new(MemberKey::generate("adam", &ca).unwrap(), vec![ca]).unwrap();
+        let mut rumor = election("tom", 0);
+        sign_election(auth.member_key(), &mut rumor);
+        assert!(auth.verify_election(&rumor).is_err());
+    }
+
+    #[test]
+    fn elections_with_unsigned_votes_do_not_verify() {
+        let ca = ring_ca();
+        let auth = Auth::new(MemberKey::generate("adam", &ca).unwrap(), vec![ca]).unwrap();
+        let mut rumor = election("adam", 0);
+        sign_election(auth.member_key(), &mut rumor);
+        rumor.mut_election().mut_votes().push(String::from("tom"));
+        assert!(auth.verify_election(&rumor).is_err());
+    }
+
+    #[test]
+    fn elections_finished_without_the_winner_do_not_verify() {
+        let ca = ring_ca();
+        let auth = Auth::new(MemberKey::generate("adam", &ca).unwrap(), vec![ca]).unwrap();
+        let mut rumor = election("adam", 0);
+        sign_election(auth.member_key(), &mut rumor);
+        rumor.mut_election().set_status(Election_Status::Finished);
+        assert!(auth.verify_election(&rumor).is_err());
+        sign_election(auth.member_key(), &mut rumor);
+        assert!(auth.verify_election(&rumor).is_ok());
+    }
+}
@@ -16,26 +16,39 @@
 //!
 //! This will connect to a given butterfly members `Pull` thread, and inject a rumor.
 
-use habitat_core::crypto::SymKey;
+use habitat_core::crypto::{MemberKey, SymKey};
 use habitat_core::service::ServiceGroup;
+use protobuf::Message;
 use zmq;
 
 use ZMQ_CONTEXT;
+use auth;
 use message;
+use message::swim::Rumor as ProtoRumor;
 use rumor::Rumor;
+use rumor::departure::Departure;
+use rumor::ring_key::RingKey;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
+use rumor::service_secret::ServiceSecret;
 use error::{Result, Error};
 
-/// Holds a ZMQ Push socket, and an optional ring encryption key.
+/// Holds a ZMQ Push socket, an optional ring encryption key, and an optional member key to sign
+/// rumors with.
 pub struct Client {
     socket: zmq::Socket,
     ring_key: Option<SymKey>,
+    member_key: Option<MemberKey>,
 }
 
 impl Client {
-    /// Connect this client to the address, and optionally encrypt the traffic.
-    pub fn new<A: ToString>(addr: A, ring_key: Option<SymKey>) -> Result<Client> {
+    /// Connect this client to the address, and optionally encrypt the traffic. Rings which
+    /// authenticate their members only accept rumors signed with a member key issued by their
+    /// ring CA.
+    pub fn new<A: ToString>(addr: A,
+                            ring_key: Option<SymKey>,
+                            member_key: Option<MemberKey>)
+                            -> Result<Client> {
         let socket = (**ZMQ_CONTEXT)
             .as_mut()
             .socket(zmq::PUSH)
@@ -60,6 +73,7 @@ impl Client {
         Ok(Client {
                socket: socket,
                ring_key: ring_key,
+               member_key: member_key,
            })
     }
 
@@ -90,10 +104,48 @@ impl Client {
         self.send(sf)
     }
 
-    /// Send any `Rumor` to the server.
-    pub fn send<T: Rumor>(&mut self, rumor: T) -> Result<()> {
-        let bytes = try!(rumor.write_to_bytes());
-        let wire_msg = try!(message::generate_wire(bytes, &self.ring_key));
+    /// Create a service secret and send it to the server. The value must already be encrypted to
+    /// the service group's key.
+    pub fn send_service_secret<S: Into<String>>(&mut self,
+                                                service_group: ServiceGroup,
+                                                name: S,
+                                                incarnation: u64,
+                                                encrypted_value: Vec<u8>)
+                                                -> Result<()> {
+        let mut ss = ServiceSecret::new("butterflyclient", service_group, name, encrypted_value);
+        ss.set_incarnation(incarnation);
+        self.send(ss)
+    }
+
+    /// Create a departure for the given member and send it to the server.
+    pub fn send_departure<S: Into<String>>(&mut self, member_id: S) -> Result<()> {
+        self.send(Departure::new(member_id))
+    }
+
+    /// Hand a revision of the ring key to the server, to spread to the whole ring. Members start
+    /// encrypting with it once it is sent again as the primary key.
+    pub fn send_ring_key(&mut self, ring_key: &SymKey, primary: bool) -> Result<()> {
+        self.send(try!(RingKey::new("butterflyclient", ring_key, primary)))
+    }
+
+    /// Have the server encrypt with a revision of the ring key, and the whole ring stop
+    /// decrypting with every older revision.
+    pub fn retire_ring_keys(&mut self, ring_key: &SymKey) -> Result<()> {
+        self.send(try!(RingKey::retirement("butterflyclient", ring_key)))
+    }
+
+    /// Send any `Rumor` to the server, signed with the member key if there is one.
+    pub fn send<T>(&mut self, rumor: T) -> Result<()>
+        where T: Rumor + Into<ProtoRumor>
+    {
+        let mut proto: ProtoRumor = rumor.into();
+        if let Some(ref member_key) = self.member_key {
+            try!(auth::sign_rumor(member_key, &mut proto));
+        }
+        let bytes = try!(proto.write_to_bytes());
+        let wire_msg = try!(message::generate_wire(bytes,
+                                                   self.ring_key.as_ref(),
+                                                   self.member_key.as_ref()));
         self.socket
             .send(&wire_msg, 0)
             .map_err(Error::ZmqSendError)
@@ -58,10 +58,12 @@ extern crate zmq;
 
 #[macro_use]
 pub mod trace;
+pub mod auth;
 pub mod client;
 pub mod error;
 pub mod member;
 pub mod message;
+pub mod ring_keys;
 pub mod rumor;
 pub mod server;
 
@@ -24,6 +24,7 @@ use std::net::SocketAddr;
 use std::path::PathBuf;
 
 use habitat_butterfly::{server, member, trace};
+use habitat_butterfly::ring_keys::RingKeys;
 use habitat_butterfly::server::Suitability;
 use habitat_core::service::ServiceGroup;
 
@@ -57,6 +58,7 @@ fn main() {
                                          gossip_bind_addr,
                                          member,
                                          trace::Trace::default(),
+                                         RingKeys::default(),
                                          None,
                                          None,
                                          None::<PathBuf>,
@@ -14,7 +14,7 @@
 
 //! Tracks membership. Contains both the `Member` struct and the `MemberList`.
 
-use std::collections::{hash_map, HashMap};
+use std::collections::{hash_map, HashMap, HashSet};
 use std::fmt;
 use std::iter::IntoIterator;
 use std::net::SocketAddr;
@@ -39,12 +39,14 @@ use rumor::RumorKey;
 /// How many nodes do we target when we need to run PingReq.
 const PINGREQ_TARGETS: usize = 5;
 
-/// The health of a node.
+/// The health of a node. `Departed` is final: a member which was departed from the ring never
+/// becomes alive again, whatever its incarnation.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum Health {
     Alive,
     Suspect,
     Confirmed,
+    Departed,
 }
 
 impl Default for Health {
@@ -61,6 +63,7 @@ impl FromStr for Health {
             "alive" => Ok(Health::Alive),
             "suspect" => Ok(Health::Suspect),
             "confirmed" => Ok(Health::Confirmed),
+            "departed" => Ok(Health::Departed),
             _ => Ok(Health::Alive),
         }
     }
@@ -79,6 +82,7 @@ impl From<ProtoMembership_Health> for Health {
             ProtoMembership_Health::ALIVE => Health::Alive,
             ProtoMembership_Health::SUSPECT => Health::Suspect,
             ProtoMembership_Health::CONFIRMED => Health::Confirmed,
+            ProtoMembership_Health::DEPARTED => Health::Departed,
         }
     }
 }
@@ -89,6 +93,7 @@ impl From<Health> for ProtoMembership_Health {
             Health::Alive => ProtoMembership_Health::ALIVE,
             Health::Suspect => ProtoMembership_Health::SUSPECT,
             Health::Confirmed => ProtoMembership_Health::CONFIRMED,
+            Health::Departed => ProtoMembership_Health::DEPARTED,
         }
     }
 }
@@ -99,6 +104,7 @@ impl<'a> From<&'a Health> for ProtoMembership_Health {
             &Health::Alive => ProtoMembership_Health::ALIVE,
             &Health::Suspect => ProtoMembership_Health::SUSPECT,
             &Health::Confirmed => ProtoMembership_Health::CONFIRMED,
+            &Health::Departed => ProtoMembership_Health::DEPARTED,
         }
     }
 }
@@ -110,6 +116,7 @@ impl fmt::Display for Health {
             Health::Alive => "alive",
             Health::Suspect => "suspect",
             Health::Confirmed => "confirmed",
+            Health::Departed => "departed",
         };
         write!(f, "{}", value)
     }
@@ -196,12 +203,41 @@ impl<'a> From<&'a &'a Member> for RumorKey {
 // This is a Uuid type turned to a string
 pub type UuidSimple = String;
 
-/// Tracks lists of members, their health, and how long they have been suspect.
+/// The suspicion of a member: since when it has been suspect, at which incarnation, which member
+/// raised the suspicion we spread, and which other members have raised a suspicion of it at that
+/// incarnation since.
+#[derive(Debug, Clone)]
+pub struct Suspicion {
+    pub since: SteadyTime,
+    incarnation: u64,
+    suspected_by: Option<UuidSimple>,
+    confirmations: HashSet<UuidSimple>,
+}
+
+impl Suspicion {
+    fn new(incarnation: u64) -> Suspicion {
+        Suspicion {
+            since: SteadyTime::now(),
+            incarnation: incarnation,
+            suspected_by: None,
+            confirmations: HashSet::new(),
+        }
+    }
+
+    /// How many other members have independently confirmed this suspicion.
+    pub fn confirmations(&self) -> usize {
+        self.confirmations.len()
+    }
+}
+
+/// Tracks lists of members, their health, and how long they have been suspect, or confirmed or
+/// departed.
 #[derive(Debug, Clone)]
 pub struct MemberList {
     pub members: Arc<RwLock<HashMap<UuidSimple, Member>>>,
     pub health: Arc<RwLock<HashMap<UuidSimple, Health>>>,
-    suspect: Arc<RwLock<HashMap<UuidSimple, SteadyTime>>>,
+    suspect: Arc<RwLock<HashMap<UuidSimple, Suspicion>>>,
+    gone: Arc<RwLock<HashMap<UuidSimple, SteadyTime>>>,
     initial_members: Arc<RwLock<Vec<Member>>>,
     update_counter: Arc<AtomicUsize>,
 }
@@ -234,6 +270,7 @@ impl MemberList {
             members: Arc::new(RwLock::new(HashMap::new())),
             health: Arc::new(RwLock::new(HashMap::new())),
             suspect: Arc::new(RwLock::new(HashMap::new())),
+            gone: Arc::new(RwLock::new(HashMap::new())),
             initial_members: Arc::new(RwLock::new(Vec::new())),
             update_counter: Arc::new(AtomicUsize::new(0)),
         }
@@ -279,8 +316,11 @@ impl MemberList {
     /// Inserts a member into the member list with the given health.
     pub fn insert(&self, member: Member, health: Health) -> bool {
         let share_rumor: bool;
-        let mut start_suspicion: bool = false;
-        let mut stop_suspicion: bool = false;
+
+        // A departed member stays departed, no matter what we hear about it later.
+        if self.check_health_of(&member, Health::Departed) {
+            return false;
+        }
 
         // If we have an existing member record..
         if let Some(current_member) =
@@ -288,9 +328,12 @@ impl MemberList {
                 .read()
                 .expect("Member List read lock poisoned")
                 .get(member.get_id()) {
-            // If my incarnation is newer than the member we are being asked
-            // to insert, we want to prefer our member, health and all.
-            if current_member.get_incarnation() > member.get_incarnation() {
+            // If the rumor is a departure, it wins over any incarnation we have.
+            if health == Health::Departed {
+                share_rumor = true;
+                // If my incarnation is newer than the member we are being asked
+                // to insert, we want to prefer our member, health and all.
+            } else if current_member.get_incarnation() > member.get_incarnation() {
                 share_rumor = false;
                 // If the new rumor has a higher incarnation than our status, we want
                 // to prefer it.
@@ -304,7 +347,6 @@ impl MemberList {
                              insert?");
                 // If currently healthy and the rumor is suspicion, then we are now suspicious.
                 if *current_health == Health::Alive && health == Health::Suspect {
-                    start_suspicion = true;
                     share_rumor = true;
                     // If currently healthy and the rumor is confirmation, then we are now confirmed
                 } else if *current_health == Health::Alive && health == Health::Confirmed {
@@ -321,7 +363,6 @@ impl MemberList {
                     // If currently suspicious and the rumor is confirmation, then we are now
                     // confirmed
                 } else if *current_health == Health::Suspect && health == Health::Confirmed {
-                    stop_suspicion = true;
                     share_rumor = true;
                     // When we are currently confirmed, we stay that way until something with a
                     // higher incarnation changes our mind.
@@ -339,18 +380,8 @@ impl MemberList {
                 .write()
                 .expect("Health lock is poisoned")
                 .insert(String::from(member.get_id()), health);
-            if start_suspicion == true {
-                self.suspect
-                    .write()
-                    .expect("Suspect lock is poisoned")
-                    .insert(String::from(member.get_id()), SteadyTime::now());
-            }
-            if stop_suspicion == true {
-                self.suspect
-                    .write()
-                    .expect("Suspect lock is poisoned")
-                    .remove(member.get_id());
-            }
+            self.track_suspicion(member.get_id(), member.get_incarnation(), &health);
+            self.track_gone(member.get_id(), &health);
             self.members
                 .write()
                 .expect("Member list lock is poisoned")
@@ -406,8 +437,11 @@ impl MemberList {
     }
 
     /// Returns true if the member is alive, suspect, or persistent; used during the target
-    /// selection phase of the outbound thread.
+    /// selection phase of the outbound thread. Departed members are never pinged.
     pub fn pingable(&self, member: &Member) -> bool {
+        if self.check_health_of(member, Health::Departed) {
+            return false;
+        }
         if member.get_persistent() {
             return true;
         }
@@ -428,14 +462,17 @@ impl MemberList {
                 .read()
                 .expect("Health read lock is poisoned")
                 .get(member_id) {
-            if *current_health == health {
+            if *current_health == health || *current_health == Health::Departed {
                 return false;
             }
         }
-        if health == Health::Suspect {
-            let mut sl = self.suspect.write().expect("Suspect lock is poisoned");
-            sl.insert(String::from(member_id), SteadyTime::now());
-        }
+        let incarnation = self.members
+            .read()
+            .expect("Member list lock is poisoned")
+            .get(member_id)
+            .map_or(0, |m| m.get_incarnation());
+        self.track_suspicion(member_id, incarnation, &health);
+        self.track_gone(member_id, &health);
         self.health
             .write()
             .expect("Health write lock is poisoned")
@@ -465,6 +502,16 @@ impl MemberList {
             .expect("Should have membership before calling membership_for");
         pm.set_health(mhealth);
         pm.set_member(member.proto.clone());
+        if mhealth == ProtoMembership_Health::SUSPECT {
+            if let Some(suspicion) = self.suspect
+                   .read()
+                   .expect("Suspect list lock is poisoned")
+                   .get(member_id) {
+                if let Some(ref suspected_by) = suspicion.suspected_by {
+                    pm.set_suspected_by(suspected_by.clone());
+                }
+            }
+        }
         pm
     }
 
@@ -562,7 +609,7 @@ impl MemberList {
 
     /// Iterates over every suspected membership entry, calling the given closure.
     pub fn with_suspects<F>(&self, mut with_closure: F) -> ()
-        where F: FnMut((&str, &SteadyTime)) -> ()
+        where F: FnMut((&str, &Suspicion)) -> ()
     {
         for (id, suspect) in self.suspect
                 .read()
@@ -572,6 +619,52 @@ impl MemberList {
         }
     }
 
+    /// Records that the member `suspected_by` raised a suspicion of the member `member_id` at the
+    /// given incarnation. A suspicion is counted once per member which raised it, however many
+    /// members relayed it to us. Returns true if this is a new confirmation of a suspicion we
+    /// hold.
+    pub fn confirm_suspicion(&self, member_id: &str, incarnation: u64, suspected_by: &str) -> bool {
+        if suspected_by == member_id {
+            return false;
+        }
+        let mut suspects = self.suspect
+            .write()
+            .expect("Suspect list lock is poisoned");
+        match suspects.get_mut(member_id) {
+            Some(suspicion) => {
+                if suspicion.incarnation != incarnation {
+                    return false;
+                }
+                if suspicion.suspected_by.is_none() {
+                    suspicion.suspected_by = Some(String::from(suspected_by));
+                }
+                suspicion.confirmations.insert(String::from(suspected_by))
+            }
+            None => false,
+        }
+    }
+
+    /// Records that we raised a suspicion of the member `member_id` at the given incarnation
+    /// ourselves, so we spread our own suspicion rather than the one we relayed. Returns true if
+    /// we were spreading the suspicion of another member.
+    pub fn raise_suspicion(&self, member_id: &str, incarnation: u64, our_id: &str) -> bool {
+        let mut suspects = self.suspect
+            .write()
+            .expect("Suspect list lock is poisoned");
+        match suspects.get_mut(member_id) {
+            Some(suspicion) => {
+                if suspicion.incarnation != incarnation ||
+                   suspicion.suspected_by.as_ref().map_or(false, |id| id == our_id) {
+                    return false;
+                }
+                let relayed = suspicion.suspected_by.is_some();
+                suspicion.suspected_by = Some(String::from(our_id));
+                relayed
+            }
+            None => false,
+        }
+    }
+
     /// Expires a member from the suspect list.
     pub fn expire(&self, member_id: &str) {
         let mut suspects = self.suspect
@@ -580,12 +673,58 @@ impl MemberList {
         suspects.remove(member_id);
     }
 
+    /// Iterates over every confirmed or departed member, calling the given closure with the time
+    /// since which it has been gone.
+    pub fn with_gone<F>(&self, mut with_closure: F) -> ()
+        where F: FnMut((&str, &SteadyTime)) -> ()
+    {
+        for (id, since) in self.gone
+                .read()
+                .expect("Gone list lock is poisoned")
+                .iter() {
+            with_closure((id, since));
+        }
+    }
+
     pub fn contains_member(&self, member_id: &str) -> bool {
         self.members
             .read()
             .expect("Member list lock is poisoned")
             .contains_key(member_id)
     }
+
+    /// Starts the suspicion of a member when it becomes suspect, keeping the suspicion while it
+    /// stays suspect at the same incarnation, and forgets it once the member has any other health.
+    fn track_suspicion(&self, member_id: &str, incarnation: u64, health: &Health) {
+        let mut suspects = self.suspect
+            .write()
+            .expect("Suspect list lock is poisoned");
+        if *health != Health::Suspect {
+            suspects.remove(member_id);
+            return;
+        }
+        let current = suspects
+            .get(member_id)
+            .map_or(false, |suspicion| suspicion.incarnation == incarnation);
+        if !current {
+            suspects.insert(String::from(member_id), Suspicion::new(incarnation));
+        }
+    }
+
+    /// Records when a member became confirmed or departed, keeping the earliest time while it
+    /// stays gone, and forgets it once the member is alive or suspect again.
+    fn track_gone(&self, member_id: &str, health: &Health) {
+        let mut gone = self.gone.write().expect("Gone list lock is poisoned");
+        match *health {
+            Health::Confirmed | Health::Departed => {
+                gone.entry(String::from(member_id))
+                    .or_insert(SteadyTime::now());
+            }
+            Health::Alive | Health::Suspect => {
+                gone.remove(member_id);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -887,5 +1026,178 @@ mod tests {
             assert!(ml.check_health_of(&mcheck_two, Health::Confirmed));
         }
 
+        #[test]
+        fn insert_departed_wins_over_higher_incarnation() {
+            let ml = MemberList::new();
+            let mut member_one = Member::default();
+            let mcheck_one = member_one.clone();
+            let member_two = member_one.clone();
+            let mcheck_two = member_two.clone();
+
+            member_one.set_incarnation(1);
+
+            assert_eq!(ml.insert(member_one, Health::Alive), true);
+            assert!(ml.check_health_of(&mcheck_one, Health::Alive));
+
+            assert_eq!(ml.insert(member_two, Health::Departed), true);
+            assert!(ml.check_health_of(&mcheck_two, Health::Departed));
+        }
+
+        #[test]
+        fn insert_current_departed_new_alive_higher_incarnation() {
+            let ml = MemberList::new();
+            let member_one = Member::default();
+            let mcheck_one = member_one.clone();
+            let mut member_two = member_one.clone();
+            member_two.set_incarnation(1);
+            let mcheck_two = member_two.clone();
+
+            assert_eq!(ml.insert(member_one, Health::Departed), true);
+            assert!(ml.check_health_of(&mcheck_one, Health::Departed));
+
+            assert_eq!(ml.insert(member_two, Health::Alive), false);
+            assert!(ml.check_health_of(&mcheck_two, Health::Departed));
+        }
+
+        #[test]
+        fn insert_health_of_departed_member() {
+            let ml = MemberList::new();
+            let member = Member::default();
+            let mcheck = member.clone();
+
+            assert_eq!(ml.insert(member, Health::Suspect), true);
+            assert_eq!(ml.insert_health(&mcheck, Health::Departed), true);
+            assert_eq!(ml.insert_health(&mcheck, Health::Alive), false);
+            assert!(ml.check_health_of(&mcheck, Health::Departed));
+            let mut suspects = 0;
+            ml.with_suspects(|_| suspects += 1);
+            assert_eq!(suspects, 0);
+        }
+
+        fn confirmations_of(ml: &MemberList, member_id: &str) -> Option<usize> {
+            let mut confirmations = None;
+            ml.with_suspects(|(id, suspicion)| if id == member_id {
+                                 confirmations = Some(suspicion.confirmations());
+                             });
+            confirmations
+        }
+
+        #[test]
+        fn suspicion_counts_distinct_confirmations() {
+            let ml = MemberList::new();
+            let member = Member::default();
+            let mcheck = member.clone();
+
+            assert_eq!(ml.insert(member, Health::Alive), true);
+            assert_eq!(confirmations_of(&ml, mcheck.get_id()), None);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), 0, "one"), false);
+
+            assert_eq!(ml.insert_health(&mcheck, Health::Suspect), true);
+            assert_eq!(confirmations_of(&ml, mcheck.get_id()), Some(0));
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), 0, "one"), true);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), 0, "one"), false);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), 0, "two"), true);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), 0, mcheck.get_id()), false);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), 1, "three"), false);
+            assert_eq!(confirmations_of(&ml, mcheck.get_id()), Some(2));
+        }
+
+        #[test]
+        fn raising_a_relayed_suspicion_makes_it_ours() {
+            let ml = MemberList::new();
+            let member = Member::default();
+            let mcheck = member.clone();
+
+            assert_eq!(ml.insert(member, Health::Suspect), true);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), 0, "one"), true);
+            assert_eq!(ml.raise_suspicion(mcheck.get_id(), 1, "me"), false);
+            assert_eq!(ml.raise_suspicion(mcheck.get_id(), 0, "me"), true);
+            assert_eq!(ml.raise_suspicion(mcheck.get_id(), 0, "me"), false);
+            assert_eq!(ml.membership_for(mcheck.get_id()).get_suspected_by(), "me");
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), 0, "one"), false);
+            assert_eq!(confirmations_of(&ml, mcheck.get_id()), Some(1));
+        }
+
+        #[test]
+        fn suspicion_restarts_at_a_new_incarnation() {
+            let ml = MemberList::new();
+            let member = Member::default();
+            let mcheck = member.clone();
+            let mut member_two = member.clone();
+            member_two.set_incarnation(1);
+
+            assert_eq!(ml.insert(member, Health::Suspect), true);
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), 0, "one"), true);
+            assert_eq!(ml.insert(member_two, Health::Suspect), true);
+            assert_eq!(confirmations_of(&ml, mcheck.get_id()), Some(0));
+            assert_eq!(ml.confirm_suspicion(mcheck.get_id(), 1, "one"), true);
+        }
+
+        #[test]
+        fn suspicion_stops_when_refuted() {
+            let ml = MemberList::new();
+            let member = Member::default();
+            let mcheck = member.clone();
+            let mut member_two = member.clone();
+            member_two.set_incarnation(1);
+
+            assert_eq!(ml.insert(member, Health::Suspect), true);
+            assert_eq!(confirmations_of(&ml, mcheck.get_id()), Some(0));
+            assert_eq!(ml.insert(member_two, Health::Alive), true);
+            assert_eq!(confirmations_of(&ml, mcheck.get_id()), None);
+        }
+
+        #[test]
+        fn departed_members_are_not_pingable() {
+            let ml = MemberList::new();
+            let mut member = Member::default();
+            member.set_persistent(true);
+            let mcheck = member.clone();
+
+            assert_eq!(ml.insert(member, Health::Departed), true);
+            assert!(!ml.pingable(&mcheck));
+        }
+
+        #[test]
+        fn confirmed_members_are_gone_until_alive() {
+            let ml = MemberList::new();
+            let member = Member::default();
+            let mcheck = member.clone();
+            let mut member_two = member.clone();
+            member_two.set_incarnation(1);
+
+            assert_eq!(ml.insert(member, Health::Alive), true);
+            let mut gone = 0;
+            ml.with_gone(|_| gone += 1);
+            assert_eq!(gone, 0);
+
+            assert_eq!(ml.insert_health(&mcheck, Health::Confirmed), true);
+            let mut since = None;
+            ml.with_gone(|(id, s)| {
+                assert_eq!(id, mcheck.get_id());
+                since = Some(s.clone());
+            });
+            assert!(since.is_some());
+
+            assert_eq!(ml.insert(member_two, Health::Alive), true);
+            let mut gone = 0;
+            ml.with_gone(|_| gone += 1);
+            assert_eq!(gone, 0);
+        }
+
+        #[test]
+        fn gone_keeps_the_time_a_member_was_first_confirmed() {
+            let ml = MemberList::new();
+            let member = Member::default();
+            let mcheck = member.clone();
+
+            assert_eq!(ml.insert(member, Health::Confirmed), true);
+            let mut first = None;
+            ml.with_gone(|(_, s)| first = Some(s.clone()));
+            assert_eq!(ml.insert_health(&mcheck, Health::Departed), true);
+            let mut second = None;
+            ml.with_gone(|(_, s)| second = Some(s.clone()));
+            assert_eq!(first, second);
+        }
     }
 }
@@ -17,18 +17,28 @@ pub mod swim;
 use std::result;
 use std::str;
 
-use habitat_core::crypto::SymKey;
+use habitat_core::crypto::{MemberKey, SymKey};
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 use toml;
 
-use error::Result;
+use auth::{self, Auth};
+use error::{Error, Result};
 use message::swim::Wire;
 use protobuf::{self, Message};
+use ring_keys::RingKeys;
 
-pub fn generate_wire(payload: Vec<u8>, ring_key: &Option<SymKey>) -> Result<Vec<u8>> {
+/// Wraps a payload for the wire, encrypting it with the ring key and signing it with the member
+/// key if they are given.
+pub fn generate_wire(payload: Vec<u8>,
+                     ring_key: Option<&SymKey>,
+                     member_key: Option<&MemberKey>)
+                     -> Result<Vec<u8>> {
     let mut wire = Wire::new();
-    if let Some(ref ring_key) = *ring_key {
+    if let Some(member_key) = member_key {
+        wire.set_signature(auth::sign(member_key, &payload));
+    }
+    if let Some(ring_key) = ring_key {
         wire.set_encrypted(true);
         let (nonce, encrypted_payload) = try!(ring_key.encrypt(&payload));
         wire.set_nonce(nonce);
@@ -39,12 +49,29 @@ pub fn generate_wire(payload: Vec<u8>, ring_key: &Option<SymKey>) -> Result<Vec<
     Ok(try!(wire.write_to_bytes()))
 }
 
-pub fn unwrap_wire(payload: &[u8], ring_key: &Option<SymKey>) -> Result<Vec<u8>> {
+/// Unwraps a payload from the wire, decrypting it with any of the ring keys if there are some. If
+/// `auth` is given, the payload must be signed by a member of the ring, and the ID of that member
+/// is returned along with it.
+pub fn unwrap_wire(payload: &[u8],
+                   ring_keys: &RingKeys,
+                   auth: Option<&Auth>)
+                   -> Result<(Vec<u8>, Option<String>)> {
     let mut wire: Wire = try!(protobuf::parse_from_bytes(payload));
-    if let Some(ref ring_key) = *ring_key {
-        Ok(try!(ring_key.decrypt(wire.get_nonce(), wire.get_payload())))
+    let payload = if ring_keys.is_empty() {
+        wire.take_payload()
     } else {
-        Ok(wire.take_payload())
+        try!(ring_keys.decrypt(wire.get_nonce(), wire.get_payload()))
+    };
+    match auth {
+        Some(auth) => {
+            if !wire.has_signature() {
+                return Err(Error::Unauthenticated(String::from("message is not signed")));
+            }
+            try!(auth.verify(&payload, wire.get_signature()));
+            let sender = String::from(wire.get_signature().get_member_id());
+            Ok((payload, Some(sender)))
+        }
+        None => Ok((payload, None)),
     }
 }
 
@@ -93,7 +120,7 @@ impl Serialize for swim::Rumor {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
     {
-        let mut strukt = try!(serializer.serialize_struct("rumor", 8));
+        let mut strukt = try!(serializer.serialize_struct("rumor", 12));
         try!(strukt.serialize_field("type", &self.get_field_type()));
         try!(strukt.serialize_field("tag", self.get_tag()));
         try!(strukt.serialize_field("from_id", self.get_from_id()));
@@ -112,6 +139,18 @@ impl Serialize for swim::Rumor {
         if self.has_election() {
             try!(strukt.serialize_field("election", self.get_election()));
         }
+        if self.has_release_blacklist() {
+            try!(strukt.serialize_field("release_blacklist", self.get_release_blacklist()));
+        }
+        if self.has_service_secret() {
+            try!(strukt.serialize_field("service_secret", self.get_service_secret()));
+        }
+        if self.has_departure() {
+            try!(strukt.serialize_field("departure", self.get_departure()));
+        }
+        if self.has_ring_key() {
+            try!(strukt.serialize_field("ring_key", self.get_ring_key()));
+        }
         strukt.end()
     }
 }
@@ -170,6 +209,54 @@ impl Serialize for swim::ServiceFile {
     }
 }
 
+impl Serialize for swim::ReleaseBlacklist {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("release_blacklist", 3));
+        try!(strukt.serialize_field("member_id", self.get_member_id()));
+        try!(strukt.serialize_field("service_group", self.get_service_group()));
+        try!(strukt.serialize_field("pkg", self.get_pkg()));
+        strukt.end()
+    }
+}
+
+/// The value of a secret is left out, even though it is encrypted.
+impl Serialize for swim::ServiceSecret {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("service_secret", 3));
+        try!(strukt.serialize_field("service_group", self.get_service_group()));
+        try!(strukt.serialize_field("incarnation", &self.get_incarnation()));
+        try!(strukt.serialize_field("name", self.get_name()));
+        strukt.end()
+    }
+}
+
+impl Serialize for swim::Departure {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("departure", 1));
+        try!(strukt.serialize_field("member_id", self.get_member_id()));
+        strukt.end()
+    }
+}
+
+/// The key itself is left out, since the whole ring encrypts its traffic with it.
+impl Serialize for swim::RingKey {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("ring_key", 3));
+        try!(strukt.serialize_field("name", self.get_name()));
+        try!(strukt.serialize_field("revision", self.get_revision()));
+        try!(strukt.serialize_field("primary", &self.get_primary()));
+        strukt.end()
+    }
+}
+
 impl Serialize for swim::SysInfo {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
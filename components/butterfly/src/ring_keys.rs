@@ -0,0 +1,255 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The revisions of the ring key a server encrypts and decrypts its traffic with.
+//!
+//! A server encrypts everything it sends with its primary ring key, but accepts traffic encrypted
+//! with any revision of the ring key it knows about. This lets a ring move to a new revision
+//! without downtime: every member learns about the new revision first, and only then starts
+//! encrypting with it. Once every member encrypts with it, the older revisions are retired, and no
+//! longer decrypted with.
+
+use habitat_core::crypto::SymKey;
+
+use error::Result;
+
+/// The revisions of a ring key, with the primary one first.
+#[derive(Debug, Clone, Default)]
+pub struct RingKeys {
+    keys: Vec<SymKey>,
+    retired_before: Option<String>,
+}
+
+impl From<Option<SymKey>> for RingKeys {
+    fn from(ring_key: Option<SymKey>) -> RingKeys {
+        match ring_key {
+            Some(ring_key) => RingKeys::new(ring_key, Vec::new()),
+            None => RingKeys::default(),
+        }
+    }
+}
+
+impl RingKeys {
+    /// Create a new set of ring keys, encrypting with the primary key and decrypting with any of
+    /// them. Revisions of other rings are left out.
+    pub fn new(primary: SymKey, others: Vec<SymKey>) -> RingKeys {
+        let mut ring_keys = RingKeys {
+            keys: vec![primary],
+            retired_before: None,
+        };
+        for key in others {
+            ring_keys.add(key);
+        }
+        ring_keys
+    }
+
+    /// Returns the name of the ring, if the traffic is encrypted.
+    pub fn name(&self) -> Option<&str> {
+        self.keys.first().map(|key| &key.name[..])
+    }
+
+    /// Returns the key to encrypt with, if the traffic is encrypted.
+    pub fn primary(&self) -> Option<&SymKey> {
+        self.keys.first()
+    }
+
+    /// Returns every revision we decrypt with, the primary one first.
+    pub fn keys(&self) -> &[SymKey] {
+        &self.keys
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns true if we know about the given revision of the ring key.
+    pub fn contains(&self, rev: &str) -> bool {
+        self.keys.iter().any(|key| key.rev == rev)
+    }
+
+    /// Returns the revision which retired every older one, if any.
+    pub fn retired_before(&self) -> Option<&str> {
+        self.retired_before.as_ref().map(|rev| &rev[..])
+    }
+
+    /// Returns true if the given revision of the ring key was retired.
+    pub fn is_retired(&self, rev: &str) -> bool {
+        match self.retired_before {
+            Some(ref retired_before) => rev < &retired_before[..],
+            None => false,
+        }
+    }
+
+    /// Start decrypting with a revision of the ring key. Returns true if it is a revision we did
+    /// not know about. Retired revisions are never added back.
+    pub fn add(&mut self, key: SymKey) -> bool {
+        if self.name() != Some(&key.name[..]) || self.contains(&key.rev) ||
+           self.is_retired(&key.rev) {
+            return false;
+        }
+        self.keys.push(key);
+        true
+    }
+
+    /// Start encrypting with a revision of the ring key. A revision older than the primary one is
+    /// only decrypted with, so a ring never goes back to an older key. Returns true if the primary
+    /// key changed.
+    pub fn promote(&mut self, key: SymKey) -> bool {
+        if self.name() != Some(&key.name[..]) {
+            return false;
+        }
+        if self.keys[0].rev >= key.rev {
+            self.add(key);
+            return false;
+        }
+        self.keys.retain(|k| k.rev != key.rev);
+        self.keys.insert(0, key);
+        true
+    }
+
+    /// Start encrypting with a revision of the ring key, and stop decrypting with every older
+    /// one, so a leaked older revision can no longer read or join the ring. Returns true if any
+    /// revision was retired.
+    pub fn retire(&mut self, key: SymKey) -> bool {
+        if self.name() != Some(&key.name[..]) || self.is_retired(&key.rev) {
+            return false;
+        }
+        let rev = key.rev.clone();
+        self.promote(key);
+        let count = self.keys.len();
+        self.keys.retain(|k| k.rev >= rev);
+        self.retired_before = Some(rev);
+        self.keys.len() != count
+    }
+
+    /// Restore the revision which retired every older one before a restart. The older revisions
+    /// are no longer decrypted with, except for the primary one, and are never added back.
+    pub fn restore_retired_before(&mut self, rev: String) {
+        if self.is_retired(&rev) {
+            return;
+        }
+        let primary = match self.keys.first() {
+            Some(key) => key.rev.clone(),
+            None => return,
+        };
+        self.keys.retain(|k| k.rev >= rev || k.rev == primary);
+        self.retired_before = Some(rev);
+    }
+
+    /// Decrypts the ciphertext with the first revision of the ring key that can, trying the
+    /// primary one first. Without any ring key, the traffic is not encrypted at all.
+    pub fn decrypt(&self, nonce: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let mut last_err = None;
+        for key in self.keys.iter() {
+            match key.decrypt(nonce, ciphertext) {
+                Ok(payload) => return Ok(payload),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        match last_err {
+            Some(e) => Err(e.into()),
+            None => Ok(ciphertext.to_vec()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::SymKey;
+
+    use super::RingKeys;
+
+    fn sym_key(name: &str, rev: &str) -> SymKey {
+        let mut sym_key = SymKey::generate_in_memory(name).unwrap();
+        sym_key.rev = String::from(rev);
+        sym_key
+    }
+
+    #[test]
+    fn new_leaves_out_other_rings_and_duplicates() {
+        let ring_keys = RingKeys::new(sym_key("beyonce", "20170102000000"),
+                                      vec![sym_key("beyonce", "20170101000000"),
+                                           sym_key("beyonce", "20170102000000"),
+                                           sym_key("jayz", "20170101000000")]);
+        assert_eq!(ring_keys.name(), Some("beyonce"));
+        assert_eq!(ring_keys.primary().unwrap().rev, "20170102000000");
+        assert_eq!(ring_keys.keys().len(), 2);
+    }
+
+    #[test]
+    fn decrypts_with_any_revision() {
+        let old = sym_key("beyonce", "20170101000000");
+        let new = sym_key("beyonce", "20170102000000");
+        let ring_keys = RingKeys::new(new.clone(), vec![old.clone()]);
+        for key in vec![old, new] {
+            let (nonce, ciphertext) = key.encrypt(b"Ringonit").unwrap();
+            assert_eq!(ring_keys.decrypt(&nonce, &ciphertext).unwrap(),
+                       b"Ringonit".to_vec());
+        }
+    }
+
+    #[test]
+    fn does_not_decrypt_with_unknown_revisions() {
+        let ring_keys = RingKeys::from(Some(sym_key("beyonce", "20170101000000")));
+        let (nonce, ciphertext) = sym_key("beyonce", "20170102000000")
+            .encrypt(b"Ringonit")
+            .unwrap();
+        assert!(ring_keys.decrypt(&nonce, &ciphertext).is_err());
+    }
+
+    #[test]
+    fn promote_makes_a_newer_revision_primary() {
+        let mut ring_keys = RingKeys::from(Some(sym_key("beyonce", "20170101000000")));
+        assert!(ring_keys.add(sym_key("beyonce", "20170102000000")));
+        assert!(ring_keys.promote(sym_key("beyonce", "20170102000000")));
+        assert_eq!(ring_keys.primary().unwrap().rev, "20170102000000");
+        assert_eq!(ring_keys.keys().len(), 2);
+        assert!(ring_keys.contains("20170101000000"));
+    }
+
+    #[test]
+    fn retire_stops_decrypting_with_older_revisions() {
+        let old = sym_key("beyonce", "20170101000000");
+        let new = sym_key("beyonce", "20170102000000");
+        let mut ring_keys = RingKeys::new(old.clone(), vec![new.clone()]);
+        assert!(ring_keys.retire(new));
+        assert_eq!(ring_keys.primary().unwrap().rev, "20170102000000");
+        assert_eq!(ring_keys.keys().len(), 1);
+        assert_eq!(ring_keys.retired_before(), Some("20170102000000"));
+        let (nonce, ciphertext) = old.encrypt(b"Ringonit").unwrap();
+        assert!(ring_keys.decrypt(&nonce, &ciphertext).is_err());
+        assert_eq!(ring_keys.add(old), false);
+        assert!(!ring_keys.contains("20170101000000"));
+    }
+
+    #[test]
+    fn restore_retired_before_drops_older_revisions() {
+        let mut ring_keys = RingKeys::new(sym_key("beyonce", "20170103000000"),
+                                          vec![sym_key("beyonce", "20170101000000"),
+                                               sym_key("beyonce", "20170102000000")]);
+        ring_keys.restore_retired_before(String::from("20170102000000"));
+        assert_eq!(ring_keys.retired_before(), Some("20170102000000"));
+        assert_eq!(ring_keys.keys().len(), 2);
+        assert!(!ring_keys.contains("20170101000000"));
+        assert_eq!(ring_keys.add(sym_key("beyonce", "20170101000000")), false);
+    }
+
+    #[test]
+    fn promote_never_goes_back_to_an_older_revision() {
+        let mut ring_keys = RingKeys::from(Some(sym_key("beyonce", "20170102000000")));
+        assert_eq!(ring_keys.promote(sym_key("beyonce", "20170101000000")), false);
+        assert_eq!(ring_keys.primary().unwrap().rev, "20170102000000");
+        assert!(ring_keys.contains("20170101000000"));
+    }
+}
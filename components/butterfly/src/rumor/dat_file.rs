@@ -24,10 +24,11 @@ use rand::{Rng, thread_rng};
 use error::{Result, Error};
 use member::{Health, Member, MemberList};
 use message::swim::Membership as ProtoMembership;
-use rumor::{Election, ElectionUpdate, Rumor, RumorStore, Service, ServiceConfig, ServiceFile};
+use rumor::{Departure, Election, ElectionUpdate, ReleaseBlacklist, Rumor, RumorStore, Service,
+            ServiceConfig, ServiceFile, ServiceSecret};
 use server::Server;
 
-const HEADER_VERSION: u8 = 1;
+const HEADER_VERSION: u8 = 4;
 
 /// A versioned binary file containing rumors exchanged by the butterfly server which have
 /// been periodically persisted to disk.
@@ -42,6 +43,7 @@ const HEADER_VERSION: u8 = 1;
 pub struct DatFile {
     header: Header,
     path: PathBuf,
+    version: u8,
 }
 
 impl DatFile {
@@ -49,6 +51,7 @@ impl DatFile {
         DatFile {
             path: data_path.as_ref().join(format!("{}.rst", member_id)),
             header: Header::default(),
+            version: HEADER_VERSION,
         }
     }
 
@@ -71,7 +74,8 @@ impl DatFile {
             .read_exact(&mut version)
             .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
         debug!("Header Version: {}", version[0]);
-        self.header = Header::from_file(&mut reader)
+        self.version = version[0];
+        self.header = Header::from_file(&mut reader, self.version)
             .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
         debug!("Header: {:?}", self.header);
 
@@ -194,6 +198,65 @@ impl DatFile {
             server.insert_update_election(rumor);
             bytes_read += size_buf.len() as u64 + rumor_size;
         }
+
+        debug!("Reading release blacklist rumors from {}",
+               self.path().display());
+        bytes_read = 0;
+        loop {
+            if bytes_read >= self.header.release_blacklist_len {
+                break;
+            }
+            reader
+                .read_exact(&mut size_buf)
+                .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+            let rumor_size = LittleEndian::read_u64(&size_buf);
+            rumor_buf.resize(rumor_size as usize, 0);
+            reader
+                .read_exact(&mut rumor_buf)
+                .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+            let rumor = ReleaseBlacklist::from_bytes(&rumor_buf)?;
+            server.insert_release_blacklist(rumor);
+            bytes_read += size_buf.len() as u64 + rumor_size;
+        }
+
+        debug!("Reading service-secret rumors from {}",
+               self.path().display());
+        bytes_read = 0;
+        loop {
+            if bytes_read >= self.header.service_secret_len {
+                break;
+            }
+            reader
+                .read_exact(&mut size_buf)
+                .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+            let rumor_size = LittleEndian::read_u64(&size_buf);
+            rumor_buf.resize(rumor_size as usize, 0);
+            reader
+                .read_exact(&mut rumor_buf)
+                .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+            let rumor = ServiceSecret::from_bytes(&rumor_buf)?;
+            server.insert_service_secret(rumor);
+            bytes_read += size_buf.len() as u64 + rumor_size;
+        }
+
+        debug!("Reading departure rumors from {}", self.path().display());
+        bytes_read = 0;
+        loop {
+            if bytes_read >= self.header.departure_len {
+                break;
+            }
+            reader
+                .read_exact(&mut size_buf)
+                .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+            let rumor_size = LittleEndian::read_u64(&size_buf);
+            rumor_buf.resize(rumor_size as usize, 0);
+            reader
+                .read_exact(&mut rumor_buf)
+                .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
+            let rumor = Departure::from_bytes(&rumor_buf)?;
+            server.insert_departure(rumor);
+            bytes_read += size_buf.len() as u64 + rumor_size;
+        }
         Ok(())
     }
 
@@ -221,6 +284,11 @@ impl DatFile {
                 self.write_rumor_store(&mut writer, &server.service_file_store)?;
             header.election_len = self.write_rumor_store(&mut writer, &server.election_store)?;
             header.update_len = self.write_rumor_store(&mut writer, &server.update_store)?;
+            header.release_blacklist_len =
+                self.write_rumor_store(&mut writer, &server.release_blacklist_store)?;
+            header.service_secret_len =
+                self.write_rumor_store(&mut writer, &server.service_secret_store)?;
+            header.departure_len = self.write_rumor_store(&mut writer, &server.departure_store)?;
             writer
                 .seek(SeekFrom::Start(1))
                 .map_err(|err| Error::DatFileIO(self.path.clone(), err))?;
@@ -249,7 +317,7 @@ impl DatFile {
     }
 
     fn member_offset(&self) -> u64 {
-        1 + mem::size_of::<Header>() as u64
+        1 + Header::size(self.version) as u64
     }
 
     #[allow(dead_code)]
@@ -277,6 +345,21 @@ impl DatFile {
         self.election_offset() + self.header.election_len
     }
 
+    #[allow(dead_code)]
+    fn release_blacklist_offset(&self) -> u64 {
+        self.update_offset() + self.header.update_len
+    }
+
+    #[allow(dead_code)]
+    fn service_secret_offset(&self) -> u64 {
+        self.release_blacklist_offset() + self.header.release_blacklist_len
+    }
+
+    #[allow(dead_code)]
+    fn departure_offset(&self) -> u64 {
+        self.service_secret_offset() + self.header.service_secret_len
+    }
+
     fn write_header<W>(&self, writer: &mut W, header: &Header) -> Result<usize>
         where W: Write
     {
@@ -365,13 +448,28 @@ pub struct Header {
     pub service_file_len: u64,
     pub election_len: u64,
     pub update_len: u64,
+    pub release_blacklist_len: u64,
+    pub service_secret_len: u64,
+    pub departure_len: u64,
 }
 
 impl Header {
-    pub fn from_file<R>(reader: &mut R) -> io::Result<Self>
+    /// Size of the header written by the given header version. Version 1 headers predate the
+    /// release blacklist, version 2 headers predate service secrets and version 3 headers predate
+    /// departures.
+    pub fn size(version: u8) -> usize {
+        match version {
+            0 | 1 => 48,
+            2 => 56,
+            3 => 64,
+            _ => mem::size_of::<Self>(),
+        }
+    }
+
+    pub fn from_file<R>(reader: &mut R, version: u8) -> io::Result<Self>
         where R: Read
     {
-        let mut bytes = vec![0; mem::size_of::<Self>()];
+        let mut bytes = vec![0; Self::size(version)];
         reader.read_exact(&mut bytes)?;
         Ok(Self::from_bytes(&bytes))
     }
@@ -384,6 +482,21 @@ impl Header {
             service_file_len: LittleEndian::read_u64(&bytes[24..32]),
             election_len: LittleEndian::read_u64(&bytes[32..40]),
             update_len: LittleEndian::read_u64(&bytes[40..48]),
+            release_blacklist_len: if bytes.len() >= 56 {
+                LittleEndian::read_u64(&bytes[48..56])
+            } else {
+                0
+            },
+            service_secret_len: if bytes.len() >= 64 {
+                LittleEndian::read_u64(&bytes[56..64])
+            } else {
+                0
+            },
+            departure_len: if bytes.len() >= 72 {
+                LittleEndian::read_u64(&bytes[64..72])
+            } else {
+                0
+            },
         }
     }
 
@@ -395,6 +508,9 @@ impl Header {
         LittleEndian::write_u64(&mut bytes[24..32], self.service_file_len);
         LittleEndian::write_u64(&mut bytes[32..40], self.election_len);
         LittleEndian::write_u64(&mut bytes[40..48], self.update_len);
+        LittleEndian::write_u64(&mut bytes[48..56], self.release_blacklist_len);
+        LittleEndian::write_u64(&mut bytes[56..64], self.service_secret_len);
+        LittleEndian::write_u64(&mut bytes[64..72], self.departure_len);
         Ok(bytes)
     }
 }
@@ -414,9 +530,44 @@ mod tests {
         original.service_file_len = rand::random::<u64>();
         original.election_len = rand::random::<u64>();
         original.update_len = rand::random::<u64>();
+        original.release_blacklist_len = rand::random::<u64>();
+        original.service_secret_len = rand::random::<u64>();
+        original.departure_len = rand::random::<u64>();
         let bytes = original.write_to_bytes().unwrap();
         let restored = Header::from_bytes(&bytes);
         assert_eq!(bytes.len(), mem::size_of::<Header>());
         assert_eq!(original, restored);
     }
+
+    #[test]
+    fn read_version_one_header() {
+        let mut original = Header::default();
+        original.update_len = rand::random::<u64>();
+        let bytes = original.write_to_bytes().unwrap();
+        let restored = Header::from_file(&mut &bytes[..Header::size(1)], 1).unwrap();
+        assert_eq!(restored.update_len, original.update_len);
+        assert_eq!(restored.release_blacklist_len, 0);
+    }
+
+    #[test]
+    fn read_version_two_header() {
+        let mut original = Header::default();
+        original.release_blacklist_len = rand::random::<u64>();
+        original.service_secret_len = rand::random::<u64>();
+        let bytes = original.write_to_bytes().unwrap();
+        let restored = Header::from_file(&mut &bytes[..Header::size(2)], 2).unwrap();
+        assert_eq!(restored.release_blacklist_len, original.release_blacklist_len);
+        assert_eq!(restored.service_secret_len, 0);
+    }
+
+    #[test]
+    fn read_version_three_header() {
+        let mut original = Header::default();
+        original.service_secret_len = rand::random::<u64>();
+        original.departure_len = rand::random::<u64>();
+        let bytes = original.write_to_bytes().unwrap();
+        let restored = Header::from_file(&mut &bytes[..Header::size(3)], 3).unwrap();
+        assert_eq!(restored.service_secret_len, original.service_secret_len);
+        assert_eq!(restored.departure_len, 0);
+    }
 }
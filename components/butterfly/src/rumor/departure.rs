@@ -0,0 +1,154 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Departure rumor.
+//!
+//! Departs a member from the ring for good. Every server marks the member as `Departed`, stops
+//! pinging it, and forgets its service rumors, so it drops out of every census.
+
+use std::cmp::Ordering;
+use std::ops::{Deref, DerefMut};
+
+use protobuf::{self, Message};
+
+use error::Result;
+use message::swim::{Departure as ProtoDeparture, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Departure(ProtoRumor);
+
+impl PartialOrd for Departure {
+    fn partial_cmp(&self, other: &Departure) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
+impl PartialEq for Departure {
+    fn eq(&self, other: &Departure) -> bool {
+        self.get_member_id() == other.get_member_id()
+    }
+}
+
+impl From<ProtoRumor> for Departure {
+    fn from(pr: ProtoRumor) -> Departure {
+        Departure(pr)
+    }
+}
+
+impl From<Departure> for ProtoRumor {
+    fn from(departure: Departure) -> ProtoRumor {
+        departure.0
+    }
+}
+
+impl Deref for Departure {
+    type Target = ProtoDeparture;
+
+    fn deref(&self) -> &ProtoDeparture {
+        self.0.get_departure()
+    }
+}
+
+impl DerefMut for Departure {
+    fn deref_mut(&mut self) -> &mut ProtoDeparture {
+        self.0.mut_departure()
+    }
+}
+
+impl Departure {
+    /// Creates a new Departure for the given member.
+    pub fn new<S: Into<String>>(member_id: S) -> Self {
+        let mut rumor = ProtoRumor::new();
+        let member_id = member_id.into();
+        rumor.set_from_id(member_id.clone());
+        rumor.set_field_type(ProtoRumor_Type::Departure);
+
+        let mut proto = ProtoDeparture::new();
+        proto.set_member_id(member_id);
+
+        rumor.set_departure(proto);
+        Departure(rumor)
+    }
+}
+
+impl Rumor for Departure {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(Departure::from(rumor))
+    }
+
+    /// A member is either departed or it isn't, so merging never changes anything.
+    fn merge(&mut self, _other: Departure) -> bool {
+        false
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::Departure
+    }
+
+    fn id(&self) -> &str {
+        self.get_member_id()
+    }
+
+    fn key(&self) -> &str {
+        "departure"
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Departure;
+    use rumor::Rumor;
+
+    #[test]
+    fn departures_for_the_same_member_are_equal() {
+        let d1 = Departure::new("adam");
+        let d2 = Departure::new("adam");
+        assert_eq!(d1, d2);
+    }
+
+    #[test]
+    fn departures_for_different_members_are_not_equal() {
+        let d1 = Departure::new("adam");
+        let d2 = Departure::new("tom");
+        assert!(d1 != d2);
+    }
+
+    #[test]
+    fn merge_never_changes_anything() {
+        let mut d1 = Departure::new("adam");
+        let d2 = Departure::new("adam");
+        assert_eq!(d1.merge(d2), false);
+    }
+
+    #[test]
+    fn round_trips_through_bytes() {
+        let d1 = Departure::new("adam");
+        let bytes = d1.write_to_bytes().unwrap();
+        let d2 = Departure::from_bytes(&bytes).unwrap();
+        assert_eq!(d1, d2);
+        assert_eq!(d2.key(), "departure");
+        assert_eq!(d2.id(), "adam");
+    }
+}
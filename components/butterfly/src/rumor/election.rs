@@ -94,11 +94,19 @@ impl Election {
         }
     }
 
-    /// Steal all the votes from another election for ourselves.
+    /// Steal all the votes from another election for ourselves, along with the signatures of
+    /// their voters.
     pub fn steal_votes(&mut self, other: &mut Election) {
         for x in other.mut_votes().iter() {
             self.insert_vote(x);
         }
+        for signature in other.get_vote_signatures() {
+            if !self.get_vote_signatures()
+                    .iter()
+                    .any(|s| s.get_member_id() == signature.get_member_id()) {
+                self.mut_vote_signatures().push(signature.clone());
+            }
+        }
     }
 
     /// Sets the status of the election to "running".
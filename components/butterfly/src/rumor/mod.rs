@@ -24,15 +24,23 @@
 //! new rumors, and dispatch them according to their `kind`.
 
 pub mod dat_file;
+pub mod departure;
 pub mod election;
+pub mod release_blacklist;
+pub mod ring_key;
 pub mod service;
 pub mod service_config;
 pub mod service_file;
+pub mod service_secret;
 
+pub use self::departure::Departure;
 pub use self::election::{Election, ElectionUpdate};
+pub use self::release_blacklist::ReleaseBlacklist;
+pub use self::ring_key::RingKey;
 pub use self::service::Service;
 pub use self::service_config::ServiceConfig;
 pub use self::service_file::ServiceFile;
+pub use self::service_secret::ServiceSecret;
 
 use std::collections::HashMap;
 use std::collections::hash_map::Entry;
@@ -188,7 +196,36 @@ impl<T: Rumor> RumorStore<T> {
 
     pub fn remove(&self, key: &str, id: &str) {
         let mut list = self.list.write().expect("Rumor store lock poisoned");
-        list.get_mut(key).and_then(|mut r| r.remove(id));
+        if list.get_mut(key).and_then(|mut r| r.remove(id)).is_some() {
+            self.increment_update_counter();
+        }
+    }
+
+    /// Removes the rumors with the given id from every key, such as all the service rumors of a
+    /// member. Returns the keys of the rumors removed.
+    pub fn remove_id(&self, id: &str) -> Vec<RumorKey> {
+        let mut list = self.list.write().expect("Rumor store lock poisoned");
+        let removed: Vec<RumorKey> = list.values_mut()
+            .filter_map(|rumors| rumors.remove(id))
+            .map(|rumor| RumorKey::from(&rumor))
+            .collect();
+        if removed.len() > 0 {
+            self.increment_update_counter();
+        }
+        removed
+    }
+
+    /// Removes every rumor under the given key, such as all the rumors of a service group.
+    /// Returns the keys of the rumors removed.
+    pub fn remove_key(&self, key: &str) -> Vec<RumorKey> {
+        let mut list = self.list.write().expect("Rumor store lock poisoned");
+        match list.remove(key) {
+            Some(rumors) => {
+                self.increment_update_counter();
+                rumors.values().map(|rumor| RumorKey::from(rumor)).collect()
+            }
+            None => Vec::new(),
+        }
     }
 
     pub fn with_keys<F>(&self, mut with_closure: F)
@@ -275,6 +312,14 @@ impl RumorList {
         rumors.insert(rk, HashMap::new());
     }
 
+    /// Stops sharing a rumor, once it has been removed from its store.
+    pub fn remove(&self, rumor_key: &RumorKey) {
+        let mut rumors = self.rumor_list
+            .write()
+            .expect("Rumor Map lock poisoned");
+        rumors.remove(rumor_key);
+    }
+
     /// Return a list of rumors, along with their current heat, sorted by heat. Lowest to highest.
     /// So all the "0" rumors sort higher than the "2" rumors.
     pub fn rumors(&self, id: &str) -> RumorVec {
@@ -518,12 +563,65 @@ mod tests {
             let rs = create_rumor_store();
             rs.with_rumor("bar", "foo", |o| assert!(o.is_none()));
         }
+
+        #[test]
+        fn remove_increments_update_counter() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let member_id = f1.id.clone();
+            let key = f1.key.clone();
+            rs.insert(f1);
+            rs.remove(&key, &member_id);
+            assert_eq!(rs.len(), 0);
+            assert_eq!(rs.get_update_counter(), 2);
+            rs.remove(&key, &member_id);
+            assert_eq!(rs.get_update_counter(), 2);
+        }
+
+        #[test]
+        fn remove_id_removes_rumors_under_every_key() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let mut f2 = FakeRumor::default();
+            f2.id = f1.id.clone();
+            f2.key = String::from("other");
+            let f3 = FakeRumor::default();
+            let member_id = f1.id.clone();
+            rs.insert(f1);
+            rs.insert(f2);
+            rs.insert(f3);
+            let removed = rs.remove_id(&member_id);
+            assert_eq!(removed.len(), 2);
+            assert!(removed.iter().all(|rk| rk.id == member_id));
+            assert_eq!(rs.len(), 1);
+            assert_eq!(rs.remove_id(&member_id).len(), 0);
+        }
+
+        #[test]
+        fn remove_key_removes_every_rumor_for_the_key() {
+            let rs = create_rumor_store();
+            let f1 = FakeRumor::default();
+            let f2 = FakeRumor::default();
+            let mut f3 = FakeRumor::default();
+            f3.key = String::from("other");
+            let key = f1.key.clone();
+            rs.insert(f1);
+            rs.insert(f2);
+            rs.insert(f3);
+            let removed = rs.remove_key(&key);
+            assert_eq!(removed.len(), 2);
+            assert!(removed.iter().all(|rk| rk.key == key));
+            assert_eq!(rs.len(), 1);
+            assert_eq!(rs.get_update_counter(), 4);
+            assert_eq!(rs.remove_key(&key).len(), 0);
+            assert_eq!(rs.get_update_counter(), 4);
+        }
     }
 
     mod rumor_list {
         use super::{FakeRumor, TrumpRumor};
         use message::swim::Rumor_Type;
-        use rumor::{RumorList, RUMOR_MAX};
+        use rumor::{RumorKey, RumorList, RUMOR_MAX};
 
         #[test]
         fn insert() {
@@ -532,6 +630,18 @@ mod tests {
             rl.insert(&rumor);
         }
 
+        #[test]
+        fn remove() {
+            let rl = RumorList::default();
+            let rumor = FakeRumor::default();
+            rl.insert(&rumor);
+            rl.insert(&FakeRumor::default());
+            rl.remove(&RumorKey::from(&rumor));
+            let rumors = rl.rumors(&String::from("fake"));
+            assert_eq!(rumors.len(), 1);
+            assert!(rumors[0].0 != RumorKey::from(&rumor));
+        }
+
         #[test]
         fn rumors() {
             let rl = RumorList::default();
@@ -0,0 +1,170 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The ReleaseBlacklist rumor.
+//!
+//! Marks a package release as bad for a service group, after a member had to roll back from it.
+//! Members updating the service group skip blacklisted releases.
+
+use std::cmp::Ordering;
+use std::ops::{Deref, DerefMut};
+
+use habitat_core::service::ServiceGroup;
+use protobuf::{self, Message};
+
+use error::Result;
+use message::swim::{ReleaseBlacklist as ProtoReleaseBlacklist, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReleaseBlacklist(ProtoRumor);
+
+impl PartialOrd for ReleaseBlacklist {
+    fn partial_cmp(&self, other: &ReleaseBlacklist) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+}
+
+impl PartialEq for ReleaseBlacklist {
+    fn eq(&self, other: &ReleaseBlacklist) -> bool {
+        self.get_service_group() == other.get_service_group() && self.get_pkg() == other.get_pkg()
+    }
+}
+
+impl From<ProtoRumor> for ReleaseBlacklist {
+    fn from(pr: ProtoRumor) -> ReleaseBlacklist {
+        ReleaseBlacklist(pr)
+    }
+}
+
+impl From<ReleaseBlacklist> for ProtoRumor {
+    fn from(release_blacklist: ReleaseBlacklist) -> ProtoRumor {
+        release_blacklist.0
+    }
+}
+
+impl Deref for ReleaseBlacklist {
+    type Target = ProtoReleaseBlacklist;
+
+    fn deref(&self) -> &ProtoReleaseBlacklist {
+        self.0.get_release_blacklist()
+    }
+}
+
+impl DerefMut for ReleaseBlacklist {
+    fn deref_mut(&mut self) -> &mut ProtoReleaseBlacklist {
+        self.0.mut_release_blacklist()
+    }
+}
+
+impl ReleaseBlacklist {
+    /// Creates a new ReleaseBlacklist for the given package release.
+    pub fn new<S1, S2>(member_id: S1, service_group: ServiceGroup, pkg: S2) -> Self
+        where S1: Into<String>,
+              S2: Into<String>
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id.clone());
+        rumor.set_field_type(ProtoRumor_Type::ReleaseBlacklist);
+
+        let mut proto = ProtoReleaseBlacklist::new();
+        proto.set_member_id(from_id);
+        proto.set_service_group(format!("{}", service_group));
+        proto.set_pkg(pkg.into());
+
+        rumor.set_release_blacklist(proto);
+        ReleaseBlacklist(rumor)
+    }
+}
+
+impl Rumor for ReleaseBlacklist {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(ReleaseBlacklist::from(rumor))
+    }
+
+    /// A release is either blacklisted or it isn't; the first member to report it wins, so
+    /// merging never changes anything.
+    fn merge(&mut self, _other: ReleaseBlacklist) -> bool {
+        false
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::ReleaseBlacklist
+    }
+
+    fn id(&self) -> &str {
+        self.get_pkg()
+    }
+
+    fn key(&self) -> &str {
+        self.get_service_group()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::service::ServiceGroup;
+
+    use super::ReleaseBlacklist;
+    use rumor::Rumor;
+
+    fn create_release_blacklist(member_id: &str, pkg: &str) -> ReleaseBlacklist {
+        ReleaseBlacklist::new(member_id,
+                              ServiceGroup::new("neurosis", "production", None).unwrap(),
+                              pkg)
+    }
+
+    #[test]
+    fn blacklists_for_the_same_release_are_equal() {
+        let b1 = create_release_blacklist("adam", "core/neurosis/1.0.0/20170101000000");
+        let b2 = create_release_blacklist("tom", "core/neurosis/1.0.0/20170101000000");
+        assert_eq!(b1, b2);
+    }
+
+    #[test]
+    fn blacklists_for_different_releases_are_not_equal() {
+        let b1 = create_release_blacklist("adam", "core/neurosis/1.0.0/20170101000000");
+        let b2 = create_release_blacklist("adam", "core/neurosis/1.0.0/20170102000000");
+        assert!(b1 != b2);
+    }
+
+    #[test]
+    fn merge_keeps_the_first_report() {
+        let mut b1 = create_release_blacklist("adam", "core/neurosis/1.0.0/20170101000000");
+        let b2 = create_release_blacklist("tom", "core/neurosis/1.0.0/20170101000000");
+        assert_eq!(b1.merge(b2), false);
+        assert_eq!(b1.get_member_id(), "adam");
+    }
+
+    #[test]
+    fn round_trips_through_bytes() {
+        let b1 = create_release_blacklist("adam", "core/neurosis/1.0.0/20170101000000");
+        let bytes = b1.write_to_bytes().unwrap();
+        let b2 = ReleaseBlacklist::from_bytes(&bytes).unwrap();
+        assert_eq!(b1, b2);
+        assert_eq!(b2.key(), "neurosis.production");
+        assert_eq!(b2.id(), "core/neurosis/1.0.0/20170101000000");
+    }
+}
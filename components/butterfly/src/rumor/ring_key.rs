@@ -0,0 +1,263 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The RingKey rumor.
+//!
+//! Hands a revision of the ring key to every member of the ring, so a ring can move to a new key
+//! without downtime. Members decrypt with a revision as soon as they learn about it, but only
+//! encrypt with it once it is gossiped again as the primary key. Once it is gossiped a third time
+//! as a retirement, members stop decrypting with every older revision and forget them. The rumor
+//! carries the secret key, so it is only ever sent on rings which already encrypt their traffic.
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::str;
+
+use habitat_core::crypto::SymKey;
+use protobuf::{self, Message};
+use time;
+
+use error::{Error, Result};
+use message::swim::{RingKey as ProtoRingKey, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RingKey(ProtoRumor);
+
+/// Newer revisions win, a revision gossiped as the primary key wins over the same revision
+/// gossiped as an additional one, and a retirement of the older revisions wins over both.
+impl PartialOrd for RingKey {
+    fn partial_cmp(&self, other: &RingKey) -> Option<Ordering> {
+        if self.get_name() != other.get_name() {
+            None
+        } else {
+            Some((self.get_revision(), self.get_primary(), self.get_retire())
+                     .cmp(&(other.get_revision(), other.get_primary(), other.get_retire())))
+        }
+    }
+}
+
+impl PartialEq for RingKey {
+    fn eq(&self, other: &RingKey) -> bool {
+        self.get_name() == other.get_name() && self.get_revision() == other.get_revision() &&
+        self.get_primary() == other.get_primary() && self.get_retire() == other.get_retire() &&
+        self.get_key() == other.get_key()
+    }
+}
+
+impl From<ProtoRumor> for RingKey {
+    fn from(pr: ProtoRumor) -> RingKey {
+        RingKey(pr)
+    }
+}
+
+impl From<RingKey> for ProtoRumor {
+    fn from(ring_key: RingKey) -> ProtoRumor {
+        ring_key.0
+    }
+}
+
+impl Deref for RingKey {
+    type Target = ProtoRingKey;
+
+    fn deref(&self) -> &ProtoRingKey {
+        self.0.get_ring_key()
+    }
+}
+
+impl DerefMut for RingKey {
+    fn deref_mut(&mut self) -> &mut ProtoRingKey {
+        self.0.mut_ring_key()
+    }
+}
+
+impl RingKey {
+    /// Creates a new RingKey for a revision of the ring key, which members start encrypting with
+    /// if it is the primary key.
+    pub fn new<S: Into<String>>(member_id: S, ring_key: &SymKey, primary: bool) -> Result<Self> {
+        let mut rumor = ProtoRumor::new();
+        rumor.set_from_id(member_id.into());
+        rumor.set_field_type(ProtoRumor_Type::RingKey);
+
+        let mut proto = ProtoRingKey::new();
+        proto.set_name(ring_key.name.clone());
+        proto.set_revision(ring_key.rev.clone());
+        proto.set_key(try!(ring_key.to_secret_string()).into_bytes());
+        proto.set_primary(primary);
+
+        rumor.set_ring_key(proto);
+        Ok(RingKey(rumor))
+    }
+
+    /// Creates a new RingKey which makes a revision of the ring key the primary one, and retires
+    /// every older revision.
+    pub fn retirement<S: Into<String>>(member_id: S, ring_key: &SymKey) -> Result<Self> {
+        let mut ring_key = try!(RingKey::new(member_id, ring_key, true));
+        ring_key.set_retire(true);
+        Ok(ring_key)
+    }
+
+    /// Reads the revision of the ring key this rumor carries. It must be the revision the rumor
+    /// claims to carry, and a revision which could have been generated by now: a revision far in
+    /// the future would stay the newest one forever.
+    pub fn sym_key(&self) -> Result<SymKey> {
+        if !is_plausible_revision(self.get_revision()) {
+            return Err(Error::BadMessage(format!("ring key {}-{} has an invalid revision",
+                                                 self.get_name(),
+                                                 self.get_revision())));
+        }
+        let content = try!(str::from_utf8(self.get_key()).map_err(|e| {
+            Error::BadMessage(format!("ring key {}-{} is not UTF-8: {}",
+                                      self.get_name(),
+                                      self.get_revision(),
+                                      e))
+        }));
+        let sym_key = try!(SymKey::from_secret_string(content));
+        if sym_key.name != self.get_name() || sym_key.rev != self.get_revision() {
+            return Err(Error::BadMessage(format!("ring key {}-{} carries the key {}",
+                                                 self.get_name(),
+                                                 self.get_revision(),
+                                                 sym_key.name_with_rev())));
+        }
+        Ok(sym_key)
+    }
+}
+
+/// Returns true if the revision is a timestamp no later than a day from now, leaving room for the
+/// clock of whoever generated it to be ahead of ours.
+fn is_plausible_revision(revision: &str) -> bool {
+    if revision.len() != 14 || !revision.chars().all(|c| c.is_digit(10)) {
+        return false;
+    }
+    let latest = time::at_utc(time::get_time() + time::Duration::days(1));
+    match latest.strftime("%Y%m%d%H%M%S") {
+        Ok(latest) => revision <= &latest.to_string()[..],
+        Err(_) => false,
+    }
+}
+
+impl Rumor for RingKey {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(RingKey::from(rumor))
+    }
+
+    /// The newest revision wins, and a promotion of the revision we have wins over it.
+    fn merge(&mut self, mut other: RingKey) -> bool {
+        if *self >= other {
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::RingKey
+    }
+
+    fn id(&self) -> &str {
+        self.get_name()
+    }
+
+    fn key(&self) -> &str {
+        "ring_key"
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use habitat_core::crypto::SymKey;
+
+    use super::RingKey;
+    use rumor::Rumor;
+
+    fn sym_key(rev: &str) -> SymKey {
+        let mut sym_key = SymKey::generate_in_memory("beyonce").unwrap();
+        sym_key.rev = String::from(rev);
+        sym_key
+    }
+
+    #[test]
+    fn newer_revisions_win() {
+        let mut older = RingKey::new("adam", &sym_key("20170101000000"), true).unwrap();
+        let newer = RingKey::new("adam", &sym_key("20170102000000"), false).unwrap();
+        assert!(older.merge(newer.clone()));
+        assert_eq!(older, newer);
+    }
+
+    #[test]
+    fn older_revisions_lose() {
+        let older = RingKey::new("adam", &sym_key("20170101000000"), true).unwrap();
+        let mut newer = RingKey::new("adam", &sym_key("20170102000000"), false).unwrap();
+        assert_eq!(newer.merge(older), false);
+        assert_eq!(newer.get_revision(), "20170102000000");
+    }
+
+    #[test]
+    fn promotions_win() {
+        let key = sym_key("20170101000000");
+        let mut added = RingKey::new("adam", &key, false).unwrap();
+        let promoted = RingKey::new("adam", &key, true).unwrap();
+        assert!(added.merge(promoted.clone()));
+        assert_eq!(added, promoted);
+        assert_eq!(added.merge(RingKey::new("adam", &key, false).unwrap()), false);
+    }
+
+    #[test]
+    fn retirements_win() {
+        let key = sym_key("20170101000000");
+        let mut promoted = RingKey::new("adam", &key, true).unwrap();
+        let retirement = RingKey::retirement("adam", &key).unwrap();
+        assert!(promoted.merge(retirement.clone()));
+        assert_eq!(promoted, retirement);
+        assert_eq!(promoted.merge(RingKey::new("adam", &key, true).unwrap()), false);
+        assert!(promoted.merge(RingKey::new("adam", &sym_key("20170102000000"), false).unwrap()));
+    }
+
+    #[test]
+    fn refuses_revisions_from_the_future() {
+        let ring_key = RingKey::new("adam", &sym_key("99999999999999"), true).unwrap();
+        assert!(ring_key.sym_key().is_err());
+        let ring_key = RingKey::new("adam", &sym_key("2017010100000"), true).unwrap();
+        assert!(ring_key.sym_key().is_err());
+    }
+
+    #[test]
+    fn carries_the_sym_key() {
+        let key = sym_key("20170101000000");
+        let ring_key = RingKey::new("adam", &key, false).unwrap();
+        let bytes = ring_key.write_to_bytes().unwrap();
+        let ring_key = RingKey::from_bytes(&bytes).unwrap();
+        assert_eq!(ring_key.key(), "ring_key");
+        assert_eq!(ring_key.id(), "beyonce");
+        let restored = ring_key.sym_key().unwrap();
+        assert_eq!(restored.name_with_rev(), key.name_with_rev());
+        let (nonce, ciphertext) = key.encrypt(b"Ringonit").unwrap();
+        assert_eq!(restored.decrypt(&nonce, &ciphertext).unwrap(), b"Ringonit".to_vec());
+    }
+
+    #[test]
+    fn refuses_a_key_of_another_revision() {
+        let mut ring_key = RingKey::new("adam", &sym_key("20170101000000"), false).unwrap();
+        ring_key.set_revision(String::from("20170102000000"));
+        assert!(ring_key.sym_key().is_err());
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The ServiceSecret rumor.
+//!
+//! Holds a named secret for a service group. The value is always encrypted to the service
+//! group's key, so it never travels or rests in gossip in plaintext.
+
+use std::cmp::Ordering;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+
+use habitat_core::crypto::{BoxKeyPair, default_cache_key_path};
+use habitat_core::service::ServiceGroup;
+use protobuf::{self, Message};
+
+use error::Result;
+use message::swim::{ServiceSecret as ProtoServiceSecret, Rumor as ProtoRumor,
+                    Rumor_Type as ProtoRumor_Type};
+use rumor::Rumor;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceSecret(ProtoRumor);
+
+impl PartialOrd for ServiceSecret {
+    fn partial_cmp(&self, other: &ServiceSecret) -> Option<Ordering> {
+        if self.get_service_group() != other.get_service_group() ||
+           self.get_name() != other.get_name() {
+            None
+        } else {
+            Some(self.get_incarnation().cmp(&other.get_incarnation()))
+        }
+    }
+}
+
+impl PartialEq for ServiceSecret {
+    fn eq(&self, other: &ServiceSecret) -> bool {
+        self.get_service_group() == other.get_service_group() &&
+        self.get_incarnation() == other.get_incarnation() &&
+        self.get_name() == other.get_name() && self.get_value() == other.get_value()
+    }
+}
+
+impl From<ProtoRumor> for ServiceSecret {
+    fn from(pr: ProtoRumor) -> ServiceSecret {
+        ServiceSecret(pr)
+    }
+}
+
+impl From<ServiceSecret> for ProtoRumor {
+    fn from(service_secret: ServiceSecret) -> ProtoRumor {
+        service_secret.0
+    }
+}
+
+impl Deref for ServiceSecret {
+    type Target = ProtoServiceSecret;
+
+    fn deref(&self) -> &ProtoServiceSecret {
+        self.0.get_service_secret()
+    }
+}
+
+impl DerefMut for ServiceSecret {
+    fn deref_mut(&mut self) -> &mut ProtoServiceSecret {
+        self.0.mut_service_secret()
+    }
+}
+
+impl ServiceSecret {
+    /// Creates a new ServiceSecret from a value already encrypted to the service group's key.
+    pub fn new<S1, S2>(member_id: S1,
+                       service_group: ServiceGroup,
+                       name: S2,
+                       encrypted_value: Vec<u8>)
+                       -> Self
+        where S1: Into<String>,
+              S2: Into<String>
+    {
+        let mut rumor = ProtoRumor::new();
+        let from_id = member_id.into();
+        rumor.set_from_id(from_id);
+        rumor.set_field_type(ProtoRumor_Type::ServiceSecret);
+
+        let mut proto = ProtoServiceSecret::new();
+        proto.set_service_group(format!("{}", service_group));
+        proto.set_incarnation(0);
+        proto.set_name(name.into());
+        proto.set_value(encrypted_value);
+
+        rumor.set_service_secret(proto);
+        ServiceSecret(rumor)
+    }
+
+    /// Encrypt a secret value from a user to a service group.
+    pub fn encrypt(value: &[u8],
+                   user_pair: &BoxKeyPair,
+                   service_pair: &BoxKeyPair)
+                   -> Result<Vec<u8>> {
+        Ok(try!(user_pair.encrypt(value, service_pair)))
+    }
+
+    /// Decrypt the value of the secret with the service group's key from the default key cache.
+    pub fn value(&self) -> Result<Vec<u8>> {
+        let bytes = try!(BoxKeyPair::decrypt(self.get_value(), &default_cache_key_path(None)));
+        Ok(bytes)
+    }
+}
+
+impl Rumor for ServiceSecret {
+    fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let rumor = protobuf::parse_from_bytes::<ProtoRumor>(bytes)?;
+        Ok(ServiceSecret::from(rumor))
+    }
+
+    /// Follows a simple pattern; if we have a newer incarnation than the one we already have, the
+    /// new one wins.
+    fn merge(&mut self, mut other: ServiceSecret) -> bool {
+        if *self >= other {
+            false
+        } else {
+            mem::swap(self, &mut other);
+            true
+        }
+    }
+
+    fn kind(&self) -> ProtoRumor_Type {
+        ProtoRumor_Type::ServiceSecret
+    }
+
+    fn id(&self) -> &str {
+        self.get_name()
+    }
+
+    fn key(&self) -> &str {
+        self.get_service_group()
+    }
+
+    fn write_to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(try!(self.0.write_to_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use habitat_core::service::ServiceGroup;
+
+    use super::ServiceSecret;
+    use rumor::Rumor;
+
+    fn create_service_secret(member_id: &str, name: &str, value: &str) -> ServiceSecret {
+        ServiceSecret::new(member_id,
+                           ServiceGroup::new("neurosis", "production", None).unwrap(),
+                           name,
+                           Vec::from(value))
+    }
+
+    #[test]
+    fn identical_service_secrets_are_equal() {
+        let s1 = create_service_secret("adam", "password", "encrypted");
+        let s2 = create_service_secret("adam", "password", "encrypted");
+        assert_eq!(s1, s2);
+    }
+
+    #[test]
+    fn service_secrets_with_different_names_are_not_comparable() {
+        let s1 = create_service_secret("adam", "password", "encrypted");
+        let s2 = create_service_secret("adam", "token", "encrypted");
+        assert_eq!(s1.partial_cmp(&s2), None);
+    }
+
+    #[test]
+    fn service_secrets_with_different_incarnations_are_ordered() {
+        let s1 = create_service_secret("adam", "password", "encrypted");
+        let mut s2 = create_service_secret("adam", "password", "encrypted");
+        s2.set_incarnation(1);
+        assert!(s1 != s2);
+        assert_eq!(s1.partial_cmp(&s2), Some(Ordering::Less));
+        assert_eq!(s2.partial_cmp(&s1), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn merge_chooses_the_higher_incarnation() {
+        let mut s1 = create_service_secret("adam", "password", "encrypted");
+        let mut s2 = create_service_secret("adam", "password", "reencrypted");
+        s2.set_incarnation(1);
+        let s2_check = s2.clone();
+        assert_eq!(s1.merge(s2), true);
+        assert_eq!(s1, s2_check);
+    }
+
+    #[test]
+    fn merge_returns_false_if_nothing_changed() {
+        let mut s1 = create_service_secret("adam", "password", "encrypted");
+        s1.set_incarnation(1);
+        let s1_check = s1.clone();
+        let s2 = create_service_secret("adam", "password", "reencrypted");
+        assert_eq!(s1.merge(s2), false);
+        assert_eq!(s1, s1_check);
+    }
+
+    #[test]
+    fn round_trips_through_bytes() {
+        let s1 = create_service_secret("adam", "password", "encrypted");
+        let bytes = s1.write_to_bytes().unwrap();
+        let s2 = ServiceSecret::from_bytes(&bytes).unwrap();
+        assert_eq!(s1, s2);
+        assert_eq!(s2.key(), "neurosis.production");
+        assert_eq!(s2.id(), "password");
+    }
+}
@@ -12,11 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Expire suspected members.
+//! Expire suspected members, and stale rumors.
 //!
 //! This module keeps track of suspected members, and sets their status to confirmed if they remain
-//! suspect long enough.
+//! suspect long enough. How long is long enough grows with the size of the ring, and shrinks as
+//! other members report the same member suspect.
+//!
+//! It also keeps the rumor stores from growing without bound. The service rumors of a member which
+//! has been confirmed or departed for longer than the rumor TTL are dropped, and so are the
+//! service and election rumors of a service group which has had no live members for that long.
+//! The configuration, files, secrets and release blacklists operators published for such a
+//! service group are kept for the much longer operator rumor TTL. A member which comes back shares
+//! its rumors again when it refutes its confirmation.
 
+use std::collections::{HashMap, HashSet};
 use std::thread;
 use std::time::Duration;
 
@@ -24,7 +33,7 @@ use time::SteadyTime;
 
 use message::swim::Rumor_Type;
 use member::Health;
-use rumor::RumorKey;
+use rumor::{Rumor, RumorKey, RumorStore};
 use server::Server;
 use server::timing::Timing;
 use trace::TraceKind;
@@ -45,36 +54,244 @@ impl Expire {
 
     /// Run the expire thread.
     pub fn run(&self) {
+        let mut dead_service_groups: HashMap<String, SteadyTime> = HashMap::new();
         loop {
             let mut expired_list: Vec<String> = Vec::new();
+            let members = self.server.member_list.len();
+            let now = SteadyTime::now();
             self.server
                 .member_list
-                .with_suspects(|(id, suspect)| {
-                    let now = SteadyTime::now();
-                    if *suspect + self.timing.suspicion_timeout_duration() > now {
+                .with_suspects(|(id, suspicion)| {
+                    let timeout = self.timing
+                        .suspicion_timeout_duration(members, suspicion.confirmations());
+                    if suspicion.since + timeout <= now {
                         expired_list.push(String::from(id));
-                        self.server
-                            .member_list
-                            .insert_health_by_id(id, Health::Confirmed);
-                        self.server
-                            .member_list
-                            .with_member(id, |has_member| {
-                                let member =
-                                    has_member.expect("Member does not exist when expiring it");
-                                debug!("Marking {:?} as Confirmed", member);
-                                trace_it!(
-                            PROBE: &self.server,
-                            TraceKind::ProbeConfirmed, member.get_id(), member.get_address());
-                            });
                     }
                 });
             for mid in expired_list.iter() {
+                // The member may have refuted its suspicion in the meantime.
+                if !self.server
+                        .member_list
+                        .check_health_of_by_id(mid, Health::Suspect) {
+                    continue;
+                }
+                self.server
+                    .member_list
+                    .insert_health_by_id(mid, Health::Confirmed);
+                self.server
+                    .member_list
+                    .with_member(mid, |has_member| {
+                        let member = has_member.expect("Member does not exist when expiring it");
+                        debug!("Marking {:?} as Confirmed", member);
+                        trace_it!(PROBE: &self.server,
+                                  TraceKind::ProbeConfirmed,
+                                  member.get_id(),
+                                  member.get_address());
+                    });
                 self.server.member_list.expire(mid);
                 self.server
                     .rumor_list
                     .insert(RumorKey::new(Rumor_Type::Member, mid.clone(), ""));
             }
+            self.expire_gone_members();
+            self.expire_dead_service_groups(&mut dead_service_groups, SteadyTime::now());
             thread::sleep(Duration::from_millis(500));
         }
     }
+
+    /// Drops the service rumors of members which have been confirmed or departed for longer than
+    /// the rumor TTL.
+    fn expire_gone_members(&self) {
+        let now = SteadyTime::now();
+        let ttl = self.timing.rumor_ttl_duration();
+        let mut gone_list: Vec<String> = Vec::new();
+        self.server
+            .member_list
+            .with_gone(|(id, since)| if *since + ttl <= now {
+                           gone_list.push(String::from(id));
+                       });
+        for mid in gone_list.iter() {
+            let removed = self.server.service_store.remove_id(mid);
+            if removed.len() > 0 {
+                debug!("Expired {} service rumors of gone member {}",
+                       removed.len(),
+                       mid);
+                self.server.record_expired_rumors(&removed);
+            }
+        }
+    }
+
+    /// Drops the service and election rumors of the service groups which have had no live members
+    /// for longer than the rumor TTL, and the rumors operators published for them once the
+    /// operator rumor TTL has passed too. `dead_service_groups` tracks when each service group was
+    /// first seen without any.
+    fn expire_dead_service_groups(&self,
+                                  dead_service_groups: &mut HashMap<String, SteadyTime>,
+                                  now: SteadyTime) {
+        let ttl = self.timing.rumor_ttl_duration();
+        let operator_ttl = self.timing.operator_rumor_ttl_duration();
+        let mut service_groups: HashSet<String> = HashSet::new();
+        let mut live_service_groups: HashSet<String> = HashSet::new();
+        self.server
+            .service_store
+            .with_keys(|(service_group, rumors)| {
+                service_groups.insert(service_group.clone());
+                if rumors.values().any(|s| !self.server.is_gone(s.get_member_id())) {
+                    live_service_groups.insert(service_group.clone());
+                }
+            });
+        insert_keys(&self.server.service_config_store, &mut service_groups);
+        insert_keys(&self.server.service_file_store, &mut service_groups);
+        insert_keys(&self.server.service_secret_store, &mut service_groups);
+        insert_keys(&self.server.election_store, &mut service_groups);
+        insert_keys(&self.server.update_store, &mut service_groups);
+        insert_keys(&self.server.release_blacklist_store, &mut service_groups);
+
+        let not_dead: Vec<String> = dead_service_groups
+            .keys()
+            .filter(|sg| !service_groups.contains(*sg) || live_service_groups.contains(*sg))
+            .cloned()
+            .collect();
+        for sg in not_dead.iter() {
+            dead_service_groups.remove(sg);
+        }
+        for sg in service_groups.difference(&live_service_groups) {
+            let since = *dead_service_groups
+                             .entry(sg.clone())
+                             .or_insert(now);
+            if since + ttl > now {
+                continue;
+            }
+            let mut removed = self.server.service_store.remove_key(sg);
+            removed.extend(self.server.election_store.remove_key(sg));
+            removed.extend(self.server.update_store.remove_key(sg));
+            if removed.len() > 0 {
+                debug!("Expired {} service and election rumors of dead service group {}",
+                       removed.len(),
+                       sg);
+                self.server.record_expired_rumors(&removed);
+            }
+            if since + operator_ttl > now {
+                continue;
+            }
+            let mut removed = self.server.service_config_store.remove_key(sg);
+            removed.extend(self.server.service_file_store.remove_key(sg));
+            removed.extend(self.server.service_secret_store.remove_key(sg));
+            removed.extend(self.server.release_blacklist_store.remove_key(sg));
+            debug!("Expired {} operator rumors of dead service group {}",
+                   removed.len(),
+                   sg);
+            self.server.record_expired_rumors(&removed);
+            dead_service_groups.remove(sg);
+        }
+    }
+}
+
+/// Adds the keys of every rumor in the store to the set of service groups.
+fn insert_keys<T: Rumor>(store: &RumorStore<T>, service_groups: &mut HashSet<String>) {
+    store.with_keys(|(key, _)| { service_groups.insert(key.clone()); });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use habitat_core::service::ServiceGroup;
+    use time::{Duration as TimeDuration, SteadyTime};
+
+    use rumor::election::Election;
+    use rumor::release_blacklist::ReleaseBlacklist;
+    use rumor::service_config::ServiceConfig;
+    use server::expire::Expire;
+    use server::tests::server::start_server;
+    use server::timing::Timing;
+
+    fn expire() -> Expire {
+        let mut timing = Timing::default();
+        timing.rumor_ttl_ms = 1000;
+        timing.operator_rumor_ttl_ms = 10_000;
+        Expire::new(start_server(), timing)
+    }
+
+    fn insert_dead_service_group(expire: &Expire) {
+        let sg = ServiceGroup::from_str("witcher.prod").unwrap();
+        let election = Election::new("gone", sg.clone(), 0);
+        let service_config = ServiceConfig::new("operator", sg.clone(), Vec::new());
+        let release_blacklist =
+            ReleaseBlacklist::new("operator", sg, "core/witcher/1.2.3/20161208121212");
+        expire.server.rumor_list.insert(&election);
+        expire.server.rumor_list.insert(&service_config);
+        expire.server.rumor_list.insert(&release_blacklist);
+        expire.server.election_store.insert(election);
+        expire
+            .server
+            .service_config_store
+            .insert(service_config);
+        expire
+            .server
+            .release_blacklist_store
+            .insert(release_blacklist);
+    }
+
+    /// Returns the number of rumors the server would still share with a member.
+    fn shared_rumors(expire: &Expire) -> usize {
+        expire.server.rumor_list.rumors("someone").len()
+    }
+
+    #[test]
+    fn dead_service_group_keeps_its_rumors_until_the_ttl() {
+        let expire = expire();
+        insert_dead_service_group(&expire);
+        let mut dead_service_groups = HashMap::new();
+        let now = SteadyTime::now();
+
+        expire.expire_dead_service_groups(&mut dead_service_groups, now);
+        expire.expire_dead_service_groups(&mut dead_service_groups,
+                                          now + TimeDuration::milliseconds(500));
+
+        assert_eq!(expire.server.election_store.len(), 1);
+        assert_eq!(expire.server.service_config_store.len(), 1);
+        assert_eq!(expire.server.release_blacklist_store.len(), 1);
+        assert_eq!(expire.server.expired_rumors(), 0);
+        assert_eq!(shared_rumors(&expire), 3);
+    }
+
+    #[test]
+    fn dead_service_group_keeps_operator_rumors_past_the_ttl() {
+        let expire = expire();
+        insert_dead_service_group(&expire);
+        let mut dead_service_groups = HashMap::new();
+        let now = SteadyTime::now();
+
+        expire.expire_dead_service_groups(&mut dead_service_groups, now);
+        expire.expire_dead_service_groups(&mut dead_service_groups,
+                                          now + TimeDuration::seconds(2));
+
+        assert_eq!(expire.server.election_store.len(), 0);
+        assert_eq!(expire.server.service_config_store.len(), 1);
+        assert_eq!(expire.server.release_blacklist_store.len(), 1);
+        assert_eq!(expire.server.expired_rumors(), 1);
+        assert_eq!(shared_rumors(&expire), 2);
+    }
+
+    #[test]
+    fn dead_service_group_drops_operator_rumors_after_the_operator_ttl() {
+        let expire = expire();
+        insert_dead_service_group(&expire);
+        let mut dead_service_groups = HashMap::new();
+        let now = SteadyTime::now();
+
+        expire.expire_dead_service_groups(&mut dead_service_groups, now);
+        expire.expire_dead_service_groups(&mut dead_service_groups,
+                                          now + TimeDuration::seconds(2));
+        expire.expire_dead_service_groups(&mut dead_service_groups,
+                                          now + TimeDuration::seconds(11));
+
+        assert_eq!(expire.server.service_config_store.len(), 0);
+        assert_eq!(expire.server.release_blacklist_store.len(), 0);
+        assert_eq!(expire.server.expired_rumors(), 3);
+        assert_eq!(shared_rumors(&expire), 0);
+        assert!(dead_service_groups.is_empty());
+    }
 }
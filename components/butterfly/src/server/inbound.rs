@@ -59,8 +59,9 @@ impl Inbound {
             }
             match self.socket.recv_from(&mut recv_buffer[..]) {
                 Ok((length, addr)) => {
-                    let swim_payload = match self.server.unwrap_wire(&recv_buffer[0..length]) {
-                        Ok(swim_payload) => swim_payload,
+                    let (swim_payload, sender) = match self.server
+                              .unwrap_wire(&recv_buffer[0..length]) {
+                        Ok(unwrapped) => unwrapped,
                         Err(e) => {
                             // NOTE: In the future, we might want to blacklist people who send us
                             // garbage all the time.
@@ -79,6 +80,14 @@ impl Inbound {
                         }
                     };
                     debug!("SWIM Message: {:?}", msg);
+                    if let Some(sender) = sender {
+                        if !self.sent_by(&sender, &msg) {
+                            warn!("Not processing message from {} signed by {}",
+                                  addr,
+                                  sender);
+                            continue;
+                        }
+                    }
                     match msg.get_field_type() {
                         Swim_Type::PING => {
                             if self.server
@@ -128,6 +137,24 @@ impl Inbound {
         }
     }
 
+    /// Returns true if an authenticated message was signed by the member it claims to be from.
+    /// The only exception are acks forwarded to us, which are signed by the member which relayed
+    /// them on our behalf.
+    fn sent_by(&self, sender: &str, msg: &Swim) -> bool {
+        let from = match msg.get_field_type() {
+            Swim_Type::PING => msg.get_ping().get_from().get_id(),
+            Swim_Type::ACK => {
+                if msg.get_ack().has_forward_to() &&
+                   msg.get_ack().get_forward_to().get_id() == self.server.member_id() {
+                    return true;
+                }
+                msg.get_ack().get_from().get_id()
+            }
+            Swim_Type::PINGREQ => msg.get_pingreq().get_from().get_id(),
+        };
+        from == sender
+    }
+
     /// Process pingreq messages.
     fn process_pingreq(&self, addr: SocketAddr, mut msg: Swim) {
         trace_it!(SWIM: &self.server,
@@ -194,13 +221,7 @@ impl Inbound {
                 return;
             }
         }
-        let membership = {
-            let membership: Vec<(Member, Health)> = msg.take_membership()
-                .iter()
-                .map(|m| (Member::from(m.get_member()), Health::from(m.get_health())))
-                .collect();
-            membership
-        };
+        let membership = msg.take_membership().into_vec();
         match self.tx_outbound.send((addr, msg)) {
             Ok(()) => {}
             Err(e) => panic!("Outbound thread has died - this shouldn't happen: #{:?}", e),
@@ -234,10 +255,7 @@ impl Inbound {
         };
         info!("Ping from {}@{}", from.get_id(), addr);
         self.server.insert_member(from.into(), Health::Alive);
-        let membership: Vec<(Member, Health)> = msg.take_membership()
-            .iter()
-            .map(|m| (Member::from(m.get_member()), Health::from(m.get_health())))
-            .collect();
-        self.server.insert_member_from_rumors(membership);
+        self.server
+            .insert_member_from_rumors(msg.take_membership().into_vec());
     }
 }
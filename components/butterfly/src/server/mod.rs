@@ -26,6 +26,7 @@ mod pull;
 mod push;
 pub mod timing;
 
+use std::cmp;
 use std::collections::HashSet;
 use std::ffi;
 use std::fmt::{self, Debug};
@@ -36,25 +37,31 @@ use std::path::PathBuf;
 use std::result;
 use std::str::FromStr;
 use std::sync::{Arc, RwLock};
-use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
 use std::sync::mpsc::channel;
 use std::time::{Instant, Duration};
 use std::thread;
 
 use habitat_core::service::ServiceGroup;
-use habitat_core::crypto::SymKey;
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 
+use auth::{self, Auth};
 use error::{Result, Error};
 use member::{Member, Health, MemberList};
 use message;
+use message::swim::{Membership as ProtoMembership, Rumor as ProtoRumor, Rumor_Type};
 use rumor::{Rumor, RumorList, RumorKey, RumorStore};
 use rumor::dat_file::DatFile;
+use rumor::departure::Departure;
 use rumor::service::Service;
 use rumor::service_config::ServiceConfig;
 use rumor::service_file::ServiceFile;
+use rumor::service_secret::ServiceSecret;
+use rumor::release_blacklist::ReleaseBlacklist;
 use rumor::election::{Election, ElectionUpdate};
+use rumor::ring_key::RingKey;
+use ring_keys::RingKeys;
 use trace::{Trace, TraceKind};
 
 pub trait Suitability: Debug + Send + Sync {
@@ -68,18 +75,26 @@ pub struct Server {
     member_id: Arc<String>,
     pub member: Arc<RwLock<Member>>,
     pub member_list: MemberList,
-    ring_key: Arc<Option<SymKey>>,
+    ring_keys: Arc<RwLock<RingKeys>>,
+    auth: Arc<Option<Auth>>,
     rumor_list: RumorList,
     pub service_store: RumorStore<Service>,
     pub service_config_store: RumorStore<ServiceConfig>,
     pub service_file_store: RumorStore<ServiceFile>,
+    pub service_secret_store: RumorStore<ServiceSecret>,
     pub election_store: RumorStore<Election>,
     pub update_store: RumorStore<ElectionUpdate>,
+    pub release_blacklist_store: RumorStore<ReleaseBlacklist>,
+    pub departure_store: RumorStore<Departure>,
+    pub ring_key_store: RumorStore<RingKey>,
     swim_addr: Arc<RwLock<SocketAddr>>,
     gossip_addr: Arc<RwLock<SocketAddr>>,
     suitability_lookup: Arc<Box<Suitability>>,
     data_path: Arc<Option<PathBuf>>,
     dat_file: Arc<RwLock<Option<DatFile>>>,
+    departed: Arc<AtomicBool>,
+    expired_rumors: Arc<AtomicUsize>,
+    local_health: Arc<AtomicUsize>,
     // These are all here for testing support
     pause: Arc<AtomicBool>,
     pub trace: Arc<RwLock<Trace>>,
@@ -90,12 +105,14 @@ pub struct Server {
 
 impl Server {
     /// Create a new server, bound to the `addr`, hosting a particular `member`, and with a
-    /// `Trace` struct, a ring_key if you want encryption on the wire, and an optional server name.
+    /// `Trace` struct, ring keys if you want encryption on the wire, an auth if you want every
+    /// message authenticated by the ring CA, and an optional server name.
     pub fn new<T, U, P>(swim_addr: T,
                         gossip_addr: U,
                         mut member: Member,
                         trace: Trace,
-                        ring_key: Option<SymKey>,
+                        ring_keys: RingKeys,
+                        auth: Option<Auth>,
                         name: Option<String>,
                         data_path: Option<P>,
                         suitability_lookup: Box<Suitability>)
@@ -104,6 +121,13 @@ impl Server {
               U: ToSocketAddrs,
               P: Into<PathBuf> + AsRef<ffi::OsStr>
     {
+        if let Some(ref auth) = auth {
+            if auth.member_id() != member.get_id() {
+                return Err(Error::Unauthenticated(format!("member key was issued to {}, not {}",
+                                                          auth.member_id(),
+                                                          member.get_id())));
+            }
+        }
         let maybe_swim_socket_addr = swim_addr.to_socket_addrs().map(|mut iter| iter.next());
         let maybe_gossip_socket_addr = gossip_addr.to_socket_addrs().map(|mut iter| iter.next());
 
@@ -116,18 +140,26 @@ impl Server {
                        member_id: Arc::new(String::from(member.get_id())),
                        member: Arc::new(RwLock::new(member)),
                        member_list: MemberList::new(),
-                       ring_key: Arc::new(ring_key),
+                       ring_keys: Arc::new(RwLock::new(ring_keys)),
+                       auth: Arc::new(auth),
                        rumor_list: RumorList::default(),
                        service_store: RumorStore::default(),
                        service_config_store: RumorStore::default(),
                        service_file_store: RumorStore::default(),
+                       service_secret_store: RumorStore::default(),
                        election_store: RumorStore::default(),
                        update_store: RumorStore::default(),
+                       release_blacklist_store: RumorStore::default(),
+                       departure_store: RumorStore::default(),
+                       ring_key_store: RumorStore::default(),
                        swim_addr: Arc::new(RwLock::new(swim_socket_addr)),
                        gossip_addr: Arc::new(RwLock::new(gossip_socket_addr)),
                        suitability_lookup: Arc::new(suitability_lookup),
                        data_path: Arc::new(data_path.as_ref().map(|p| p.into())),
                        dat_file: Arc::new(RwLock::new(None)),
+                       departed: Arc::new(AtomicBool::new(false)),
+                       expired_rumors: Arc::new(AtomicUsize::new(0)),
+                       local_health: Arc::new(AtomicUsize::new(0)),
                        pause: Arc::new(AtomicBool::new(false)),
                        trace: Arc::new(RwLock::new(trace)),
                        swim_rounds: Arc::new(AtomicIsize::new(0)),
@@ -322,6 +354,58 @@ impl Server {
             .compare_and_swap(false, true, Ordering::Relaxed);
     }
 
+    /// Returns true if this server's member was departed from the ring.
+    pub fn departed(&self) -> bool {
+        self.departed.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of rumors dropped by the expire thread since this server started.
+    pub fn expired_rumors(&self) -> usize {
+        self.expired_rumors.load(Ordering::Relaxed)
+    }
+
+    /// Stops sharing the rumors dropped by the expire thread, and adds them to the number of
+    /// rumors it dropped.
+    fn record_expired_rumors(&self, removed: &[RumorKey]) {
+        for rumor_key in removed {
+            self.rumor_list.remove(rumor_key);
+        }
+        self.expired_rumors
+            .fetch_add(removed.len(), Ordering::Relaxed);
+    }
+
+    /// Returns our local health score: zero while we keep up with the failure detector, rising
+    /// up to `timing::LOCAL_HEALTH_MAX` while our acks are late. The outbound thread stretches
+    /// its timeouts by it, so an overloaded member is slow to suspect its healthy peers.
+    pub fn local_health(&self) -> usize {
+        self.local_health.load(Ordering::SeqCst)
+    }
+
+    /// Raises the local health score by one, as we look to be falling behind.
+    fn raise_local_health(&self) {
+        self.adjust_local_health(true);
+    }
+
+    /// Lowers the local health score by one, as we look to be keeping up.
+    fn lower_local_health(&self) {
+        self.adjust_local_health(false);
+    }
+
+    fn adjust_local_health(&self, raise: bool) {
+        loop {
+            let current = self.local_health.load(Ordering::SeqCst);
+            let next = if raise {
+                cmp::min(current + 1, timing::LOCAL_HEALTH_MAX)
+            } else {
+                current.saturating_sub(1)
+            };
+            if next == current ||
+               self.local_health.compare_and_swap(current, next, Ordering::SeqCst) == current {
+                return;
+            }
+        }
+    }
+
     /// Whether this server is currently paused.
     pub fn paused(&self) -> bool {
         self.pause.load(Ordering::Relaxed)
@@ -372,6 +456,7 @@ impl Server {
     /// Insert a member to the `MemberList`, and update its `RumorKey` appropriately.
     pub fn insert_member(&self, member: Member, health: Health) {
         let rk: RumorKey = RumorKey::from(&member);
+        health = self.checked_health(member.get_id(), health);
         // NOTE: This sucks so much right here. Check out how we allocate no matter what, because
         // of just how the logic goes. The value of the trace is really high, though, so we suck it
         // for now.
@@ -388,18 +473,39 @@ impl Server {
         }
     }
 
-    /// Given a membership record and some health, insert it into the Member List.
-    fn insert_member_from_rumor(&self, member: Member, mut health: Health) {
+    /// Marks a member we failed to probe as suspect, spreading our own suspicion of it even if we
+    /// already relay the suspicion of another member.
+    pub fn insert_suspect(&self, member: Member) {
+        let rk: RumorKey = RumorKey::from(&member);
+        let member_id = String::from(member.get_id());
+        let incarnation = member.get_incarnation();
+        self.insert_member(member, Health::Suspect);
+        if self.member_list
+               .raise_suspicion(&member_id, incarnation, self.member_id()) {
+            self.rumor_list.insert(rk);
+        }
+    }
+
+    /// Given a membership record and some health, insert it into the Member List. For a suspect
+    /// member, `suspected_by` is the member which raised the suspicion, rather than the one which
+    /// relayed it to us.
+    fn insert_member_from_rumor(&self, suspected_by: &str, member: Member, mut health: Health) {
         let mut incremented_incarnation = false;
         let rk: RumorKey = RumorKey::from(&member);
+        health = self.checked_health(member.get_id(), health);
         if member.get_id() == self.member_id() {
-            if health != Health::Alive {
+            // There is no refuting a departure; the supervisor has to shut down.
+            if health == Health::Departed {
+                self.departed.store(true, Ordering::Relaxed);
+            } else if health != Health::Alive {
                 let mut me = self.member.write().expect("Member lock is poisoned");
                 let mut incarnation = me.get_incarnation();
                 incarnation += 1;
                 me.set_incarnation(incarnation);
                 health = Health::Alive;
                 incremented_incarnation = true;
+                // Someone did not hear from us in time; we may be the slow one.
+                self.raise_local_health();
             }
         }
         // NOTE: This sucks so much right here. Check out how we allocate no matter what, because
@@ -417,17 +523,62 @@ impl Server {
                       trace_health);
             self.rumor_list.insert(rk);
         }
+        // Every other member raising a suspicion of a suspect member brings it closer to being
+        // confirmed. Relaying the suspicion of another member does not count.
+        if health == Health::Suspect && !suspected_by.is_empty() &&
+           suspected_by != self.member_id() &&
+           self.member_list
+               .confirm_suspicion(&trace_member_id, trace_incarnation, suspected_by) {
+            debug!("{} confirmed the suspicion of {}",
+                   suspected_by,
+                   trace_member_id);
+        }
+        // Members which thought we were gone may have expired our rumors; share them again.
+        if incremented_incarnation {
+            self.reannounce_service_groups();
+        }
+    }
+
+    /// Shares the service rumors of this member again, along with the configuration and files of
+    /// its service groups.
+    fn reannounce_service_groups(&self) {
+        let mut rumor_keys = Vec::new();
+        let mut service_groups = Vec::new();
+        self.service_store.with_keys(|(service_group, rumors)| {
+            if let Some(service) = rumors.get(self.member_id()) {
+                rumor_keys.push(RumorKey::from(service));
+                service_groups.push(service_group.clone());
+            }
+        });
+        for service_group in service_groups.iter() {
+            self.service_config_store
+                .with_rumors(service_group, |config| rumor_keys.push(RumorKey::from(config)));
+            self.service_file_store
+                .with_rumors(service_group, |file| rumor_keys.push(RumorKey::from(file)));
+        }
+        for rk in rumor_keys.into_iter() {
+            self.rumor_list.insert(rk);
+        }
     }
 
-    /// Insert members from a list of received rumors.
-    fn insert_member_from_rumors(&self, members: Vec<(Member, Health)>) {
-        for (member, health) in members.into_iter() {
-            self.insert_member_from_rumor(member, health);
+    /// Insert members from a list of received membership rumors.
+    fn insert_member_from_rumors(&self, memberships: Vec<ProtoMembership>) {
+        for membership in memberships.iter() {
+            self.insert_member_from_rumor(membership.get_suspected_by(),
+                                          Member::from(membership.get_member()),
+                                          Health::from(membership.get_health()));
         }
     }
 
-    /// Insert a service rumor into the service store.
-    pub fn insert_service(&self, service: Service) {
+    /// Insert a service rumor into the service store. Rumors of departed members are dropped, as
+    /// they can still be making their way around the ring.
+    pub fn insert_service(&self, mut service: Service) {
+        if self.is_departed(service.get_member_id()) {
+            return;
+        }
+        if service.get_member_id() == self.member_id() {
+            service = self.sign_rumor(service);
+        }
         let rk = RumorKey::from(&service);
         if self.service_store.insert(service) {
             self.rumor_list.insert(rk);
@@ -450,6 +601,191 @@ impl Server {
         }
     }
 
+    /// Insert a service secret rumor into the service secret store.
+    pub fn insert_service_secret(&self, service_secret: ServiceSecret) {
+        let rk = RumorKey::from(&service_secret);
+        if self.service_secret_store.insert(service_secret) {
+            self.rumor_list.insert(rk);
+        }
+    }
+
+    /// Insert a release blacklist rumor into the release blacklist store.
+    pub fn insert_release_blacklist(&self, mut release_blacklist: ReleaseBlacklist) {
+        if release_blacklist.get_member_id() == self.member_id() {
+            release_blacklist = self.sign_rumor(release_blacklist);
+        }
+        let rk = RumorKey::from(&release_blacklist);
+        if self.release_blacklist_store.insert(release_blacklist) {
+            self.rumor_list.insert(rk);
+        }
+    }
+
+    /// Insert a departure rumor into the departure store. Marks the member as departed, and
+    /// removes its service rumors so it leaves the census of every service group.
+    pub fn insert_departure(&self, departure: Departure) {
+        let rk = RumorKey::from(&departure);
+        let member_id = String::from(departure.get_member_id());
+        if member_id == self.member_id() {
+            self.departed.store(true, Ordering::Relaxed);
+        }
+        if self.departure_store.insert(departure) {
+            // Members we haven't heard of yet are marked as departed when they show up.
+            if self.member_list.contains_member(&member_id) &&
+               self.member_list
+                   .insert_health_by_id(&member_id, Health::Departed) {
+                self.rumor_list
+                    .insert(RumorKey::new(Rumor_Type::Member, member_id.clone(), ""));
+            }
+            let removed = self.service_store.remove_id(&member_id);
+            for rumor_key in removed.iter() {
+                self.rumor_list.remove(rumor_key);
+            }
+            info!("Departed member {}, removed {} service rumors",
+                  member_id,
+                  removed.len());
+            self.remove_elections_of(&member_id);
+            self.rumor_list.insert(rk);
+        }
+    }
+
+    /// Removes the election rumors in which the given departed member leads or is the current
+    /// candidate, and starts a new election with the next term in the service groups we are a
+    /// member of.
+    fn remove_elections_of(&self, member_id: &str) {
+        let mut elections = vec![];
+        let mut update_elections = vec![];
+        self.election_store.with_keys(|(service_group, rumors)| {
+            if let Some(election) = rumors.get("election") {
+                if election.get_member_id() == member_id {
+                    elections.push((service_group.clone(), election.get_term()));
+                }
+            }
+        });
+        self.update_store.with_keys(|(service_group, rumors)| {
+            if let Some(election) = rumors.get("election") {
+                if election.get_member_id() == member_id {
+                    update_elections.push((service_group.clone(), election.get_term()));
+                }
+            }
+        });
+
+        for (service_group, old_term) in elections {
+            info!("Removing the election of {} led by departed member {}",
+                  service_group,
+                  member_id);
+            self.election_store.remove(&service_group, "election");
+            self.rumor_list
+                .remove(&RumorKey::new(Rumor_Type::Election, "election", service_group.clone()));
+            if self.service_store.contains_rumor(&service_group, self.member_id()) {
+                match ServiceGroup::from_str(&service_group) {
+                    Ok(sg) => self.start_election(sg, old_term + 1),
+                    Err(e) => {
+                        error!("Failed to process service group from string '{}': {}",
+                               service_group,
+                               e)
+                    }
+                }
+            }
+        }
+        for (service_group, old_term) in update_elections {
+            info!("Removing the update election of {} led by departed member {}",
+                  service_group,
+                  member_id);
+            self.update_store.remove(&service_group, "election");
+            self.rumor_list
+                .remove(&RumorKey::new(Rumor_Type::ElectionUpdate,
+                                       "election",
+                                       service_group.clone()));
+            if self.service_store.contains_rumor(&service_group, self.member_id()) {
+                match ServiceGroup::from_str(&service_group) {
+                    Ok(sg) => self.start_update_election(sg, 0, old_term + 1),
+                    Err(e) => {
+                        error!("Failed to process service group from string '{}': {}",
+                               service_group,
+                               e)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Insert a ring key rumor into the ring key store. We decrypt with a revision of our ring key
+    /// as soon as we learn about it, encrypt with it once it is promoted to the primary key, and
+    /// stop decrypting with the older revisions once it retires them.
+    pub fn insert_ring_key(&self, ring_key: RingKey) {
+        if self.ring_keys
+               .read()
+               .expect("Ring keys lock poisoned")
+               .name() != Some(ring_key.get_name()) {
+            debug!("Ignoring a key of ring {}, which is not our ring",
+                   ring_key.get_name());
+            return;
+        }
+        let sym_key = match ring_key.sym_key() {
+            Ok(sym_key) => sym_key,
+            Err(e) => {
+                warn!("Ignoring ring key {}-{}: {}",
+                      ring_key.get_name(),
+                      ring_key.get_revision(),
+                      e);
+                return;
+            }
+        };
+        let rk = RumorKey::from(&ring_key);
+        let primary = ring_key.get_primary();
+        let retire = ring_key.get_retire();
+        if self.ring_key_store.insert(ring_key) {
+            let mut ring_keys = self.ring_keys.write().expect("Ring keys lock poisoned");
+            let name_with_rev = sym_key.name_with_rev();
+            if retire {
+                if ring_keys.retire(sym_key) {
+                    info!("Retired the ring keys older than {}", name_with_rev);
+                }
+            } else if primary {
+                if ring_keys.promote(sym_key) {
+                    info!("Encrypting with ring key {}", name_with_rev);
+                }
+            } else if ring_keys.add(sym_key) {
+                info!("Decrypting with ring key {}", name_with_rev);
+            }
+            self.rumor_list.insert(rk);
+        }
+    }
+
+    /// Returns the revisions of the ring key we decrypt with, the one we encrypt with first.
+    pub fn ring_keys(&self) -> RingKeys {
+        self.ring_keys
+            .read()
+            .expect("Ring keys lock poisoned")
+            .clone()
+    }
+
+    /// Returns true if we have a departure rumor for the given member.
+    fn is_departed(&self, member_id: &str) -> bool {
+        self.departure_store.contains_rumor("departure", member_id)
+    }
+
+    /// Returns the health to record for the given member. Only a departure rumor departs a
+    /// member: a member we have one for is departed whatever its reported health, and a member
+    /// reported as departed without one is only taken as confirmed dead.
+    fn checked_health(&self, member_id: &str, health: Health) -> Health {
+        if self.is_departed(member_id) {
+            Health::Departed
+        } else if health == Health::Departed {
+            Health::Confirmed
+        } else {
+            health
+        }
+    }
+
+    /// Returns true if the given member is confirmed dead or departed.
+    fn is_gone(&self, member_id: &str) -> bool {
+        self.member_list
+            .check_health_of_by_id(member_id, Health::Confirmed) ||
+        self.member_list
+            .check_health_of_by_id(member_id, Health::Departed)
+    }
+
     /// Get all the Member ID's who are present in a given service group.
     fn get_electorate(&self, key: &str) -> Vec<String> {
         let mut electorate = vec![];
@@ -486,6 +822,7 @@ impl Server {
         let suitability = self.suitability_lookup.get(&sg);
         let mut e = Election::new(self.member_id(), sg, suitability);
         e.set_term(term);
+        let mut e = self.sign_election(e);
         let ek = RumorKey::from(&e);
         if !self.check_quorum(e.key()) {
             e.no_quorum();
@@ -497,6 +834,7 @@ impl Server {
     pub fn start_update_election(&self, sg: ServiceGroup, suitability: u64, term: u64) {
         let mut e = ElectionUpdate::new(self.member_id(), sg, suitability);
         e.set_term(term);
+        let mut e = self.sign_election(e);
         let ek = RumorKey::from(&e);
         if !self.check_quorum(e.key()) {
             e.no_quorum();
@@ -509,6 +847,8 @@ impl Server {
     ///
     /// a) We are the leader, and we have lost quorum with the rest of the group.
     /// b) We are not the leader, and we have detected that the leader is confirmed dead.
+    ///
+    /// Elections led by a departed member are restarted as soon as we learn of its departure.
     pub fn restart_elections(&self) {
         let mut elections_to_restart = vec![];
         let mut update_elections_to_restart = vec![];
@@ -530,8 +870,8 @@ impl Server {
 
                     }
                 } else if election.is_finished() {
-                    if self.member_list.check_health_of_by_id(election.get_member_id(),
-                                                              Health::Confirmed) {
+                    if self.member_list
+                           .check_health_of_by_id(election.get_member_id(), Health::Confirmed) {
                         warn!("Restarting election with a new term as the leader is dead {}: {:?}",
                               self.member_id(),
                               election);
@@ -559,8 +899,8 @@ impl Server {
 
                     }
                 } else if election.is_finished() {
-                    if self.member_list.check_health_of_by_id(election.get_member_id(),
-                                                              Health::Confirmed) {
+                    if self.member_list
+                           .check_health_of_by_id(election.get_member_id(), Health::Confirmed) {
                         warn!("Restarting election with a new term as the leader is dead {}: {:?}",
                               self.member_id(),
                               election);
@@ -646,6 +986,7 @@ impl Server {
                         if num_votes == electorate.len() {
                             debug!("Election is finished: {:#?}", election);
                             election.finish();
+                            election = self.sign_election(election);
                         } else {
                             debug!("I have quorum, but election is not finished {}/{}",
                                    num_votes,
@@ -721,6 +1062,7 @@ impl Server {
                         if num_votes == electorate.len() {
                             debug!("Election is finished: {:#?}", election);
                             election.finish();
+                            election = self.sign_election(election);
                         } else {
                             debug!("I have quorum, but election is not finished {}/{}",
                                    num_votes,
@@ -757,12 +1099,47 @@ impl Server {
         }
     }
 
+    /// Returns the auth of this server, if messages are authenticated by the ring CA.
+    pub fn auth(&self) -> Option<&Auth> {
+        (*self.auth).as_ref()
+    }
+
+    /// Signs a rumor this server originated, if messages are authenticated.
+    fn sign_rumor<T>(&self, rumor: T) -> T
+        where T: Into<ProtoRumor> + From<ProtoRumor>
+    {
+        let mut proto: ProtoRumor = rumor.into();
+        if let Some(auth) = self.auth() {
+            if let Err(e) = auth::sign_rumor(auth.member_key(), &mut proto) {
+                error!("Cannot sign {:?} rumor: {}", proto.get_field_type(), e);
+            }
+        }
+        T::from(proto)
+    }
+
+    /// Signs an election this server is the candidate of, if messages are authenticated.
+    fn sign_election<T>(&self, election: T) -> T
+        where T: Into<ProtoRumor> + From<ProtoRumor>
+    {
+        let mut proto: ProtoRumor = election.into();
+        if let Some(auth) = self.auth() {
+            auth::sign_election(auth.member_key(), &mut proto);
+        }
+        T::from(proto)
+    }
+
     fn generate_wire(&self, payload: Vec<u8>) -> Result<Vec<u8>> {
-        message::generate_wire(payload, &self.ring_key)
+        let ring_keys = self.ring_keys.read().expect("Ring keys lock poisoned");
+        message::generate_wire(payload,
+                               ring_keys.primary(),
+                               self.auth().map(|auth| auth.member_key()))
     }
 
-    fn unwrap_wire(&self, payload: &[u8]) -> Result<Vec<u8>> {
-        message::unwrap_wire(payload, &self.ring_key)
+    /// Unwraps a message from the wire, returning its payload and, if messages are authenticated,
+    /// the ID of the member which sent it.
+    fn unwrap_wire(&self, payload: &[u8]) -> Result<(Vec<u8>, Option<String>)> {
+        let ring_keys = self.ring_keys.read().expect("Ring keys lock poisoned");
+        message::unwrap_wire(payload, &ring_keys, self.auth())
     }
 
     fn persist_data(&self) {
@@ -778,13 +1155,17 @@ impl Serialize for Server {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
     {
-        let mut strukt = try!(serializer.serialize_struct("butterfly", 6));
+        let mut strukt = try!(serializer.serialize_struct("butterfly", 10));
         try!(strukt.serialize_field("member", &self.member_list));
         try!(strukt.serialize_field("service", &self.service_store));
         try!(strukt.serialize_field("service_config", &self.service_config_store));
         try!(strukt.serialize_field("service_file", &self.service_file_store));
+        try!(strukt.serialize_field("service_secret", &self.service_secret_store));
         try!(strukt.serialize_field("election", &self.election_store));
         try!(strukt.serialize_field("election_update", &self.update_store));
+        try!(strukt.serialize_field("release_blacklist", &self.release_blacklist_store));
+        try!(strukt.serialize_field("departure", &self.departure_store));
+        try!(strukt.serialize_field("ring_key", &self.ring_key_store));
         strukt.end()
     }
 }
@@ -816,13 +1197,19 @@ fn persist_loop(server: Server) {
 
 #[cfg(test)]
 mod tests {
-    mod server {
+    pub mod server {
         use habitat_core::service::ServiceGroup;
         use server::{Server, Suitability};
         use server::timing::Timing;
-        use member::Member;
+        use member::{Health, Member};
+        use message::swim::{Membership as ProtoMembership,
+                            Membership_Health as ProtoMembership_Health};
+        use ring_keys::RingKeys;
+        use rumor::departure::Departure;
+        use rumor::election::{Election, ElectionUpdate};
         use trace::Trace;
         use std::path::PathBuf;
+        use std::str::FromStr;
         use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
 
         static SWIM_PORT: AtomicUsize = ATOMIC_USIZE_INIT;
@@ -836,7 +1223,7 @@ mod tests {
             }
         }
 
-        fn start_server() -> Server {
+        pub fn start_server() -> Server {
             SWIM_PORT.compare_and_swap(0, 6666, Ordering::Relaxed);
             GOSSIP_PORT.compare_and_swap(0, 7777, Ordering::Relaxed);
             let swim_port = SWIM_PORT.fetch_add(1, Ordering::Relaxed);
@@ -850,6 +1237,7 @@ mod tests {
                         &gossip_listen[..],
                         member,
                         Trace::default(),
+                        RingKeys::default(),
                         None,
                         None,
                         None::<PathBuf>,
@@ -871,6 +1259,7 @@ mod tests {
                                 &gossip_listen[..],
                                 member,
                                 Trace::default(),
+                                RingKeys::default(),
                                 None,
                                 None,
                                 None::<PathBuf>,
@@ -885,5 +1274,103 @@ mod tests {
                 .start(Timing::default())
                 .expect("Server failed to start");
         }
+
+        fn suspicion(member: &Member, suspected_by: &str) -> ProtoMembership {
+            let mut membership = ProtoMembership::new();
+            membership.set_member(member.proto.clone());
+            membership.set_health(ProtoMembership_Health::SUSPECT);
+            membership.set_suspected_by(String::from(suspected_by));
+            membership
+        }
+
+        fn confirmations_of(server: &Server, member_id: &str) -> usize {
+            let mut confirmations = 0;
+            server
+                .member_list
+                .with_suspects(|(id, suspicion)| if id == member_id {
+                                   confirmations = suspicion.confirmations();
+                               });
+            confirmations
+        }
+
+        #[test]
+        fn relayed_suspicions_count_once() {
+            let server = start_server();
+            let member = Member::default();
+            server.insert_member(member.clone(), Health::Alive);
+
+            // The same suspicion, relayed to us by three different members.
+            server.insert_member_from_rumors(vec![suspicion(&member, "one"),
+                                                  suspicion(&member, "one"),
+                                                  suspicion(&member, "one")]);
+            assert!(server
+                        .member_list
+                        .check_health_of_by_id(member.get_id(), Health::Suspect));
+            assert_eq!(confirmations_of(&server, member.get_id()), 1);
+            assert_eq!(server
+                           .member_list
+                           .membership_for(member.get_id())
+                           .get_suspected_by(),
+                       "one");
+
+            server.insert_member_from_rumors(vec![suspicion(&member, "two")]);
+            assert_eq!(confirmations_of(&server, member.get_id()), 2);
+        }
+
+        #[test]
+        fn our_own_suspicion_replaces_a_relayed_one() {
+            let server = start_server();
+            let member = Member::default();
+            server.insert_member(member.clone(), Health::Alive);
+            server.insert_member_from_rumors(vec![suspicion(&member, "one")]);
+
+            server.insert_suspect(member.clone());
+            assert_eq!(server
+                           .member_list
+                           .membership_for(member.get_id())
+                           .get_suspected_by(),
+                       server.member_id());
+            server.insert_member_from_rumors(vec![suspicion(&member, server.member_id())]);
+            assert_eq!(confirmations_of(&server, member.get_id()), 1);
+        }
+
+        #[test]
+        fn departed_health_needs_a_departure_rumor() {
+            let server = start_server();
+            let member = Member::default();
+            let member_id = String::from(member.get_id());
+
+            server.insert_member_from_rumor("", member, Health::Departed);
+            assert!(server
+                        .member_list
+                        .check_health_of_by_id(&member_id, Health::Confirmed));
+
+            server.insert_departure(Departure::new(member_id.clone()));
+            assert!(server
+                        .member_list
+                        .check_health_of_by_id(&member_id, Health::Departed));
+        }
+
+        #[test]
+        fn departure_removes_the_elections_of_the_departed_member() {
+            let server = start_server();
+            let member = Member::default();
+            let sg = ServiceGroup::from_str("witcher.prod").unwrap();
+            server
+                .election_store
+                .insert(Election::new(member.get_id(), sg.clone(), 0));
+            server
+                .update_store
+                .insert(ElectionUpdate::new(member.get_id(), sg, 0));
+
+            server.insert_departure(Departure::new(member.get_id()));
+
+            assert!(!server
+                         .election_store
+                         .contains_rumor("witcher.prod", "election"));
+            assert!(!server
+                         .update_store
+                         .contains_rumor("witcher.prod", "election"));
+        }
     }
 }
@@ -109,7 +109,9 @@ impl Outbound {
 
             self.server.update_swim_round();
 
-            let long_wait = self.timing.next_protocol_period();
+            let long_wait = self.timing
+                .with_local_health(self.server.local_health())
+                .next_protocol_period();
 
             let check_list = self.server
                 .member_list
@@ -121,12 +123,15 @@ impl Outbound {
 
             for member in check_list {
                 if self.server.member_list.pingable(&member) {
+                    // The less healthy we are, the longer we give the member to answer.
+                    let timing = self.timing.with_local_health(self.server.local_health());
+
                     // This is the timeout for the next protocol period - if we
                     // complete faster than this, we want to wait in the end
                     // until this timer expires.
-                    let next_protocol_period = self.timing.next_protocol_period();
+                    let next_protocol_period = timing.next_protocol_period();
 
-                    self.probe(member);
+                    self.probe(member, &timing);
 
                     if SteadyTime::now() <= next_protocol_period {
                         let wait_time = (next_protocol_period - SteadyTime::now())
@@ -164,14 +169,17 @@ impl Outbound {
     /// PING_RECV_QUEUE_EMPTY_SLEEP_MS, and try again.
     ///
     /// If we don't receive anything at all in the Ping/PingReq loop, we mark the member as Suspect.
-    fn probe(&mut self, member: Member) {
+    ///
+    /// The `timing` is stretched by our local health score, which an ack arriving in time for
+    /// our ping lowers, and a direct ack arriving only after our ping timed out raises.
+    fn probe(&mut self, member: Member, timing: &Timing) {
         let addr = member.swim_socket_address();
 
         trace_it!(PROBE: &self.server, TraceKind::ProbeBegin, member.get_id(), addr);
 
         // Ping the member, and wait for the ack.
         ping(&self.server, &self.socket, &member, addr, None);
-        if self.recv_ack(&member, addr, AckFrom::Ping) {
+        if self.recv_ack(&member, addr, AckFrom::Ping, timing) {
             trace_it!(PROBE: &self.server, TraceKind::ProbeAckReceived, member.get_id(), addr);
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
             return;
@@ -186,24 +194,29 @@ impl Outbound {
                           pingreq_target.get_address());
                 pingreq(&self.server, &self.socket, &pingreq_target, &member);
             });
-        if !self.recv_ack(&member, addr, AckFrom::PingReq) {
+        if !self.recv_ack(&member, addr, AckFrom::PingReq, timing) {
             // We mark as suspect when we fail to get a response from the PingReq. That moves us
             // into the suspicion phase, where anyone marked as suspect has a certain number of
             // protocol periods to recover.
             warn!("Marking {} as Suspect", member.get_id());
             trace_it!(PROBE: &self.server, TraceKind::ProbeSuspect, member.get_id(), addr);
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
-            self.server.insert_member(member, Health::Suspect);
+            self.server.insert_suspect(member);
         } else {
             trace_it!(PROBE: &self.server, TraceKind::ProbeComplete, member.get_id(), addr);
         }
     }
 
     /// Listen for an ack from the `Inbound` thread.
-    fn recv_ack(&mut self, member: &Member, addr: SocketAddr, ack_from: AckFrom) -> bool {
-        let timeout = match ack_from {
-            AckFrom::Ping => self.timing.ping_timeout(),
-            AckFrom::PingReq => self.timing.pingreq_timeout(),
+    fn recv_ack(&mut self,
+                member: &Member,
+                addr: SocketAddr,
+                ack_from: AckFrom,
+                timing: &Timing)
+                -> bool {
+        let (timeout, direct_ack_is_late) = match ack_from {
+            AckFrom::Ping => (timing.ping_timeout(), false),
+            AckFrom::PingReq => (timing.pingreq_timeout(), true),
         };
         loop {
             match self.rx_inbound.try_recv() {
@@ -221,6 +234,14 @@ impl Outbound {
                     // sent the ack, not the one we received on the socket.
                     if !swim.get_ack().has_forward_to() {
                         ack_from.set_address(format!("{}", real_addr.ip()));
+                        if direct_ack_is_late {
+                            debug!("Late ack from {}@{}; raising our local health score",
+                                   ack_from.get_id(),
+                                   real_addr);
+                            self.server.raise_local_health();
+                        } else {
+                            self.server.lower_local_health();
+                        }
                     }
                     let ack_from_member: Member = ack_from.into();
                     self.server.insert_member(ack_from_member, Health::Alive);
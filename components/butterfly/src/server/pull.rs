@@ -24,6 +24,7 @@ use protobuf;
 use zmq;
 
 use ZMQ_CONTEXT;
+use error::{Error, Result};
 use server::Server;
 use message::swim::{Rumor, Rumor_Type};
 use trace::TraceKind;
@@ -67,8 +68,8 @@ impl Pull {
                     continue 'recv;
                 }
             };
-            let payload = match self.server.unwrap_wire(&msg) {
-                Ok(payload) => payload,
+            let (payload, sender) = match self.server.unwrap_wire(&msg) {
+                Ok(unwrapped) => unwrapped,
                 Err(e) => {
                     // NOTE: In the future, we might want to blacklist people who send us
                     // garbage all the time.
@@ -88,12 +89,31 @@ impl Pull {
                       proto.get_from_id());
                 continue 'recv;
             }
+            if let Some(sender) = sender {
+                if let Err(e) = self.authenticate(&sender, &proto) {
+                    warn!("Not processing {:?} rumor sent by {}: {}",
+                          proto.get_field_type(),
+                          sender,
+                          e);
+                    continue 'recv;
+                }
+            } else if proto.get_field_type() == Rumor_Type::RingKey {
+                // Anyone holding the ring key could otherwise hand out a revision the ring never
+                // moves on from, so only operators of an authenticated ring can rotate it.
+                warn!("Not processing ring key rumor from {}: ring keys are only accepted by \
+                       rings authenticating their members",
+                      proto.get_from_id());
+                continue 'recv;
+            }
             trace_it!(GOSSIP: &self.server, TraceKind::RecvRumor, proto.get_from_id(), &proto);
             match proto.get_field_type() {
                 Rumor_Type::Member => {
                     let member = proto.mut_member().take_member().into();
                     let health = proto.mut_member().get_health().into();
-                    self.server.insert_member_from_rumor(member, health);
+                    self.server
+                        .insert_member_from_rumor(proto.get_member().get_suspected_by(),
+                                                  member,
+                                                  health);
                 }
                 Rumor_Type::Service => {
                     self.server.insert_service(proto.into());
@@ -110,9 +130,83 @@ impl Pull {
                 Rumor_Type::ElectionUpdate => {
                     self.server.insert_update_election(proto.into());
                 }
+                Rumor_Type::ReleaseBlacklist => {
+                    self.server.insert_release_blacklist(proto.into());
+                }
+                Rumor_Type::ServiceSecret => {
+                    self.server.insert_service_secret(proto.into());
+                }
+                Rumor_Type::Departure => {
+                    self.server.insert_departure(proto.into());
+                }
+                Rumor_Type::RingKey => {
+                    self.server.insert_ring_key(proto.into());
+                }
                 Rumor_Type::Fake |
                 Rumor_Type::Fake2 => debug!("Nothing to do for fake rumor types"),
             }
         }
     }
+    /// Checks that a rumor sent by an authenticated member can be trusted. Membership rumors are
+    /// rewritten by every member as they spread, so they must come from the member which sent
+    /// them. Elections must come from a member of the service group, and carry the signatures of
+    /// their candidate and of every voter. Every other rumor must carry the signature of the one
+    /// who originated it: services and release blacklists can only be signed by the member they
+    /// are about, and rumors which change the whole ring only by an operator.
+    fn authenticate(&self, sender: &str, rumor: &Rumor) -> Result<()> {
+        let auth = match self.server.auth() {
+            Some(auth) => auth,
+            None => return Ok(()),
+        };
+        match rumor.get_field_type() {
+            Rumor_Type::Member => {
+                if rumor.get_from_id() != sender {
+                    return Err(Error::Unauthenticated(format!("membership rumor from {} was \
+                                                               relayed",
+                                                              rumor.get_from_id())));
+                }
+            }
+            Rumor_Type::Election |
+            Rumor_Type::ElectionUpdate => {
+                let service_group = rumor.get_election().get_service_group();
+                if !self.server
+                        .service_store
+                        .contains_rumor(service_group, sender) {
+                    return Err(Error::Unauthenticated(format!("{} is not a member of {}",
+                                                              sender,
+                                                              service_group)));
+                }
+                try!(auth.verify_election(rumor));
+            }
+            Rumor_Type::Service => {
+                let signer = try!(auth.verify_rumor(rumor));
+                if signer != rumor.get_service().get_member_id() {
+                    return Err(Error::Unauthenticated(format!("service rumor of {} was signed \
+                                                               by {}",
+                                                              rumor.get_service().get_member_id(),
+                                                              signer)));
+                }
+            }
+            Rumor_Type::ReleaseBlacklist => {
+                let signer = try!(auth.verify_rumor(rumor));
+                if signer != rumor.get_release_blacklist().get_member_id() {
+                    let member_id = rumor.get_release_blacklist().get_member_id();
+                    return Err(Error::Unauthenticated(format!("release blacklist of {} was \
+                                                               signed by {}",
+                                                              member_id,
+                                                              signer)));
+                }
+            }
+            Rumor_Type::ServiceConfig |
+            Rumor_Type::ServiceFile |
+            Rumor_Type::ServiceSecret |
+            Rumor_Type::Departure |
+            Rumor_Type::RingKey => {
+                try!(auth.verify_operator_rumor(rumor));
+            }
+            Rumor_Type::Fake |
+            Rumor_Type::Fake2 => {}
+        }
+        Ok(())
+    }
 }
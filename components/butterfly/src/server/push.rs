@@ -26,8 +26,7 @@ use time::SteadyTime;
 use zmq;
 
 use ZMQ_CONTEXT;
-use message::swim::{Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type, Member as ProtoMember,
-                    Membership as ProtoMembership};
+use message::swim::{Rumor as ProtoRumor, Rumor_Type as ProtoRumor_Type};
 use rumor::{RumorKey, RumorVec};
 use member::Member;
 use server::Server;
@@ -279,6 +278,58 @@ impl PushWorker {
                         }
                     }
                 }
+                ProtoRumor_Type::ReleaseBlacklist => {
+                    match self.server
+                              .release_blacklist_store
+                              .write_to_bytes(&rumor_key.key, &rumor_key.id) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!("Could not write our own rumor to bytes; abandoning sending \
+                                      rumor: {:?}",
+                                     e);
+                            continue 'rumorlist;
+                        }
+                    }
+                }
+                ProtoRumor_Type::ServiceSecret => {
+                    match self.server
+                              .service_secret_store
+                              .write_to_bytes(&rumor_key.key, &rumor_key.id) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!("Could not write our own rumor to bytes; abandoning sending \
+                                      rumor: {:?}",
+                                     e);
+                            continue 'rumorlist;
+                        }
+                    }
+                }
+                ProtoRumor_Type::Departure => {
+                    match self.server
+                              .departure_store
+                              .write_to_bytes(&rumor_key.key, &rumor_key.id) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!("Could not write our own rumor to bytes; abandoning sending \
+                                      rumor: {:?}",
+                                     e);
+                            continue 'rumorlist;
+                        }
+                    }
+                }
+                ProtoRumor_Type::RingKey => {
+                    match self.server
+                              .ring_key_store
+                              .write_to_bytes(&rumor_key.key, &rumor_key.id) {
+                        Ok(bytes) => bytes,
+                        Err(e) => {
+                            println!("Could not write our own rumor to bytes; abandoning sending \
+                                      rumor: {:?}",
+                                     e);
+                            continue 'rumorlist;
+                        }
+                    }
+                }
                 ProtoRumor_Type::Fake |
                 ProtoRumor_Type::Fake2 => {
                     debug!("You have fake rumors; how odd!");
@@ -304,20 +355,9 @@ impl PushWorker {
 
     /// Given a rumorkey, creates a protobuf rumor for sharing.
     fn create_member_rumor(&self, rumor_key: &RumorKey) -> ProtoRumor {
-        let mut member: ProtoMember = ProtoMember::new();
-        self.server
+        let membership = self.server
             .member_list
-            .with_member(&rumor_key.key(), |m| {
-                // TODO: This should not stand
-                member = m.unwrap().proto.clone();
-            });
-        let mut membership = ProtoMembership::new();
-        membership.set_member(member);
-        membership.set_health(self.server
-                                  .member_list
-                                  .health_of_by_id(&rumor_key.key())
-                                  .unwrap()
-                                  .into());
+            .membership_for(&rumor_key.key());
         let mut rumor = ProtoRumor::new();
         rumor.set_field_type(ProtoRumor_Type::Member);
         rumor.set_member(membership);
@@ -12,16 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
+
 use time::{SteadyTime, Duration as TimeDuration};
 
 /// How long to wait for an Ack after we ping
 const PING_TIMING_DEFAULT_MS: i64 = 1000;
 /// How long to wait for an Ack after we PingReq - should be at least 2x the PING_TIMING_DEFAULT_MS
 const PINGREQ_TIMING_DEFAULT_MS: i64 = 2100;
-/// How many protocol periods before a suspect member is marked as confirmed.
+/// How many protocol periods before a suspect member is marked as confirmed, at the least; scaled
+/// by the log of the size of the ring.
 const SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS: i64 = 3;
+/// How many times the least suspicion timeout a suspect member has before it is marked as
+/// confirmed, if nobody else suspects it.
+const SUSPICION_MAX_TIMEOUT_DEFAULT_MULTIPLIER: i64 = 6;
+/// How many other members independently suspecting a member bring its suspicion timeout down to
+/// the least one.
+const SUSPICION_CONFIRMATIONS_DEFAULT: usize = 3;
 /// How long is the gossip period
 const GOSSIP_PERIOD_DEFAULT_MS: i64 = 1000;
+/// How long the rumors of a confirmed member, or of a service group without live members, are
+/// kept before they are expired
+const RUMOR_TTL_DEFAULT_MS: i64 = 24 * 60 * 60 * 1000;
+/// How long the configuration, files, secrets and release blacklists of a service group without
+/// live members are kept before they are expired. Operators publish them, often before any member
+/// of the service group starts, so they outlive the rumors of the members.
+const OPERATOR_RUMOR_TTL_DEFAULT_MS: i64 = 30 * 24 * 60 * 60 * 1000;
+/// The highest local health score; the ping and pingreq timeouts are stretched by up to one more
+/// than this many times.
+pub const LOCAL_HEALTH_MAX: usize = 8;
 
 /// The timing of the outbound threads.
 #[derive(Debug, Clone)]
@@ -30,6 +49,10 @@ pub struct Timing {
     pub pingreq_ms: i64,
     pub gossip_period_ms: i64,
     pub suspicion_timeout_protocol_periods: i64,
+    pub suspicion_max_timeout_multiplier: i64,
+    pub suspicion_confirmations: usize,
+    pub rumor_ttl_ms: i64,
+    pub operator_rumor_ttl_ms: i64,
 }
 
 impl Default for Timing {
@@ -39,6 +62,10 @@ impl Default for Timing {
             pingreq_ms: PINGREQ_TIMING_DEFAULT_MS,
             gossip_period_ms: GOSSIP_PERIOD_DEFAULT_MS,
             suspicion_timeout_protocol_periods: SUSPICION_TIMEOUT_DEFAULT_PROTOCOL_PERIODS,
+            suspicion_max_timeout_multiplier: SUSPICION_MAX_TIMEOUT_DEFAULT_MULTIPLIER,
+            suspicion_confirmations: SUSPICION_CONFIRMATIONS_DEFAULT,
+            rumor_ttl_ms: RUMOR_TTL_DEFAULT_MS,
+            operator_rumor_ttl_ms: OPERATOR_RUMOR_TTL_DEFAULT_MS,
         }
     }
 }
@@ -55,9 +82,24 @@ impl Timing {
             pingreq_ms: pingreq_ms,
             gossip_period_ms: gossip_period_ms,
             suspicion_timeout_protocol_periods: suspicion_timeout_protocol_periods,
+            suspicion_max_timeout_multiplier: SUSPICION_MAX_TIMEOUT_DEFAULT_MULTIPLIER,
+            suspicion_confirmations: SUSPICION_CONFIRMATIONS_DEFAULT,
+            rumor_ttl_ms: RUMOR_TTL_DEFAULT_MS,
+            operator_rumor_ttl_ms: OPERATOR_RUMOR_TTL_DEFAULT_MS,
         }
     }
 
+    /// Returns this timing with the ping and pingreq timeouts, and so the protocol period,
+    /// stretched by our local health score. The higher the score, the less healthy we think we
+    /// are, and the longer we give other members to answer before we suspect them.
+    pub fn with_local_health(&self, local_health: usize) -> Timing {
+        let multiplier = (cmp::min(local_health, LOCAL_HEALTH_MAX) + 1) as i64;
+        let mut timing = self.clone();
+        timing.ping_ms = self.ping_ms * multiplier;
+        timing.pingreq_ms = self.pingreq_ms * multiplier;
+        timing
+    }
+
     /// When should this gossip period expire
     pub fn gossip_timeout(&self) -> SteadyTime {
         SteadyTime::now() + TimeDuration::milliseconds(self.gossip_period_ms)
@@ -83,9 +125,97 @@ impl Timing {
         SteadyTime::now() + TimeDuration::milliseconds(self.ping_ms + self.pingreq_ms)
     }
 
-    /// How long before this suspect entry times out
-    pub fn suspicion_timeout_duration(&self) -> TimeDuration {
-        TimeDuration::milliseconds(self.protocol_period_ms() *
-                                   self.suspicion_timeout_protocol_periods)
+    /// How long before a suspect entry times out, in a ring of `members` members, once
+    /// `confirmations` other members have raised a suspicion of it too. Members which merely relay
+    /// a suspicion raised by another do not count.
+    ///
+    /// The timeout starts at `suspicion_max_timeout_multiplier` times the least timeout, and
+    /// shrinks towards the least one as confirmations arrive, reaching it after
+    /// `suspicion_confirmations` of them. The least timeout grows with the log of the size of the
+    /// ring, since suspicion takes longer to spread through a larger one.
+    pub fn suspicion_timeout_duration(&self, members: usize, confirmations: usize) -> TimeDuration {
+        let scale = (members as f64).log10().max(1.0);
+        let min_ms = (self.protocol_period_ms() as f64 *
+                      self.suspicion_timeout_protocol_periods as f64 * scale) as i64;
+        // Only the members other than us and the suspect can confirm the suspicion.
+        let expected = cmp::min(self.suspicion_confirmations, members.saturating_sub(2));
+        if expected == 0 {
+            return TimeDuration::milliseconds(min_ms);
+        }
+        let max_ms = min_ms * self.suspicion_max_timeout_multiplier;
+        let fraction = ((cmp::min(confirmations, expected) + 1) as f64).ln() /
+                       ((expected + 1) as f64).ln();
+        let timeout_ms = max_ms - ((max_ms - min_ms) as f64 * fraction) as i64;
+        TimeDuration::milliseconds(cmp::max(timeout_ms, min_ms))
+    }
+
+    /// How long the rumors of a confirmed member, or of a service group without live members,
+    /// are kept
+    pub fn rumor_ttl_duration(&self) -> TimeDuration {
+        TimeDuration::milliseconds(self.rumor_ttl_ms)
+    }
+
+    /// How long the rumors published by operators for a service group without live members are
+    /// kept; never shorter than the rumor TTL
+    pub fn operator_rumor_ttl_duration(&self) -> TimeDuration {
+        TimeDuration::milliseconds(cmp::max(self.operator_rumor_ttl_ms, self.rumor_ttl_ms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use server::timing::{Timing, LOCAL_HEALTH_MAX};
+
+    fn periods(timing: &Timing, count: i64) -> i64 {
+        timing.protocol_period_ms() * count
+    }
+
+    #[test]
+    fn suspicion_timeout_is_the_least_in_small_rings() {
+        let timing = Timing::default();
+        for members in 0..3 {
+            assert_eq!(timing
+                           .suspicion_timeout_duration(members, 0)
+                           .num_milliseconds(),
+                       periods(&timing, 3));
+        }
+    }
+
+    #[test]
+    fn suspicion_timeout_scales_with_ring_size() {
+        let timing = Timing::default();
+        let small = timing.suspicion_timeout_duration(10, 3);
+        let large = timing.suspicion_timeout_duration(300, 3);
+        assert_eq!(small.num_milliseconds(), periods(&timing, 3));
+        assert!(large > small);
+        // 3 protocol periods, times log10(300)
+        assert_eq!(large.num_milliseconds(), 23_037);
+    }
+
+    #[test]
+    fn suspicion_timeout_shrinks_with_confirmations() {
+        let timing = Timing::default();
+        let mut last = timing.suspicion_timeout_duration(300, 0);
+        assert_eq!(last.num_milliseconds(), 23_037 * 6);
+        for confirmations in 1..4 {
+            let timeout = timing.suspicion_timeout_duration(300, confirmations);
+            assert!(timeout < last);
+            last = timeout;
+        }
+        assert_eq!(last.num_milliseconds(), 23_037);
+        assert_eq!(timing.suspicion_timeout_duration(300, 10), last);
+    }
+
+    #[test]
+    fn with_local_health_stretches_the_protocol_period() {
+        let timing = Timing::default();
+        assert_eq!(timing.with_local_health(0).protocol_period_ms(),
+                   timing.protocol_period_ms());
+        let unhealthy = timing.with_local_health(2);
+        assert_eq!(unhealthy.ping_ms, timing.ping_ms * 3);
+        assert_eq!(unhealthy.pingreq_ms, timing.pingreq_ms * 3);
+        assert_eq!(unhealthy.gossip_period_ms, timing.gossip_period_ms);
+        assert_eq!(timing.with_local_health(LOCAL_HEALTH_MAX + 10).ping_ms,
+                   timing.ping_ms * (LOCAL_HEALTH_MAX as i64 + 1));
     }
 }
@@ -386,6 +386,27 @@ macro_rules! trace_it {
                                 $payload.get_election().get_status(),
                                 $payload.get_election().get_votes())
                     }
+                    Rumor_Type::ReleaseBlacklist => {
+                        format!("{}-{}-{}",
+                                $payload.get_release_blacklist().get_member_id(),
+                                $payload.get_release_blacklist().get_service_group(),
+                                $payload.get_release_blacklist().get_pkg())
+                    }
+                    Rumor_Type::ServiceSecret => {
+                        format!("{}-{}-{}",
+                                $payload.get_service_secret().get_service_group(),
+                                $payload.get_service_secret().get_incarnation(),
+                                $payload.get_service_secret().get_name())
+                    }
+                    Rumor_Type::Departure => {
+                        format!("{}", $payload.get_departure().get_member_id())
+                    }
+                    Rumor_Type::RingKey => {
+                        format!("{}-{}-{}",
+                                $payload.get_ring_key().get_name(),
+                                $payload.get_ring_key().get_revision(),
+                                $payload.get_ring_key().get_primary())
+                    }
                     Rumor_Type::Fake | Rumor_Type::Fake2 => format!("nothing-to-see"),
                 };
 
@@ -0,0 +1,124 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use habitat_butterfly::client::Client;
+use habitat_butterfly::member::Health;
+use habitat_butterfly::message::swim::Election_Status;
+use habitat_core::crypto::{MemberKey, SigKeyPair};
+use habitat_core::service::ServiceGroup;
+
+use btest;
+
+#[test]
+fn authenticated_members_share_rumors() {
+    let ring_ca = SigKeyPair::generate_in_memory("wolverine-ca")
+        .expect("Failed to generate an in memory ring CA");
+    let mut net = btest::SwimNet::new_ring_ca(2, &ring_ca);
+    net.connect(0, 1);
+    assert_wait_for_health_of!(net, [0..2, 0..2], Health::Alive);
+    net.add_service(0, "core/beast/1.2.3/20161208121212");
+    net.wait_for_gossip_rounds(2);
+    net[1]
+        .service_store
+        .with_rumor("beast.prod", net[0].member_id(), |u| assert!(u.is_some()));
+}
+
+#[test]
+fn members_issued_by_another_ring_ca_are_confirmed() {
+    let ring_ca = SigKeyPair::generate_in_memory("wolverine-ca")
+        .expect("Failed to generate an in memory ring CA");
+    let other_ca = SigKeyPair::generate_in_memory("sabretooth-ca")
+        .expect("Failed to generate an in memory ring CA");
+    let mut net = btest::SwimNet::new_ring_ca(1, &ring_ca);
+    net.members
+        .push(btest::start_server_with_ring_ca("1", None, Some(&other_ca), 0));
+    net.connect(0, 1);
+    assert_wait_for_health_of!(net, 0, 1, Health::Confirmed);
+}
+
+#[test]
+fn unsigned_rumors_are_not_shared() {
+    let ring_ca = SigKeyPair::generate_in_memory("wolverine-ca")
+        .expect("Failed to generate an in memory ring CA");
+    let mut net = btest::SwimNet::new_ring_ca(2, &ring_ca);
+    net.mesh();
+    net.add_service_config(0, "witcher", "tcp-backlog = 128");
+    net.wait_for_gossip_rounds(2);
+    net[1]
+        .service_config_store
+        .with_rumor("witcher.prod", "service_config", |u| assert!(u.is_none()));
+}
+
+#[test]
+fn service_config_via_client_with_operator_key() {
+    let ring_ca = SigKeyPair::generate_in_memory("wolverine-ca")
+        .expect("Failed to generate an in memory ring CA");
+    let mut net = btest::SwimNet::new_ring_ca(2, &ring_ca);
+    net.mesh();
+    net.wait_for_gossip_rounds(1);
+    let operator_key = MemberKey::generate_operator("operator", &ring_ca)
+        .expect("Cannot issue an operator key");
+    let mut client = Client::new(net[0].gossip_addr(), None, Some(operator_key))
+        .expect("Cannot create Butterfly Client");
+    let payload = Vec::from("I want to get lost in you, tokyo".as_bytes());
+    client
+        .send_service_config(ServiceGroup::new("witcher", "prod", None).unwrap(),
+                             0,
+                             payload,
+                             false)
+        .expect("Cannot send the service configuration");
+    net.wait_for_gossip_rounds(1);
+    net[1]
+        .service_config_store
+        .with_rumor("witcher.prod", "service_config", |u| assert!(u.is_some()));
+}
+
+#[test]
+fn service_config_via_client_with_member_key_is_not_shared() {
+    let ring_ca = SigKeyPair::generate_in_memory("wolverine-ca")
+        .expect("Failed to generate an in memory ring CA");
+    let mut net = btest::SwimNet::new_ring_ca(2, &ring_ca);
+    net.mesh();
+    net.wait_for_gossip_rounds(1);
+    let member_key = MemberKey::generate("operator", &ring_ca).expect("Cannot issue a member key");
+    let mut client = Client::new(net[0].gossip_addr(), None, Some(member_key))
+        .expect("Cannot create Butterfly Client");
+    let payload = Vec::from("I want to get lost in you, tokyo".as_bytes());
+    client
+        .send_service_config(ServiceGroup::new("witcher", "prod", None).unwrap(),
+                             0,
+                             payload,
+                             false)
+        .expect("Cannot send the service configuration");
+    net.wait_for_gossip_rounds(2);
+    for member in 0..2 {
+        net[member]
+            .service_config_store
+            .with_rumor("witcher.prod", "service_config", |u| assert!(u.is_none()));
+    }
+}
+
+#[test]
+fn authenticated_members_run_election() {
+    let ring_ca = SigKeyPair::generate_in_memory("wolverine-ca")
+        .expect("Failed to generate an in memory ring CA");
+    let mut net = btest::SwimNet::new_ring_ca(3, &ring_ca);
+    net.mesh();
+    net.add_service(0, "core/witcher/1.2.3/20161208121212");
+    net.add_service(1, "core/witcher/1.2.3/20161208121212");
+    net.add_service(2, "core/witcher/1.2.3/20161208121212");
+    net.add_election(0, "witcher");
+    assert_wait_for_election_status!(net, [0..3], "witcher.prod", Election_Status::Finished);
+    assert_wait_for_equal_election!(net, [0..3, 0..3], "witcher.prod");
+}
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use habitat_butterfly::client::Client;
 use habitat_butterfly::member::Health;
+use habitat_core::crypto::{MemberKey, SigKeyPair};
 use habitat_core::crypto::keys::sym_key::SymKey;
 
 use btest;
@@ -30,3 +32,81 @@ fn symmetric_encryption_of_wire_payloads() {
         .service_store
         .with_rumor("beast.prod", net[0].member_id(), |u| assert!(u.is_some()));
 }
+
+fn rev_key(rev: &str) -> SymKey {
+    let mut key =
+        SymKey::generate_in_memory("wolverine").expect("Failed to generate an in memory symkey");
+    key.rev = String::from(rev);
+    key
+}
+
+#[test]
+fn ring_key_rotation_without_downtime() {
+    let old_key = rev_key("20170101000000");
+    let new_key = rev_key("20170102000000");
+    let ring_ca = SigKeyPair::generate_in_memory("wolverine-ca")
+        .expect("Failed to generate an in memory ring CA");
+    let mut net = btest::SwimNet::new_ring_encryption_and_ca(3, Some(old_key.clone()), &ring_ca);
+    net.mesh();
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+
+    let operator_key = MemberKey::generate_operator("operator", &ring_ca)
+        .expect("Cannot issue an operator key");
+    let mut client = Client::new(net[0].gossip_addr(), Some(old_key.clone()), Some(operator_key))
+        .expect("Cannot create Butterfly Client");
+    client
+        .send_ring_key(&new_key, false)
+        .expect("Cannot send the ring key");
+    net.wait_for_gossip_rounds(2);
+    for server in net.iter() {
+        let ring_keys = server.ring_keys();
+        assert!(ring_keys.contains(&new_key.rev));
+        assert_eq!(ring_keys.primary().unwrap().rev, old_key.rev);
+    }
+
+    client
+        .send_ring_key(&new_key, true)
+        .expect("Cannot promote the ring key");
+    net.wait_for_gossip_rounds(2);
+    for server in net.iter() {
+        assert_eq!(server.ring_keys().primary().unwrap().rev, new_key.rev);
+    }
+    net.add_service(0, "core/beast/1.2.3/20161208121212");
+    net.wait_for_gossip_rounds(2);
+    net[2]
+        .service_store
+        .with_rumor("beast.prod", net[0].member_id(), |u| assert!(u.is_some()));
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+
+    client
+        .retire_ring_keys(&new_key)
+        .expect("Cannot retire the ring keys");
+    net.wait_for_gossip_rounds(2);
+    for server in net.iter() {
+        let ring_keys = server.ring_keys();
+        assert!(!ring_keys.contains(&old_key.rev));
+        assert_eq!(ring_keys.keys().len(), 1);
+    }
+    assert_wait_for_health_of!(net, [0..3, 0..3], Health::Alive);
+}
+
+#[test]
+fn ring_keys_need_an_authenticated_ring() {
+    let old_key = rev_key("20170101000000");
+    let new_key = rev_key("20170102000000");
+    let mut net = btest::SwimNet::new_ring_encryption(2, Some(old_key.clone()));
+    net.mesh();
+    assert_wait_for_health_of!(net, [0..2, 0..2], Health::Alive);
+
+    let mut client = Client::new(net[0].gossip_addr(), Some(old_key.clone()), None)
+        .expect("Cannot create Butterfly Client");
+    client
+        .send_ring_key(&new_key, true)
+        .expect("Cannot send the ring key");
+    net.wait_for_gossip_rounds(2);
+    for server in net.iter() {
+        let ring_keys = server.ring_keys();
+        assert!(!ring_keys.contains(&new_key.rev));
+        assert_eq!(ring_keys.primary().unwrap().rev, old_key.rev);
+    }
+}
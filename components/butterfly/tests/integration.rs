@@ -20,6 +20,7 @@ extern crate habitat_butterfly;
 extern crate habitat_butterfly_test as btest;
 extern crate habitat_core;
 
+mod authentication;
 mod rumor;
 mod encryption;
 
@@ -15,4 +15,6 @@
 pub mod service;
 pub mod service_config;
 pub mod service_file;
+pub mod service_secret;
 pub mod election;
+pub mod departure;
@@ -0,0 +1,378 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Member keys authenticate the Supervisors of a ring to each other.
+//!
+//! A member key is a signing key pair issued to one member ID by the certificate authority (CA) of
+//! a ring, itself a sig key pair named `<ring>-ca`. The CA signs the member ID together with the
+//! public half of the member key; that signature is the member's certificate. Members sign what
+//! they send with their secret key, and the receivers check the certificate against the public
+//! key of the ring CA, so no member can speak on behalf of another.
+//!
+//! The ring CA also issues operator keys, marked as such in their certificate, to the people who
+//! apply configuration, files, secrets, departures and ring keys to the ring. A Supervisor's member
+//! key can't sign those.
+
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+use base64;
+use sodiumoxide::crypto::sign;
+use sodiumoxide::crypto::sign::ed25519::SecretKey as SigSecretKey;
+use sodiumoxide::crypto::sign::ed25519::PublicKey as SigPublicKey;
+use sodiumoxide::crypto::sign::ed25519::Signature;
+
+use error::{Error, Result};
+use super::super::{MEMBER_KEY_VERSION, OPERATOR_KEY_VERSION, SigKeyPair};
+
+/// Prefixes the certified content of operator keys. It holds NUL bytes, which member IDs can't, so
+/// a member key can never pass for an operator key.
+const OPERATOR_MARKER: &'static [u8] = b"\0operator\0";
+
+/// A signing key pair issued to a member of a ring, along with the certificate of the ring CA.
+#[derive(Clone)]
+pub struct MemberKey {
+    /// The member ID the key was issued to
+    pub member_id: String,
+    /// The name with revision of the ring CA key which issued the key
+    pub ring_ca: String,
+    /// Whether the key was issued to an operator rather than to a Supervisor
+    pub operator: bool,
+    public: SigPublicKey,
+    secret: SigSecretKey,
+    certificate: Signature,
+}
+
+impl fmt::Debug for MemberKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "MemberKey {{ member_id: {}, ring_ca: {}, operator: {} }}",
+               self.member_id,
+               self.ring_ca,
+               self.operator)
+    }
+}
+
+impl MemberKey {
+    /// Generates a new member key for the given member ID, certified by the secret key of the
+    /// ring CA.
+    pub fn generate(member_id: &str, ring_ca: &SigKeyPair) -> Result<Self> {
+        Self::generate_with(member_id, ring_ca, false)
+    }
+
+    /// Generates a new operator key for the given operator name, certified as such by the secret
+    /// key of the ring CA.
+    pub fn generate_operator(name: &str, ring_ca: &SigKeyPair) -> Result<Self> {
+        Self::generate_with(name, ring_ca, true)
+    }
+
+    fn generate_with(member_id: &str, ring_ca: &SigKeyPair, operator: bool) -> Result<Self> {
+        let (public, secret) = sign::gen_keypair();
+        let certificate =
+            sign::sign_detached(&certified_content(member_id, &public[..], operator),
+                                try!(ring_ca.secret()));
+        Ok(MemberKey {
+               member_id: member_id.to_string(),
+               ring_ca: ring_ca.name_with_rev(),
+               operator: operator,
+               public: public,
+               secret: secret,
+               certificate: certificate,
+           })
+    }
+
+    /// Reads a member key from the given file.
+    pub fn read_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut content = String::new();
+        try!(try!(File::open(path.as_ref())).read_to_string(&mut content));
+        Self::from_str(&content)
+    }
+
+    /// Returns the public half of the member key.
+    pub fn public_key(&self) -> &[u8] {
+        &self.public[..]
+    }
+
+    /// Returns the ring CA's signature of the member ID and public key.
+    pub fn certificate(&self) -> &[u8] {
+        &self.certificate[..]
+    }
+
+    /// Signs the given data with the secret member key, returning the detached signature.
+    pub fn sign(&self, data: &[u8]) -> Vec<u8> {
+        sign::sign_detached(data, &self.secret)[..].to_vec()
+    }
+}
+
+impl fmt::Display for MemberKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "{}\n{}\n{}\n\n{}\n{}\n{}",
+               if self.operator {
+                   OPERATOR_KEY_VERSION
+               } else {
+                   MEMBER_KEY_VERSION
+               },
+               self.member_id,
+               self.ring_ca,
+               base64::encode(&self.secret[..]),
+               base64::encode(&self.public[..]),
+               base64::encode(&self.certificate[..]))
+    }
+}
+
+impl FromStr for MemberKey {
+    type Err = Error;
+
+    fn from_str(content: &str) -> Result<Self> {
+        let mut lines = content.lines();
+        let operator = match lines.next() {
+            Some(MEMBER_KEY_VERSION) => false,
+            Some(OPERATOR_KEY_VERSION) => true,
+            Some(val) => {
+                return Err(Error::CryptoError(format!("Unsupported member key version: {}", val)))
+            }
+            None => return Err(Error::CryptoError("Malformed member key: empty".to_string())),
+        };
+        let member_id = try!(next_line(&mut lines, "member ID"));
+        let ring_ca = try!(next_line(&mut lines, "ring CA"));
+        try!(next_line(&mut lines, "separator"));
+        let secret = try!(decode_line(&mut lines, "secret key"));
+        let public = try!(decode_line(&mut lines, "public key"));
+        let certificate = try!(decode_line(&mut lines, "certificate"));
+        Ok(MemberKey {
+               member_id: member_id.to_string(),
+               ring_ca: ring_ca.to_string(),
+               operator: operator,
+               secret: try!(SigSecretKey::from_slice(&secret).ok_or(malformed("secret key"))),
+               public: try!(SigPublicKey::from_slice(&public).ok_or(malformed("public key"))),
+               certificate: try!(Signature::from_slice(&certificate)
+                                     .ok_or(malformed("certificate"))),
+           })
+    }
+}
+
+/// Verifies that `signature` is the signature of `data` by the member key whose public half and
+/// certificate are given, and that the certificate was issued to `member_id` by one of the
+/// revisions of the ring CA, as an operator key if `operator` is set.
+pub fn verify_member_signature(ring_ca: &[SigKeyPair],
+                               member_id: &str,
+                               operator: bool,
+                               public_key: &[u8],
+                               certificate: &[u8],
+                               data: &[u8],
+                               signature: &[u8])
+                               -> Result<()> {
+    let public = try!(SigPublicKey::from_slice(public_key).ok_or(malformed("public key")));
+    let certificate = try!(Signature::from_slice(certificate).ok_or(malformed("certificate")));
+    let signature = try!(Signature::from_slice(signature).ok_or(malformed("signature")));
+    let content = certified_content(member_id, public_key, operator);
+    let certified = ring_ca
+        .iter()
+        .filter_map(|pair| pair.public.as_ref())
+        .any(|ca| sign::verify_detached(&certificate, &content, ca));
+    if !certified {
+        return Err(Error::CryptoError(format!("Member key of {} was not issued by the ring CA",
+                                              member_id)));
+    }
+    if !sign::verify_detached(&signature, data, &public) {
+        return Err(Error::CryptoError(format!("Invalid signature from member {}", member_id)));
+    }
+    Ok(())
+}
+
+/// Returns the name of the CA key of the given ring.
+pub fn ring_ca_name(ring: &str) -> String {
+    format!("{}-ca", ring)
+}
+
+fn certified_content(member_id: &str, public_key: &[u8], operator: bool) -> Vec<u8> {
+    let mut content = if operator {
+        OPERATOR_MARKER.to_vec()
+    } else {
+        Vec::new()
+    };
+    content.extend_from_slice(member_id.as_bytes());
+    content.extend_from_slice(public_key);
+    content
+}
+
+fn next_line<'a, I>(lines: &mut I, what: &str) -> Result<&'a str>
+    where I: Iterator<Item = &'a str>
+{
+    lines.next().ok_or(malformed(what))
+}
+
+fn decode_line<'a, I>(lines: &mut I, what: &str) -> Result<Vec<u8>>
+    where I: Iterator<Item = &'a str>
+{
+    let line = try!(next_line(lines, what));
+    base64::decode(line.trim()).map_err(|_| malformed(what))
+}
+
+fn malformed(what: &str) -> Error {
+    Error::CryptoError(format!("Malformed member key: bad {}", what))
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use tempdir::TempDir;
+
+    use super::{verify_member_signature, MemberKey};
+    use super::super::super::SigKeyPair;
+
+    fn ring_ca(cache: &TempDir) -> SigKeyPair {
+        SigKeyPair::generate_pair_for_ring_ca("beyonce", cache.path()).unwrap()
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let ca = ring_ca(&cache);
+        let key = MemberKey::generate("deadbeef", &ca).unwrap();
+        let signature = key.sign(b"Ringonit");
+        verify_member_signature(&[ca],
+                                "deadbeef",
+                                false,
+                                key.public_key(),
+                                key.certificate(),
+                                b"Ringonit",
+                                &signature)
+                .unwrap();
+    }
+
+    #[test]
+    fn verify_fails_for_another_member_id() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let ca = ring_ca(&cache);
+        let key = MemberKey::generate("deadbeef", &ca).unwrap();
+        let signature = key.sign(b"Ringonit");
+        assert!(verify_member_signature(&[ca],
+                                        "cafebabe",
+                                        false,
+                                        key.public_key(),
+                                        key.certificate(),
+                                        b"Ringonit",
+                                        &signature)
+                        .is_err());
+    }
+
+    #[test]
+    fn verify_fails_for_another_ring_ca() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let ca = ring_ca(&cache);
+        let other_ca = SigKeyPair::generate_pair_for_ring_ca("jayz", cache.path()).unwrap();
+        let key = MemberKey::generate("deadbeef", &other_ca).unwrap();
+        let signature = key.sign(b"Ringonit");
+        assert!(verify_member_signature(&[ca],
+                                        "deadbeef",
+                                        false,
+                                        key.public_key(),
+                                        key.certificate(),
+                                        b"Ringonit",
+                                        &signature)
+                        .is_err());
+    }
+
+    #[test]
+    fn verify_fails_for_tampered_data() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let ca = ring_ca(&cache);
+        let key = MemberKey::generate("deadbeef", &ca).unwrap();
+        let signature = key.sign(b"Ringonit");
+        assert!(verify_member_signature(&[ca],
+                                        "deadbeef",
+                                        false,
+                                        key.public_key(),
+                                        key.certificate(),
+                                        b"Ringonot",
+                                        &signature)
+                        .is_err());
+    }
+
+    #[test]
+    fn member_keys_do_not_verify_as_operator_keys() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let ca = ring_ca(&cache);
+        let key = MemberKey::generate("deadbeef", &ca).unwrap();
+        let signature = key.sign(b"Ringonit");
+        assert!(verify_member_signature(&[ca],
+                                        "deadbeef",
+                                        true,
+                                        key.public_key(),
+                                        key.certificate(),
+                                        b"Ringonit",
+                                        &signature)
+                        .is_err());
+    }
+
+    #[test]
+    fn operator_keys_verify_only_as_operator_keys() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let ca = ring_ca(&cache);
+        let key = MemberKey::generate_operator("ops", &ca).unwrap();
+        let signature = key.sign(b"Ringonit");
+        verify_member_signature(&[ca.clone()],
+                                "ops",
+                                true,
+                                key.public_key(),
+                                key.certificate(),
+                                b"Ringonit",
+                                &signature)
+                .unwrap();
+        assert!(verify_member_signature(&[ca],
+                                        "ops",
+                                        false,
+                                        key.public_key(),
+                                        key.certificate(),
+                                        b"Ringonit",
+                                        &signature)
+                        .is_err());
+    }
+
+    #[test]
+    fn operator_key_to_string_and_from_str() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let ca = ring_ca(&cache);
+        let key = MemberKey::generate_operator("ops", &ca).unwrap();
+        let parsed = MemberKey::from_str(&key.to_string()).unwrap();
+        assert!(parsed.operator);
+        assert_eq!(parsed.member_id, "ops");
+    }
+
+    #[test]
+    fn to_string_and_from_str() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let ca = ring_ca(&cache);
+        let key = MemberKey::generate("deadbeef", &ca).unwrap();
+        let parsed = MemberKey::from_str(&key.to_string()).unwrap();
+        assert_eq!(parsed.member_id, "deadbeef");
+        assert_eq!(parsed.ring_ca, ca.name_with_rev());
+        assert!(!parsed.operator);
+        assert_eq!(parsed.public_key(), key.public_key());
+        assert_eq!(parsed.certificate(), key.certificate());
+        assert_eq!(parsed.sign(b"Ringonit"), key.sign(b"Ringonit"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported member key version")]
+    fn from_str_invalid_version() {
+        MemberKey::from_str("SIG-SEC-1\nbeyonce-20160504220722\n\nabc").unwrap();
+    }
+}
@@ -42,6 +42,7 @@ lazy_static! {
 }
 
 pub mod box_key_pair;
+pub mod member_key;
 pub mod sym_key;
 pub mod sig_key_pair;
 
@@ -25,12 +25,22 @@ use sodiumoxide::randombytes::randombytes;
 use error::{Error, Result};
 use super::{get_key_revisions, mk_key_filename, mk_revision_string, parse_name_with_rev,
             read_key_bytes, write_keypair_files, KeyPair, KeyType, PairType, TmpKeyfile};
+use super::member_key::ring_ca_name;
 use super::super::{PUBLIC_KEY_SUFFIX, PUBLIC_SIG_KEY_VERSION, SECRET_SIG_KEY_SUFFIX,
                    SECRET_SIG_KEY_VERSION, hash};
 
 pub type SigKeyPair = KeyPair<SigPublicKey, SigSecretKey>;
 
 impl SigKeyPair {
+    pub fn generate_in_memory<S: ToString>(name: S) -> Result<Self> {
+        let revision = try!(mk_revision_string());
+        let (public_key, secret_key) = sign::gen_keypair();
+        Ok(Self::new(name.to_string(),
+                     revision,
+                     Some(public_key),
+                     Some(secret_key)))
+    }
+
     pub fn generate_pair_for_origin<P: AsRef<Path> + ?Sized>(name: &str,
                                                              cache_key_path: &P)
                                                              -> Result<Self> {
@@ -45,6 +55,14 @@ impl SigKeyPair {
                      Some(secret_key)))
     }
 
+    /// Generates the certificate authority key of a ring, which issues the member keys of its
+    /// Supervisors.
+    pub fn generate_pair_for_ring_ca<P: AsRef<Path> + ?Sized>(ring: &str,
+                                                              cache_key_path: &P)
+                                                              -> Result<Self> {
+        Self::generate_pair_for_origin(&ring_ca_name(ring), cache_key_path)
+    }
+
     fn mk_key_name(name: &str, revision: &str) -> String {
         format!("{}-{}", name, revision)
     }
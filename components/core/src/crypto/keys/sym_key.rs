@@ -375,6 +375,56 @@ impl SymKey {
         Ok((try!(Self::get_pair_for(&name_with_rev, cache_key_path)), PairType::Secret))
     }
 
+    /// Returns the contents of the secret key file of this `SymKey`, which
+    /// `SymKey::write_file_from_str` and `SymKey::from_secret_string` read back.
+    ///
+    /// # Errors
+    ///
+    /// * If the secret key component of the `SymKey` is not present
+    pub fn to_secret_string(&self) -> Result<String> {
+        let key = try!(self.secret());
+        Ok(format!("{}\n{}\n\n{}",
+                   SECRET_SYM_KEY_VERSION,
+                   self.name_with_rev(),
+                   base64::encode(&key[..])))
+    }
+
+    /// Reads a sym key from the contents of a string slice, without writing it to the key cache.
+    ///
+    /// # Errors
+    ///
+    /// * If there is a key version mismatch
+    /// * If the key version, the key name with revision or the key value is missing
+    /// * If the key value is not a valid sym secret key
+    pub fn from_secret_string(content: &str) -> Result<Self> {
+        let mut lines = content.lines();
+        match lines.next() {
+            Some(val) if val == SECRET_SYM_KEY_VERSION => (),
+            Some(val) => {
+                return Err(Error::CryptoError(format!("Unsupported key version: {}", val)))
+            }
+            None => return Err(Error::CryptoError("Malformed sym key string".to_string())),
+        }
+        let (name, rev) = match lines.next() {
+            Some(val) => try!(parse_name_with_rev(val)),
+            None => return Err(Error::CryptoError("Malformed sym key string".to_string())),
+        };
+        let bytes = match lines.nth(1) {
+            Some(val) => {
+                try!(base64::decode(val).map_err(|e| {
+                    Error::CryptoError(format!("Can't read raw key for {}-{}: {}", name, rev, e))
+                }))
+            }
+            None => return Err(Error::CryptoError("Malformed sym key string".to_string())),
+        };
+        match SymSecretKey::from_slice(&bytes) {
+            Some(sk) => Ok(Self::new(name, rev, Some(()), Some(sk))),
+            None => {
+                Err(Error::CryptoError(format!("Can't read sym secret key for {}-{}", name, rev)))
+            }
+        }
+    }
+
     fn mk_key_name_for_ring(name: &str, revision: &str) -> String {
         format!("{}-{}", name, revision)
     }
@@ -602,6 +652,35 @@ mod test {
         pair.decrypt(&nonce, "singleladies".as_bytes()).unwrap();
     }
 
+    #[test]
+    fn to_and_from_secret_string() {
+        let pair = SymKey::generate_in_memory("beyonce").unwrap();
+        let content = pair.to_secret_string().unwrap();
+        let restored = SymKey::from_secret_string(&content).unwrap();
+
+        assert_eq!(restored.name_with_rev(), pair.name_with_rev());
+        let (nonce, ciphertext) = pair.encrypt("Ringonit".as_bytes()).unwrap();
+        assert_eq!(restored.decrypt(&nonce, &ciphertext).unwrap(),
+                   "Ringonit".as_bytes().to_vec());
+    }
+
+    #[test]
+    fn from_secret_string_reads_a_key_file() {
+        let content = fixture_as_string(&format!("keys/{}", VALID_KEY));
+        let pair = SymKey::from_secret_string(&content).unwrap();
+
+        assert_eq!(pair.name_with_rev(), VALID_NAME_WITH_REV);
+        assert_eq!(pair.to_secret_string().unwrap().trim(), content.trim());
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported key version")]
+    fn from_secret_string_unsupported_version() {
+        let content = fixture_as_string("keys/ring-key-invalid-version-20160504221247.sym.key");
+
+        SymKey::from_secret_string(&content).unwrap();
+    }
+
     #[test]
     fn write_file_from_str() {
         let cache = TempDir::new("key_cache").unwrap();
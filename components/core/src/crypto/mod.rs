@@ -51,6 +51,12 @@
 //! into the original message.
 //! - A **Ring key** is a **sym** key used when sending messages between the Supervisors to prevent
 //! a third party from intercepting the traffic.
+//! - A **Ring CA key** is a **sig** key which certifies the **Member keys** of a ring.
+//! - A **Member key** is a **sig** key pair issued to a single Supervisor of a ring, used to
+//! authenticate the messages it sends to the other Supervisors.
+//! - An **Operator key** is a **Member key** the ring CA marked as issued to an operator rather
+//! than to a Supervisor. Only operator keys may sign the configuration, files, secrets, departures
+//! and ring keys applied to a ring.
 //! - **Key revisions** - There can exist several keys for any given user, service, ring, or origin
 //! via different revision numbers. Revision numbers appear following the key name and are in the
 //! format `{year}{month}{day}{hour24}{minute}{second}`. For all user-facing cryptographic
@@ -118,6 +124,20 @@
 //! staging-201603312016.sym.key
 //! ```
 //!
+//! ## Ring CA key
+//!
+//! ```text
+//! <ring_name>-ca-<revision>.pub
+//! <ring_name>-ca-<revision>.sig.key
+//! ```
+//!
+//! Example Ring CA keys:
+//!
+//! ```text
+//! staging-ca-201603312016.pub
+//! staging-ca-201603312016.sig.key
+//! ```
+//!
 //! # File formats
 //!
 //! ## Habitat artifacts
@@ -223,6 +243,34 @@
 //!
 //! <symkey_base64>
 //! ```
+//!
+//! ## Member keys
+//!
+//! Member keys are not kept in the key cache but in the data directory of the Supervisor they
+//! were issued to. There are 7 lines, as follows:
+//!
+//! 1. The member key format version
+//! 1. The member ID the key was issued to
+//! 1. The ring CA key name, including revision, which issued the key
+//! 1. An empty line
+//! 1. The secret key, which is Base64-encoded
+//! 1. The public key, which is Base64-encoded
+//! 1. The certificate, the ring CA's Base64-encoded signature of the member ID followed by the
+//!    public key
+//!
+//! ```text
+//! MEMBER-KEY-1
+//! 3c1d6e0a9d2d4ab3b6a1e3e4f0d2c1b0
+//! staging-ca-20160405144945
+//!
+//! <secret_key_base64>
+//! <public_key_base64>
+//! <certificate_base64>
+//! ```
+//!
+//! Operator keys have the same format, with `OPERATOR-KEY-1` as their format version and the
+//! operator's name in place of the member ID. Their certificate is the ring CA's signature of an
+//! operator marker, which can't be part of a member ID, followed by the name and the public key.
 
 use std::path::{Path, PathBuf};
 
@@ -263,8 +311,11 @@ pub const SECRET_SIG_KEY_VERSION: &'static str = "SIG-SEC-1";
 pub const PUBLIC_BOX_KEY_VERSION: &'static str = "BOX-PUB-1";
 pub const SECRET_BOX_KEY_VERSION: &'static str = "BOX-SEC-1";
 pub const SECRET_SYM_KEY_VERSION: &'static str = "SYM-SEC-1";
+pub const MEMBER_KEY_VERSION: &'static str = "MEMBER-KEY-1";
+pub const OPERATOR_KEY_VERSION: &'static str = "OPERATOR-KEY-1";
 
 pub use self::keys::box_key_pair::BoxKeyPair;
+pub use self::keys::member_key::MemberKey;
 pub use self::keys::sym_key::SymKey;
 pub use self::keys::sig_key_pair::SigKeyPair;
 
@@ -89,6 +89,8 @@ pub enum Error {
     InvalidPlatform(String),
     /// Occurs when a service group string cannot be successfully parsed.
     InvalidServiceGroup(String),
+    /// Occurs when a signal name cannot be successfully parsed.
+    InvalidSignal(String),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
     /// Occurs when a BIND or BIND_OPTIONAL MetaFile is read and contains a bad entry.
@@ -229,6 +231,11 @@ impl fmt::Display for Error {
                          service.group (example: redis.production)",
                         e)
             }
+            Error::InvalidSignal(ref e) => {
+                format!("Invalid signal: {}. Valid signals are INT, ILL, ABRT, FPE, KILL, SEGV, \
+                         TERM, HUP, QUIT, ALRM, USR1 and USR2",
+                        e)
+            }
             Error::IO(ref err) => format!("{}", err),
             Error::MetaFileBadBind => format!("Bad value parsed from BIND or BIND_OPTIONAL"),
             Error::MetaFileMalformed(ref e) => {
@@ -313,6 +320,7 @@ impl error::Error for Error {
             Error::InvalidArchitecture(_) => "Unsupported target architecture supplied.",
             Error::InvalidPlatform(_) => "Unsupported target platform supplied.",
             Error::InvalidServiceGroup(_) => "Service group strings must be in service.group format (example: redis.production)",
+            Error::InvalidSignal(_) => "Unsupported signal name supplied.",
             Error::IO(ref err) => err.description(),
             Error::MetaFileBadBind => "Bad value parsed from BIND or BIND_OPTIONAL MetaFile",
             Error::MetaFileMalformed(_) => "MetaFile didn't contain a valid UTF-8 string",
@@ -18,7 +18,7 @@ use std::path::PathBuf;
 use std::ops::Neg;
 use std::os::unix::process::CommandExt;
 use std::process::{self, Command};
-use time::{Duration, SteadyTime};
+use std::time::{Duration, Instant};
 
 use error::{Error, Result};
 
@@ -137,6 +137,10 @@ impl Child {
     }
 
     pub fn kill(&mut self) -> Result<ShutdownMethod> {
+        self.stop(Signal::TERM, Duration::from_secs(8))
+    }
+
+    pub fn stop(&mut self, signal: Signal, timeout: Duration) -> Result<ShutdownMethod> {
         // check the group of the process being killed
         // if it is the root process of the process group
         // we send our signals to the entire process group
@@ -150,16 +154,16 @@ impl Child {
             self.pid = self.pid.neg();
         }
 
-        signal(self.pid, Signal::TERM)?;
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        self::signal(self.pid, signal)?;
+        let stop_time = Instant::now() + timeout;
         loop {
             if let Ok(status) = self.status() {
                 if !status.no_status() {
                     break;
                 }
             }
-            if SteadyTime::now() > stop_time {
-                signal(self.pid, Signal::KILL)?;
+            if Instant::now() > stop_time {
+                self::signal(self.pid, Signal::KILL)?;
                 return Ok(ShutdownMethod::Killed);
             }
         }
@@ -197,6 +201,7 @@ impl ExitStatusExt for HabExitStatus {
 mod tests {
     use libc;
     use std::process::Command;
+    use std::time::Duration;
     use super::super::*;
 
     #[test]
@@ -243,6 +248,23 @@ mod tests {
         assert_eq!(exit.signal(), Some(libc::SIGTERM as u32))
     }
 
+    #[test]
+    fn stopped_process_returns_stop_signal() {
+        let mut cmd = Command::new("/bin/bash");
+        cmd.arg("-c").arg("while : ; do /bin/sleep 1; done");
+        let mut child = cmd.spawn().unwrap();
+
+        let mut hab_child = HabChild::from(&mut child).unwrap();
+        let _ = hab_child.stop(Signal::HUP, Duration::from_secs(8));
+
+        let mut exit = hab_child.status().unwrap();
+        while exit.no_status() {
+            exit = hab_child.status().unwrap();
+        }
+
+        assert_eq!(exit.signal(), Some(libc::SIGHUP as u32))
+    }
+
     #[test]
     fn calling_wait_multiple_times_after_exit_returns_same_status() {
         let mut cmd = Command::new("/bin/bash");
@@ -14,8 +14,10 @@
 
 use std::fmt;
 use std::process::Child;
+use std::str::FromStr;
+use std::time::Duration;
 
-use error::Result;
+use error::{Error, Result};
 
 #[allow(unused_variables)]
 #[cfg(windows)]
@@ -34,7 +36,7 @@ pub trait OsSignal {
 }
 
 #[allow(non_snake_case)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Signal {
     INT,
     ILL,
@@ -50,6 +52,55 @@ pub enum Signal {
     USR2,
 }
 
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Signal::INT => "INT",
+            Signal::ILL => "ILL",
+            Signal::ABRT => "ABRT",
+            Signal::FPE => "FPE",
+            Signal::KILL => "KILL",
+            Signal::SEGV => "SEGV",
+            Signal::TERM => "TERM",
+            Signal::HUP => "HUP",
+            Signal::QUIT => "QUIT",
+            Signal::ALRM => "ALRM",
+            Signal::USR1 => "USR1",
+            Signal::USR2 => "USR2",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Parses a signal name, with or without the `SIG` prefix and in any case.
+impl FromStr for Signal {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let upper = value.to_uppercase();
+        let name = if upper.starts_with("SIG") {
+            &upper[3..]
+        } else {
+            &upper[..]
+        };
+        match name {
+            "INT" => Ok(Signal::INT),
+            "ILL" => Ok(Signal::ILL),
+            "ABRT" => Ok(Signal::ABRT),
+            "FPE" => Ok(Signal::FPE),
+            "KILL" => Ok(Signal::KILL),
+            "SEGV" => Ok(Signal::SEGV),
+            "TERM" => Ok(Signal::TERM),
+            "HUP" => Ok(Signal::HUP),
+            "QUIT" => Ok(Signal::QUIT),
+            "ALRM" => Ok(Signal::ALRM),
+            "USR1" => Ok(Signal::USR1),
+            "USR2" => Ok(Signal::USR2),
+            _ => Err(Error::InvalidSignal(value.to_string())),
+        }
+    }
+}
+
 pub enum ShutdownMethod {
     AlreadyExited,
     GracefulTermination,
@@ -90,6 +141,12 @@ impl HabChild {
     pub fn kill(&mut self) -> Result<ShutdownMethod> {
         self.inner.kill()
     }
+
+    /// Asks the process to stop with the given signal and kills it if it hasn't exited once the
+    /// timeout has passed.
+    pub fn stop(&mut self, signal: Signal, timeout: Duration) -> Result<ShutdownMethod> {
+        self.inner.stop(signal, timeout)
+    }
 }
 
 impl fmt::Debug for HabChild {
@@ -112,3 +169,29 @@ pub trait ExitStatusExt {
     fn code(&self) -> Option<u32>;
     fn signal(&self) -> Option<u32>;
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Signal;
+
+    #[test]
+    fn signal_from_str_accepts_names() {
+        assert_eq!(Signal::from_str("HUP").unwrap(), Signal::HUP);
+        assert_eq!(Signal::from_str("sigusr1").unwrap(), Signal::USR1);
+        assert_eq!(Signal::from_str("SIGTERM").unwrap(), Signal::TERM);
+    }
+
+    #[test]
+    fn signal_from_str_invalid() {
+        assert!(Signal::from_str("SIGWINCH").is_err());
+        assert!(Signal::from_str("").is_err());
+    }
+
+    #[test]
+    fn signal_display_round_trips() {
+        assert_eq!(Signal::from_str(&Signal::QUIT.to_string()).unwrap(),
+                   Signal::QUIT);
+    }
+}
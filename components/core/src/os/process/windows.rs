@@ -17,7 +17,7 @@ use std::path::PathBuf;
 use std::process::{self, Command};
 use std::ptr;
 use std::io;
-use time::{Duration, SteadyTime};
+use std::time::{Duration, Instant};
 
 use kernel32;
 use winapi;
@@ -179,6 +179,11 @@ impl Child {
     }
 
     pub fn kill(&mut self) -> Result<ShutdownMethod> {
+        self.stop(Signal::TERM, Duration::from_secs(8))
+    }
+
+    /// Windows processes can't be sent signals, they are always sent a Ctrl-C.
+    pub fn stop(&mut self, _: Signal, timeout: Duration) -> Result<ShutdownMethod> {
         if self.last_status.is_some() {
             return Ok(ShutdownMethod::AlreadyExited);
         }
@@ -204,11 +209,11 @@ impl Child {
             }
         }
 
-        let stop_time = SteadyTime::now() + Duration::seconds(8);
+        let stop_time = Instant::now() + timeout;
 
         let result;
         loop {
-            if ret == 0 || SteadyTime::now() > stop_time {
+            if ret == 0 || Instant::now() > stop_time {
                 unsafe {
                     ret = kernel32::TerminateProcess(self.handle.unwrap(), 1);
                     if ret == 0 {
@@ -50,8 +50,34 @@ pub fn get() -> App<'static, 'static> {
                     (default: 127.0.0.1:9638)")
                 (@arg RING: -r --ring +takes_value
                     "Ring key name, which will encrypt communication messages")
+                (@arg MEMBER_KEY: --("member-key") +takes_value {file_exists}
+                    "Path to an operator key issued by the ring CA, which will sign the messages \
+                    for rings authenticating their members (see `hab ring ca issue --operator`)")
             )
         )
+        (@subcommand ring =>
+            (about: "Commands relating to Habitat rings")
+            (aliases: &["r", "ri", "rin"])
+            (@setting ArgRequiredElseHelp)
+            (@subcommand key =>
+                (about: "Commands relating to Habitat ring keys")
+                (aliases: &["k", "ke"])
+                (@setting ArgRequiredElseHelp)
+                (subcommand: sub_ring_key_rotate().aliases(&["r", "ro", "rot", "rota", "rotat"]))
+            )
+        )
+        (@subcommand secret =>
+            (about: "Commands relating to Habitat service secrets")
+            (aliases: &["s", "se", "sec", "secr", "secre"])
+            (@setting ArgRequiredElseHelp)
+            (subcommand: sub_secret_apply().aliases(&["a", "ap", "app", "appl"]))
+        )
+        (@subcommand sup =>
+            (about: "Commands relating to the Habitat Supervisor ring")
+            (aliases: &["su"])
+            (@setting ArgRequiredElseHelp)
+            (subcommand: sub_sup_depart().aliases(&["d", "de", "dep", "depa", "depar"]))
+        )
     )
 }
 
@@ -63,17 +89,91 @@ fn sub_config_apply() -> App<'static, 'static> {
             (default: 127.0.0.1:9638)")
         (@arg RING: -r --ring +takes_value
             "Ring key name, which will encrypt communication messages")
+        (@arg MEMBER_KEY: --("member-key") +takes_value {file_exists}
+            "Path to an operator key issued by the ring CA, which will sign the messages \
+            for rings authenticating their members (see `hab ring ca issue --operator`)")
         (@arg SERVICE_GROUP: +required {valid_service_group}
             "Target service group (ex: redis.default)")
         (@arg VERSION_NUMBER: +required
             "A version number (positive integer) for this configuration (ex: 42)")
-        (@arg FILE: {file_exists_or_stdin}
+        (@arg FILE: {file_exists_or_stdin} conflicts_with[ROLLBACK]
             "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>)")
+        (@arg ROLLBACK: --rollback +takes_value
+            "Re-apply the configuration of an earlier incarnation, read from a Supervisor's \
+            configuration history, instead of a file (ex: 41)")
+        (@arg REMOTE_SUP: --("remote-sup") +takes_value
+            "Address of the HTTP gateway of the Supervisor to read the configuration history \
+            from (default: 127.0.0.1:9631)")
+        (@arg ORG: --org +takes_value "Name of service organization to use for encryption")
+        (@arg USER: -u --user +takes_value "Name of a user key to use for encryption")
+    )
+}
+
+fn sub_ring_key_rotate() -> App<'static, 'static> {
+    clap_app!(@subcommand rotate =>
+        (about: "Moves a ring to a new revision of its ring key without downtime. Generates the \
+            new revision and hands it to the Supervisors of the ring, which start accepting gossip \
+            encrypted with it. Once every Supervisor has it, run again with --promote to have them \
+            encrypt their gossip with it, and then with --retire to have them stop accepting the \
+            older revisions.")
+        (@arg RING: +required +takes_value "Ring key name")
+        (@arg PROMOTE: --promote
+            "Have the Supervisors encrypt their gossip with the latest revision of the ring key, \
+            instead of handing out a new one")
+        (@arg RETIRE: --retire conflicts_with[PROMOTE]
+            "Have the Supervisors encrypt their gossip with the latest revision of the ring key, \
+            and stop accepting gossip encrypted with any older revision")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg MEMBER_KEY: --("member-key") +takes_value {file_exists}
+            "Path to an operator key issued by the ring CA, which will sign the messages \
+            for rings authenticating their members (see `hab ring ca issue --operator`)")
+    )
+}
+
+fn sub_secret_apply() -> App<'static, 'static> {
+    clap_app!(@subcommand apply =>
+        (about: "Applies a secret to a group of Habitat Supervisors, encrypted to the service \
+            group's key")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg MEMBER_KEY: --("member-key") +takes_value {file_exists}
+            "Path to an operator key issued by the ring CA, which will sign the messages \
+            for rings authenticating their members (see `hab ring ca issue --operator`)")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg SECRET_NAME: +required {valid_secret_name}
+            "Name of the secret, as templates refer to it (ex: password)")
+        (@arg VERSION_NUMBER: +required
+            "A version number (positive integer) for this secret (ex: 42)")
+        (@arg FILE: {file_exists_or_stdin}
+            "Path to local file on disk holding the secret (default: <stdin>)")
         (@arg ORG: --org +takes_value "Name of service organization to use for encryption")
         (@arg USER: -u --user +takes_value "Name of a user key to use for encryption")
     )
 }
 
+fn sub_sup_depart() -> App<'static, 'static> {
+    clap_app!(@subcommand depart =>
+        (about: "Permanently departs a Supervisor from the ring, removing it from the census of \
+            every service group. The departed Supervisor shuts down and cannot rejoin under the \
+            same member id.")
+        (@arg MEMBER_ID: +required +takes_value "The member id of the Supervisor to depart")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg MEMBER_KEY: --("member-key") +takes_value {file_exists}
+            "Path to an operator key issued by the ring CA, which will sign the messages \
+            for rings authenticating their members (see `hab ring ca issue --operator`)")
+    )
+}
+
 fn file_exists(val: String) -> result::Result<(), String> {
     if Path::new(&val).is_file() {
         Ok(())
@@ -86,6 +186,15 @@ fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" { Ok(()) } else { file_exists(val) }
 }
 
+fn valid_secret_name(val: String) -> result::Result<(), String> {
+    if !val.is_empty() && val.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
+        Ok(())
+    } else {
+        Err(format!("Secret name: '{}' may only contain letters, numbers, '_' and '-'",
+                    &val))
+    }
+}
+
 fn valid_service_group(val: String) -> result::Result<(), String> {
     match ServiceGroup::validate(&val) {
         Ok(()) => Ok(()),
@@ -22,8 +22,10 @@ pub mod apply {
 
     use butterfly::client::Client;
     use common::ui::{Status, UI};
-    use hcore::crypto::{SymKey, BoxKeyPair};
+    use hcore::crypto::{BoxKeyPair, MemberKey, SymKey};
     use hcore::service::ServiceGroup;
+    use hyper::Client as HttpClient;
+    use hyper::status::StatusCode;
     use toml;
 
     use error::{Error, Result};
@@ -34,6 +36,7 @@ pub mod apply {
                  file_path: Option<&Path>,
                  peers: &Vec<String>,
                  ring_key: Option<&SymKey>,
+                 member_key: Option<&MemberKey>,
                  user_pair: Option<&BoxKeyPair>,
                  service_pair: Option<&BoxKeyPair>)
                  -> Result<()> {
@@ -52,7 +55,84 @@ pub mod apply {
                 try!(io::stdin().read_to_end(&mut body));
             }
         };
+        apply(ui, sg, number, body, peers, ring_key, member_key, user_pair, service_pair)
+    }
+
+    /// Applies the configuration of an earlier incarnation, as kept in the configuration history
+    /// of the Supervisor whose HTTP gateway listens on `remote_sup`, again as a new incarnation.
+    pub fn rollback(ui: &mut UI,
+                    sg: &ServiceGroup,
+                    number: u64,
+                    incarnation: u64,
+                    remote_sup: &str,
+                    peers: &Vec<String>,
+                    ring_key: Option<&SymKey>,
+                    member_key: Option<&MemberKey>,
+                    user_pair: Option<&BoxKeyPair>,
+                    service_pair: Option<&BoxKeyPair>)
+                    -> Result<()> {
+        try!(ui.begin(format!("Rolling back configuration for {} to incarnation {} as \
+                               incarnation {}",
+                              sg,
+                              incarnation,
+                              number)));
+
+        try!(ui.status(Status::Downloading,
+                       format!("configuration incarnation {} from {}", incarnation, remote_sup)));
+
+        let body = try!(history_entry(sg, incarnation, remote_sup));
+        apply(ui, sg, number, body, peers, ring_key, member_key, user_pair, service_pair)
+    }
+
+    /// Reads the configuration of the given incarnation from the configuration history of the
+    /// Supervisor whose HTTP gateway listens on `remote_sup`.
+    fn history_entry(sg: &ServiceGroup, incarnation: u64, remote_sup: &str) -> Result<Vec<u8>> {
+        let url = match sg.org() {
+            Some(org) => {
+                format!("http://{}/services/{}/{}/{}/config/history/{}",
+                        remote_sup,
+                        sg.service(),
+                        sg.group(),
+                        org,
+                        incarnation)
+            }
+            None => {
+                format!("http://{}/services/{}/{}/config/history/{}",
+                        remote_sup,
+                        sg.service(),
+                        sg.group(),
+                        incarnation)
+            }
+        };
+        let mut res = try!(HttpClient::new()
+                               .get(&url)
+                               .send()
+                               .map_err(|e| Error::ConfigHistory(format!("{}, {}", url, e))));
+        match res.status {
+            StatusCode::Ok => {
+                let mut body = Vec::new();
+                try!(res.read_to_end(&mut body));
+                Ok(body)
+            }
+            StatusCode::NotFound => {
+                Err(Error::ConfigHistory(format!("incarnation {} of {} is not in the history",
+                                                 incarnation,
+                                                 sg)))
+            }
+            status => Err(Error::ConfigHistory(format!("{}, {}", url, status))),
+        }
+    }
 
+    fn apply(ui: &mut UI,
+             sg: &ServiceGroup,
+             number: u64,
+             mut body: Vec<u8>,
+             peers: &Vec<String>,
+             ring_key: Option<&SymKey>,
+             member_key: Option<&MemberKey>,
+             user_pair: Option<&BoxKeyPair>,
+             service_pair: Option<&BoxKeyPair>)
+             -> Result<()> {
         match toml::de::from_slice::<toml::value::Value>(&body) {
             Ok(_) => try!(ui.status(Status::Verified, "this configuration is valid TOML")),
             Err(err) => {
@@ -76,7 +156,9 @@ pub mod apply {
 
         for peer in peers.iter() {
             try!(ui.status(Status::Applying, format!("to peer {}", peer)));
-            let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
+            let mut client = try!(Client::new(peer,
+                                              ring_key.map(|k| k.clone()),
+                                              member_key.map(|k| k.clone()))
                 .map_err(|e| Error::ButterflyError(format!("{}", e))));
             try!(client
                      .send_service_config(sg.clone(), number, body.clone(), encrypted)
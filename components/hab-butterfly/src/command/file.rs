@@ -21,7 +21,7 @@ pub mod upload {
 
     use butterfly::client::Client;
     use common::ui::{Status, UI};
-    use hcore::crypto::{SymKey, BoxKeyPair};
+    use hcore::crypto::{BoxKeyPair, MemberKey, SymKey};
     use hcore::service::ServiceGroup;
 
     use error::{Error, Result};
@@ -32,6 +32,7 @@ pub mod upload {
                  file_path: &Path,
                  peers: &Vec<String>,
                  ring_key: Option<&SymKey>,
+                 member_key: Option<&MemberKey>,
                  user_pair: Option<&BoxKeyPair>,
                  service_pair: Option<&BoxKeyPair>)
                  -> Result<()> {
@@ -64,7 +65,9 @@ pub mod upload {
 
         for peer in peers.iter() {
             try!(ui.status(Status::Applying, format!("to peer {}", peer)));
-            let mut client = try!(Client::new(peer, ring_key.map(|k| k.clone()))
+            let mut client = try!(Client::new(peer,
+                                              ring_key.map(|k| k.clone()),
+                                              member_key.map(|k| k.clone()))
                 .map_err(|e| Error::ButterflyError(format!("{}", e))));
             try!(client
                      .send_service_file(sg.clone(),
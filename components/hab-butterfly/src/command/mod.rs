@@ -14,3 +14,6 @@
 
 pub mod config;
 pub mod file;
+pub mod ring;
+pub mod secret;
+pub mod sup;
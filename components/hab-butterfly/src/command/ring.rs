@@ -0,0 +1,88 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod key {
+    pub mod rotate {
+        use std::path::Path;
+        use std::thread;
+        use std::time;
+
+        use butterfly::client::Client;
+        use common::ui::{Status, UI};
+        use hcore::crypto::{MemberKey, SymKey};
+
+        use error::{Error, Result};
+
+        /// Hands a new revision of the ring key to every peer, encrypted with the latest revision
+        /// the ring already has. With `promote`, has every peer encrypt with the latest revision
+        /// instead. With `retire`, also has every peer stop decrypting with the older revisions.
+        pub fn start(ui: &mut UI,
+                     ring: &str,
+                     peers: &Vec<String>,
+                     member_key: Option<&MemberKey>,
+                     promote: bool,
+                     retire: bool,
+                     cache: &Path)
+                     -> Result<()> {
+            let current = try!(SymKey::get_latest_pair_for(ring, cache));
+            let ring_key = if retire {
+                try!(ui.begin(format!("Retiring the ring keys older than {}",
+                                      current.name_with_rev())));
+                current.clone()
+            } else if promote {
+                try!(ui.begin(format!("Promoting ring key {}", current.name_with_rev())));
+                current.clone()
+            } else {
+                let ring_key = try!(SymKey::generate_pair_for_ring(ring, cache));
+                try!(ui.begin(format!("Handing out ring key {}", ring_key.name_with_rev())));
+                ring_key
+            };
+            for peer in peers.iter() {
+                try!(ui.status(Status::Applying, format!("to peer {}", peer)));
+                let mut client = try!(Client::new(peer,
+                                                  Some(current.clone()),
+                                                  member_key.map(|k| k.clone()))
+                    .map_err(|e| Error::ButterflyError(format!("{}", e))));
+                let sent = if retire {
+                    client.retire_ring_keys(&ring_key)
+                } else {
+                    client.send_ring_key(&ring_key, promote)
+                };
+                try!(sent.map_err(|e| Error::ButterflyError(format!("{}", e))));
+
+                // please take a moment to weep over the following line
+                // of code. We must sleep to allow messages to be sent
+                // before freeing the socket to prevent loss.
+                // see https://github.com/zeromq/libzmq/issues/1264
+                thread::sleep(time::Duration::from_millis(100));
+            }
+            if retire {
+                try!(ui.end(format!("Retired the ring keys older than {}. Remove them from the key \
+                                     caches which are not on a Supervisor.",
+                                    ring_key.name_with_rev())));
+            } else if promote {
+                try!(ui.end(format!("Promoted ring key {}. Once every Supervisor encrypts with \
+                                     it, run `hab ring key rotate {} --retire`.",
+                                    ring_key.name_with_rev(),
+                                    ring)));
+            } else {
+                try!(ui.end(format!("Handed out ring key {}. Once every Supervisor has it, run \
+                                     `hab ring key rotate {} --promote`.",
+                                    ring_key.name_with_rev(),
+                                    ring)));
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod depart {
+    use std::thread;
+    use std::time;
+
+    use butterfly::client::Client;
+    use common::ui::{Status, UI};
+    use hcore::crypto::{MemberKey, SymKey};
+
+    use error::{Error, Result};
+
+    pub fn start(ui: &mut UI,
+                 member_id: &str,
+                 peers: &Vec<String>,
+                 ring_key: Option<&SymKey>,
+                 member_key: Option<&MemberKey>)
+                 -> Result<()> {
+        try!(ui.begin(format!("Permanently marking {} as departed", member_id)));
+        for peer in peers.iter() {
+            try!(ui.status(Status::Applying, format!("to peer {}", peer)));
+            let mut client = try!(Client::new(peer,
+                                              ring_key.map(|k| k.clone()),
+                                              member_key.map(|k| k.clone()))
+                .map_err(|e| Error::ButterflyError(format!("{}", e))));
+            try!(client
+                     .send_departure(member_id)
+                     .map_err(|e| Error::ButterflyError(format!("{}", e))));
+
+            // please take a moment to weep over the following line
+            // of code. We must sleep to allow messages to be sent
+            // before freeing the socket to prevent loss.
+            // see https://github.com/zeromq/libzmq/issues/1264
+            thread::sleep(time::Duration::from_millis(100));
+        }
+        try!(ui.end("Departed member"));
+        Ok(())
+    }
+}
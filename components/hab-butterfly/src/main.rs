@@ -34,7 +34,7 @@ use clap::ArgMatches;
 
 use common::ui::{Coloring, UI, NOCOLORING_ENVVAR, NONINTERACTIVE_ENVVAR};
 use hcore::env as henv;
-use hcore::crypto::{init, default_cache_key_path, BoxKeyPair, SymKey};
+use hcore::crypto::{init, default_cache_key_path, BoxKeyPair, MemberKey, SymKey};
 use hcore::service::ServiceGroup;
 
 use hab_butterfly::{analytics, cli, command};
@@ -45,6 +45,8 @@ const HABITAT_ORG_ENVVAR: &'static str = "HAB_ORG";
 /// Makes the --user CLI param optional when this env var is set
 const HABITAT_USER_ENVVAR: &'static str = "HAB_USER";
 const HABITAT_BUTTERFLY_PORT: u64 = 9638;
+/// HTTP gateway of the Supervisor the configuration history is read from by default
+const HABITAT_HTTP_GATEWAY_ADDR: &'static str = "127.0.0.1:9631";
 const MAX_FILE_UPLOAD_SIZE_BYTES: u64 = 4096;
 
 lazy_static! {
@@ -94,6 +96,29 @@ fn start(ui: &mut UI) -> Result<()> {
                 _ => unreachable!(),
             }
         }
+        ("ring", Some(matches)) => {
+            match matches.subcommand() {
+                ("key", Some(m)) => {
+                    match m.subcommand() {
+                        ("rotate", Some(sc)) => try!(sub_ring_key_rotate(ui, sc)),
+                        _ => unreachable!(),
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+        ("secret", Some(matches)) => {
+            match matches.subcommand() {
+                ("apply", Some(m)) => try!(sub_secret_apply(ui, m)),
+                _ => unreachable!(),
+            }
+        }
+        ("sup", Some(matches)) => {
+            match matches.subcommand() {
+                ("depart", Some(m)) => try!(sub_sup_depart(ui, m)),
+                _ => unreachable!(),
+            }
+        }
         _ => unreachable!(),
     };
     Ok(())
@@ -113,6 +138,11 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some("-") | None => None,
         Some(p) => Some(Path::new(p)),
     };
+    let rollback = if m.is_present("ROLLBACK") {
+        Some(value_t!(m, "ROLLBACK", u64).unwrap_or_else(|e| e.exit()))
+    } else {
+        None
+    };
 
     init();
     let cache = default_cache_key_path(Some(&*FS_ROOT));
@@ -120,6 +150,10 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
         None => None,
     };
+    let member_key = match m.value_of("MEMBER_KEY") {
+        Some(path) => Some(try!(MemberKey::read_file(path))),
+        None => None,
+    };
 
     let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
     if let Some(org) = org_param_or_env(&m) {
@@ -134,14 +168,32 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some(username) => Some(try!(BoxKeyPair::get_latest_pair_for(username, &cache))),
         None => None,
     };
-    command::config::apply::start(ui,
-                                  &sg,
-                                  number,
-                                  file_path,
-                                  &peers,
-                                  ring_key.as_ref(),
-                                  user_pair.as_ref(),
-                                  service_pair.as_ref())
+    match rollback {
+        Some(incarnation) => {
+            let remote_sup = m.value_of("REMOTE_SUP").unwrap_or(HABITAT_HTTP_GATEWAY_ADDR);
+            command::config::apply::rollback(ui,
+                                             &sg,
+                                             number,
+                                             incarnation,
+                                             remote_sup,
+                                             &peers,
+                                             ring_key.as_ref(),
+                                             member_key.as_ref(),
+                                             user_pair.as_ref(),
+                                             service_pair.as_ref())
+        }
+        None => {
+            command::config::apply::start(ui,
+                                          &sg,
+                                          number,
+                                          file_path,
+                                          &peers,
+                                          ring_key.as_ref(),
+                                          member_key.as_ref(),
+                                          user_pair.as_ref(),
+                                          service_pair.as_ref())
+        }
+    }
 }
 
 fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -174,6 +226,10 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
         None => None,
     };
+    let member_key = match m.value_of("MEMBER_KEY") {
+        Some(path) => Some(try!(MemberKey::read_file(path))),
+        None => None,
+    };
 
     let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
     if let Some(org) = org_param_or_env(&m) {
@@ -194,10 +250,123 @@ fn sub_file_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
                                  file_path,
                                  &peers,
                                  ring_key.as_ref(),
+                                 member_key.as_ref(),
                                  user_pair.as_ref(),
                                  service_pair.as_ref())
 }
 
+fn sub_secret_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let name = m.value_of("SECRET_NAME").unwrap(); // Required via clap
+    let number = value_t!(m, "VERSION_NUMBER", u64).unwrap_or_else(|e| e.exit());
+    let file_path = match m.value_of("FILE") {
+        Some("-") | None => None,
+        Some(p) => Some(Path::new(p)),
+    };
+
+    init();
+    let cache = default_cache_key_path(Some(&*FS_ROOT));
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+    let member_key = match m.value_of("MEMBER_KEY") {
+        Some(path) => Some(try!(MemberKey::read_file(path))),
+        None => None,
+    };
+
+    let mut sg = try!(ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap()));
+    if let Some(org) = org_param_or_env(&m) {
+        sg.set_org(org);
+    }
+    // Secrets are always encrypted, so both keys are required.
+    if sg.org().is_none() {
+        return Err(Error::CryptoCLI(format!("An organization is required to encrypt secrets \
+                                             for {}; pass --org or set {}",
+                                            sg,
+                                            HABITAT_ORG_ENVVAR)));
+    }
+    let service_pair = try!(BoxKeyPair::get_latest_pair_for(&sg, &cache));
+    let user_pair = match user_param_or_env(&m) {
+        Some(username) => try!(BoxKeyPair::get_latest_pair_for(username, &cache)),
+        None => {
+            return Err(Error::CryptoCLI(format!("A user key is required to encrypt secrets; \
+                                                 pass --user or set {}",
+                                                HABITAT_USER_ENVVAR)))
+        }
+    };
+    command::secret::apply::start(ui,
+                                  &sg,
+                                  name,
+                                  number,
+                                  file_path,
+                                  &peers,
+                                  ring_key.as_ref(),
+                                  member_key.as_ref(),
+                                  &user_pair,
+                                  &service_pair)
+}
+
+fn sub_ring_key_rotate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let ring = m.value_of("RING").unwrap(); // Required via clap
+
+    init();
+    let member_key = match m.value_of("MEMBER_KEY") {
+        Some(path) => Some(try!(MemberKey::read_file(path))),
+        None => None,
+    };
+    command::ring::key::rotate::start(ui,
+                                      ring,
+                                      &peers,
+                                      member_key.as_ref(),
+                                      m.is_present("PROMOTE"),
+                                      m.is_present("RETIRE"),
+                                      &default_cache_key_path(Some(&*FS_ROOT)))
+}
+
+fn sub_sup_depart(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let peers_str = m.value_of("PEER").unwrap_or("127.0.0.1");
+    let mut peers: Vec<String> = peers_str.split(",").map(|p| p.into()).collect();
+    for p in peers.iter_mut() {
+        if p.find(':').is_none() {
+            p.push(':');
+            p.push_str(&HABITAT_BUTTERFLY_PORT.to_string());
+        }
+    }
+    let member_id = m.value_of("MEMBER_ID").unwrap(); // Required via clap
+
+    init();
+    let cache = default_cache_key_path(Some(&*FS_ROOT));
+    let ring_key = match m.value_of("RING") {
+        Some(name) => Some(try!(SymKey::get_latest_pair_for(&name, &cache))),
+        None => None,
+    };
+    let member_key = match m.value_of("MEMBER_KEY") {
+        Some(path) => Some(try!(MemberKey::read_file(path))),
+        None => None,
+    };
+    command::sup::depart::start(ui,
+                                member_id,
+                                &peers,
+                                ring_key.as_ref(),
+                                member_key.as_ref())
+}
+
 fn ui() -> UI {
     let isatty = if henv::var(NONINTERACTIVE_ENVVAR)
            .map(|val| val == "true")
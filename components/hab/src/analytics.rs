@@ -50,7 +50,9 @@
 //! * `file upload`
 //! * `origin key generate`
 //! * `pkg build`
+//! * `ring ca generate`
 //! * `ring key generate`
+//! * `ring key rotate`
 //! * `service key generate`
 //! * `setup`
 //! * `studio build`
@@ -70,6 +72,7 @@
 //! * `install`
 //! * `origin key upload`
 //! * `pkg install`
+//! * `ring key rotate`
 //! * `setup`
 //!
 //! For all other subcommands, even those which report events, the event payload is saved to a
@@ -264,6 +267,8 @@ pub fn instrument_subcommand() {
         // reported.
         ("config", "apply", _) |
         ("file", "upload", _) |
+        ("secret", "apply", _) |
+        ("sup", "depart", _) |
         ("pkg", "build", _) |
         ("pkg", "upload", _) |
         ("studio", "build", _) |
@@ -275,7 +280,9 @@ pub fn instrument_subcommand() {
         // more positional matches left, we ignore all further arguments, options, or flags to that
         // subcommand.
         ("origin", "key", "generate") |
+        ("ring", "ca", "generate") |
         ("ring", "key", "generate") |
+        ("ring", "key", "rotate") |
         ("svc", "key", "generate") |
         ("user", "key", "generate") => {
             record_event(Event::Subcommand,
@@ -315,7 +322,7 @@ pub fn instrument_clap_error(err: &clap::Error) {
     // Use a pattern match against the first program argument.
     match arg1.as_str() {
         // Match against subcommands which are 2 levels deep.
-        "config" | "file" | "pkg" => {
+        "config" | "file" | "secret" | "pkg" => {
             record_event(Event::CliError,
                          &format!("{:?}--{}--{}--{}", err.kind, PRODUCT, arg1, arg2))
         }
@@ -479,9 +486,12 @@ fn should_send() -> bool {
         ("config", "apply", _) |
         ("file", "upload", _) |
         ("install", _, _) |
+        ("secret", "apply", _) |
+        ("sup", "depart", _) |
         ("origin", "key", "upload") |
         ("pkg", "install", _) |
-        ("pkg", "upload", _) => true,
+        ("pkg", "upload", _) |
+        ("ring", "key", "rotate") => true,
         _ => false,
     }
 }
@@ -73,8 +73,17 @@ pub fn get() -> App<'static, 'static> {
                     (default: 127.0.0.1:9638)")
                 (@arg RING: -r --ring +takes_value
                     "Ring key name, which will encrypt communication messages")
+                (@arg MEMBER_KEY: --("member-key") +takes_value {file_exists}
+                    "Path to an operator key issued by the ring CA, which will sign the messages \
+                    for rings authenticating their members (see `hab ring ca issue --operator`)")
             )
         )
+        (@subcommand secret =>
+            (about: "Commands relating to Habitat service secrets")
+            (aliases: &["sec", "secr", "secre"])
+            (@setting ArgRequiredElseHelp)
+            (subcommand: sub_secret_apply().aliases(&["a", "ap", "app", "appl"]))
+        )
         (@subcommand origin =>
             (about: "Commands relating to Habitat origin keys")
             (aliases: &["o", "or", "ori", "orig", "origi"])
@@ -193,6 +202,11 @@ pub fn get() -> App<'static, 'static> {
                     (ex: core/busybox-static/1.24.2/20160708162350)")
                 (@arg FULL_PATHS: -p "Show full path to file")
             )
+            (@subcommand render =>
+                (about: "Renders the configuration and hook templates of an installed package \
+                    without running it, reporting undefined variables and rendering errors")
+                (@setting Hidden)
+            )
             (@subcommand search =>
                 (about: "Search for a package on a Depot")
                 (@arg SEARCH_TERM: +required +takes_value "Search term")
@@ -235,6 +249,10 @@ pub fn get() -> App<'static, 'static> {
                     "A path to a Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
+            (after_help: "\nALIASES:\
+                \n    render     Alias for: 'sup render'\
+                \n"
+            )
         )
         (@subcommand plan =>
             (about: "Commands relating to plans and other app-specific configuration.")
@@ -257,6 +275,29 @@ pub fn get() -> App<'static, 'static> {
             (about: "Commands relating to Habitat rings")
             (aliases: &["r", "ri", "rin"])
             (@setting ArgRequiredElseHelp)
+            (@subcommand ca =>
+                (about: "Commands relating to Habitat ring CA keys, which issue the member keys \
+                    authenticating the Supervisors of a ring")
+                (aliases: &["c"])
+                (@setting ArgRequiredElseHelp)
+                (@subcommand generate =>
+                    (about: "Generates a Habitat ring CA key")
+                    (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
+                    (@arg RING: +required +takes_value "Ring key name")
+                )
+                (@subcommand issue =>
+                    (about: "Issues a member key for a Supervisor with the latest ring CA key and \
+                    outputs it to stdout")
+                    (aliases: &["i", "is", "iss", "issu"])
+                    (@arg RING: +required +takes_value "Ring key name")
+                    (@arg MEMBER_ID: +required +takes_value
+                        "The member id of the Supervisor the key is issued to, or the name of the \
+                        operator with --operator")
+                    (@arg OPERATOR: --operator
+                        "Issue an operator key, which applies configuration, files, secrets, \
+                        departures and ring keys to the ring, rather than a member key")
+                )
+            )
             (@subcommand key =>
                 (about: "Commands relating to Habitat ring keys")
                 (aliases: &["k", "ke"])
@@ -276,6 +317,7 @@ pub fn get() -> App<'static, 'static> {
                     (aliases: &["g", "ge", "gen", "gene", "gener", "genera", "generat"])
                     (@arg RING: +required +takes_value "Ring key name")
                 )
+                (subcommand: sub_ring_key_rotate().aliases(&["r", "ro", "rot", "rota", "rotat"]))
             )
         )
         (@subcommand svc =>
@@ -432,16 +474,73 @@ fn sub_config_apply() -> App<'static, 'static> {
             (default: 127.0.0.1:9638)")
         (@arg RING: -r --ring +takes_value
             "Ring key name, which will encrypt communication messages")
+        (@arg MEMBER_KEY: --("member-key") +takes_value {file_exists}
+            "Path to an operator key issued by the ring CA, which will sign the messages \
+            for rings authenticating their members (see `hab ring ca issue --operator`)")
         (@arg SERVICE_GROUP: +required {valid_service_group}
             "Target service group (ex: redis.default)")
         (@arg VERSION_NUMBER: +required
             "A version number (positive integer) for this configuration (ex: 42)")
-        (@arg FILE: {file_exists_or_stdin}
+        (@arg FILE: {file_exists_or_stdin} conflicts_with[ROLLBACK]
             "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>)")
+        (@arg ROLLBACK: --rollback +takes_value
+            "Re-apply the configuration of an earlier incarnation, read from a Supervisor's \
+            configuration history, instead of a file (ex: 41)")
+        (@arg REMOTE_SUP: --("remote-sup") +takes_value
+            "Address of the HTTP gateway of the Supervisor to read the configuration history \
+            from (default: 127.0.0.1:9631)")
         (@arg ORG: --org +takes_value "Name of service organization")
     )
 }
 
+fn sub_ring_key_rotate() -> App<'static, 'static> {
+    clap_app!(@subcommand rotate =>
+        (about: "Moves a ring to a new revision of its ring key without downtime. Generates the \
+            new revision and hands it to the Supervisors of the ring, which start accepting gossip \
+            encrypted with it. Once every Supervisor has it, run again with --promote to have them \
+            encrypt their gossip with it, and then with --retire to have them stop accepting the \
+            older revisions.")
+        (@arg RING: +required +takes_value "Ring key name")
+        (@arg PROMOTE: --promote
+            "Have the Supervisors encrypt their gossip with the latest revision of the ring key, \
+            instead of handing out a new one")
+        (@arg RETIRE: --retire conflicts_with[PROMOTE]
+            "Have the Supervisors encrypt their gossip with the latest revision of the ring key, \
+            and stop accepting gossip encrypted with any older revision")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+            (default: 127.0.0.1:9638)")
+        (@arg MEMBER_KEY: --("member-key") +takes_value {file_exists}
+            "Path to an operator key issued by the ring CA, which will sign the messages \
+            for rings authenticating their members (see `hab ring ca issue --operator`)")
+    )
+}
+
+fn sub_secret_apply() -> App<'static, 'static> {
+    clap_app!(@subcommand apply =>
+        (about: "Applies a secret to a group of Habitat Supervisors, encrypted to the service \
+            group's key")
+        (@arg PEER: -p --peer +takes_value
+            "A comma-delimited list of one or more Habitat Supervisor peers to infect \
+            (default: 127.0.0.1:9638)")
+        (@arg RING: -r --ring +takes_value
+            "Ring key name, which will encrypt communication messages")
+        (@arg MEMBER_KEY: --("member-key") +takes_value {file_exists}
+            "Path to an operator key issued by the ring CA, which will sign the messages \
+            for rings authenticating their members (see `hab ring ca issue --operator`)")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg SECRET_NAME: +required {valid_secret_name}
+            "Name of the secret, as templates refer to it (ex: password)")
+        (@arg VERSION_NUMBER: +required
+            "A version number (positive integer) for this secret (ex: 42)")
+        (@arg FILE: {file_exists_or_stdin}
+            "Path to local file on disk holding the secret (default: <stdin>)")
+        (@arg ORG: --org +takes_value "Name of service organization to use for encryption")
+        (@arg USER: -u --user +takes_value "Name of a user key to use for encryption")
+    )
+}
+
 fn sub_pkg_build() -> App<'static, 'static> {
     let sub = clap_app!(@subcommand build =>
         (about: "Builds a Plan using a Studio")
@@ -509,6 +608,15 @@ fn valid_pair_type(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_secret_name(val: String) -> result::Result<(), String> {
+    if !val.is_empty() && val.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
+        Ok(())
+    } else {
+        Err(format!("Secret name: '{}' may only contain letters, numbers, '_' and '-'",
+                    &val))
+    }
+}
+
 fn valid_service_group(val: String) -> result::Result<(), String> {
     let regex = Regex::new(r"([A-Za-z_0-9]+)\.([A-Za-z_0-9]+)").unwrap();
     if regex.is_match(&val) {
@@ -85,6 +85,10 @@ mod inner {
             ("config", _) => "config",
             ("file", "upload") => "file upload",
             ("file", _) => "file",
+            ("ring", _) => "ring key rotate",
+            ("secret", "apply") => "secret apply",
+            ("secret", _) => "secret",
+            ("sup", "depart") => "sup depart",
             (_, _) => unreachable!(),
         };
         try!(ui.warn(format!("Running `{}` on this operating system is not currently \
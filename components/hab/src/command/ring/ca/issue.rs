@@ -0,0 +1,35 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use hcore::crypto::{MemberKey, SigKeyPair};
+use hcore::crypto::keys::member_key::ring_ca_name;
+
+use error::Result;
+
+pub fn start(ring: &str, member_id: &str, operator: bool, cache: &Path) -> Result<()> {
+    let ring_ca = try!(SigKeyPair::get_latest_pair_for(&ring_ca_name(ring), cache));
+    let member_key = if operator {
+        try!(MemberKey::generate_operator(member_id, &ring_ca))
+    } else {
+        try!(MemberKey::generate(member_id, &ring_ca))
+    };
+    debug!("Issued {} key for {} with ring CA {}",
+           if operator { "operator" } else { "member" },
+           member_id,
+           ring_ca.name_with_rev());
+    println!("{}", member_key);
+    Ok(())
+}
@@ -33,6 +33,7 @@ pub enum Error {
     ArgumentError(&'static str),
     ButterflyError(String),
     CommandNotFoundInPkg((String, String)),
+    ConfigHistory(String),
     CryptoCLI(String),
     DepotClient(depot_client::Error),
     DockerDaemonDown,
@@ -67,6 +68,9 @@ impl fmt::Display for Error {
                         c,
                         p)
             }
+            Error::ConfigHistory(ref e) => {
+                format!("Can't read the configuration history from the Supervisor: {}", e)
+            }
             Error::CryptoCLI(ref e) => format!("{}", e),
             Error::DepotClient(ref err) => format!("{}", err),
             Error::DockerDaemonDown => format!("Can not connect to Docker. Is the Docker daemon running?"),
@@ -130,6 +134,7 @@ impl error::Error for Error {
             Error::ArgumentError(_) => "There was an error parsing an error or with it's value",
             Error::ButterflyError(_) => "Butterfly has had an error",
             Error::CommandNotFoundInPkg(_) => "Command was not found under any 'PATH' directories in the package",
+            Error::ConfigHistory(_) => "Can't read the configuration history from the Supervisor",
             Error::CryptoCLI(_) => "A cryptographic error has occurred",
             Error::DepotClient(ref err) => err.description(),
             Error::DockerDaemonDown => "The Docker daemon could not be found.",
@@ -137,11 +137,21 @@ fn start(ui: &mut UI) -> Result<()> {
         }
         ("ring", Some(matches)) => {
             match matches.subcommand() {
+                ("ca", Some(m)) => {
+                    match m.subcommand() {
+                        ("generate", Some(sc)) => try!(sub_ring_ca_generate(ui, sc)),
+                        ("issue", Some(sc)) => try!(sub_ring_ca_issue(sc)),
+                        _ => unreachable!(),
+                    }
+                }
                 ("key", Some(m)) => {
                     match m.subcommand() {
                         ("export", Some(sc)) => try!(sub_ring_key_export(sc)),
                         ("import", Some(_)) => try!(sub_ring_key_import(ui)),
                         ("generate", Some(sc)) => try!(sub_ring_key_generate(ui, sc)),
+                        ("rotate", Some(_)) => {
+                            try!(command::butterfly::start(ui, env::args_os().skip(1).collect()))
+                        }
                         _ => unreachable!(),
                     }
                 }
@@ -431,6 +441,25 @@ fn sub_pkg_header(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::header::start(ui, &src)
 }
 
+fn sub_ring_ca_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let ring = m.value_of("RING").unwrap(); // Required via clap
+    init();
+
+    command::ring::ca::generate::start(ui, ring, &default_cache_key_path(Some(&*FS_ROOT)))
+}
+
+fn sub_ring_ca_issue(m: &ArgMatches) -> Result<()> {
+    let ring = m.value_of("RING").unwrap(); // Required via clap
+    let member_id = m.value_of("MEMBER_ID").unwrap(); // Required via clap
+    let operator = m.is_present("OPERATOR");
+    init();
+
+    command::ring::ca::issue::start(ring,
+                                    member_id,
+                                    operator,
+                                    &default_cache_key_path(Some(&*FS_ROOT)))
+}
+
 fn sub_ring_key_export(m: &ArgMatches) -> Result<()> {
     let ring = m.value_of("RING").unwrap(); // Required via clap
     init();
@@ -498,10 +527,12 @@ fn exec_subcommand_if_called(ui: &mut UI) -> Result<()> {
             args.insert(0, OsString::from("config"));
             command::butterfly::start(ui, args)
         }
-        ("config", _) | ("file", _) => {
+        ("config", _) | ("file", _) | ("secret", _) => {
             command::butterfly::start(ui, env::args_os().skip(1).collect())
         }
+        ("pkg", "render") => command::sup::start(ui, env::args_os().skip(2).collect()),
         ("run", _) => command::sup::start(ui, env::args_os().skip(1).collect()),
+        ("sup", "depart") => command::butterfly::start(ui, env::args_os().skip(1).collect()),
         ("stu", _) | ("stud", _) | ("studi", _) | ("studio", _) => {
             command::studio::start(ui, env::args_os().skip(2).collect())
         }
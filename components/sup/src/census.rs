@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
 use std::str::FromStr;
 
@@ -20,10 +20,12 @@ use butterfly::member::{MemberList, Member, Health};
 use butterfly::rumor::RumorStore;
 use butterfly::rumor::service::Service as ServiceRumor;
 use butterfly::rumor::service_file::ServiceFile as ServiceFileRumor;
+use butterfly::rumor::service_secret::ServiceSecret as ServiceSecretRumor;
 use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
 use butterfly::rumor::election::Election as ElectionRumor;
 use butterfly::rumor::election::Election_Status as ElectionStatusRumor;
 use butterfly::rumor::election::ElectionUpdate as ElectionUpdateRumor;
+use butterfly::rumor::release_blacklist::ReleaseBlacklist as ReleaseBlacklistRumor;
 use butterfly::rumor::service::SysInfo;
 use eventsrv::message::event::{CensusEntry as CensusEntryProto, PackageIdent as PackageIdentProto,
                                SysInfo as SysInfoProto};
@@ -33,6 +35,8 @@ use hcore::package::PackageIdent;
 use toml;
 
 use error::{Error, SupError};
+use manager::events::{self, Event};
+use manager::service::{CanarySize, HealthCheck};
 
 static LOGKEY: &'static str = "CE";
 
@@ -50,6 +54,8 @@ pub struct CensusRing {
     last_membership_counter: usize,
     last_service_config_counter: usize,
     last_service_file_counter: usize,
+    last_service_secret_counter: usize,
+    last_release_blacklist_counter: usize,
 }
 
 impl CensusRing {
@@ -66,6 +72,8 @@ impl CensusRing {
             last_membership_counter: 0,
             last_service_config_counter: 0,
             last_service_file_counter: 0,
+            last_service_secret_counter: 0,
+            last_release_blacklist_counter: 0,
         }
     }
 
@@ -75,7 +83,9 @@ impl CensusRing {
                               election_update_rumors: &RumorStore<ElectionUpdateRumor>,
                               member_list: &MemberList,
                               service_config_rumors: &RumorStore<ServiceConfigRumor>,
-                              service_file_rumors: &RumorStore<ServiceFileRumor>) {
+                              service_file_rumors: &RumorStore<ServiceFileRumor>,
+                              service_secret_rumors: &RumorStore<ServiceSecretRumor>,
+                              release_blacklist_rumors: &RumorStore<ReleaseBlacklistRumor>) {
         self.changed = false;
         self.update_from_service_store(service_rumors);
         self.update_from_election_store(election_rumors);
@@ -83,6 +93,8 @@ impl CensusRing {
         self.update_from_member_list(member_list);
         self.update_from_service_config(service_config_rumors);
         self.update_from_service_files(service_file_rumors);
+        self.update_from_service_secrets(service_secret_rumors);
+        self.update_from_release_blacklist(release_blacklist_rumors);
     }
 
     pub fn census_group_for(&self, sg: &ServiceGroup) -> Option<&CensusGroup> {
@@ -189,6 +201,40 @@ impl CensusRing {
         self.last_service_file_counter = service_file_rumors.get_update_counter();
     }
 
+    fn update_from_service_secrets(&mut self,
+                                   service_secret_rumors: &RumorStore<ServiceSecretRumor>) {
+        if service_secret_rumors.get_update_counter() <= self.last_service_secret_counter {
+            return;
+        }
+        self.changed = true;
+        service_secret_rumors.with_keys(|(service_group, rumors)| {
+            if let Ok(sg) = service_group_from_str(service_group) {
+                let mut census_group = self.census_groups
+                    .entry(sg.clone())
+                    .or_insert(CensusGroup::new(sg, &self.local_member_id));
+                census_group.update_from_service_secret_rumors(rumors);
+            }
+        });
+        self.last_service_secret_counter = service_secret_rumors.get_update_counter();
+    }
+
+    fn update_from_release_blacklist(&mut self,
+                                     release_blacklist_rumors: &RumorStore<ReleaseBlacklistRumor>) {
+        if release_blacklist_rumors.get_update_counter() <= self.last_release_blacklist_counter {
+            return;
+        }
+        self.changed = true;
+        release_blacklist_rumors.with_keys(|(service_group, rumors)| {
+            if let Ok(sg) = service_group_from_str(service_group) {
+                let mut census_group = self.census_groups
+                    .entry(sg.clone())
+                    .or_insert(CensusGroup::new(sg, &self.local_member_id));
+                census_group.update_from_release_blacklist_rumors(rumors);
+            }
+        });
+        self.last_release_blacklist_counter = release_blacklist_rumors.get_update_counter();
+    }
+
     fn find_member_mut(&mut self, member_id: &MemberId) -> Option<&mut CensusMember> {
         for group in self.census_groups.values_mut() {
             if let Some(member) = group.find_member_mut(member_id) {
@@ -256,6 +302,24 @@ pub struct ServiceFile {
     pub body: Vec<u8>,
 }
 
+/// A decrypted secret of a service group. Secrets are never serialized, so they stay out of the
+/// census data and the HTTP gateway.
+#[derive(Default)]
+pub struct ServiceSecret {
+    pub name: String,
+    pub incarnation: u64,
+    pub value: Vec<u8>,
+}
+
+impl fmt::Debug for ServiceSecret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "ServiceSecret {{ name: {:?}, incarnation: {} }}",
+               self.name,
+               self.incarnation)
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct ServiceConfig {
     pub incarnation: u64,
@@ -269,12 +333,18 @@ pub struct CensusGroup {
     pub update_election_status: ElectionStatus,
     pub leader_id: Option<MemberId>,
     pub service_config: Option<ServiceConfig>,
+    /// Releases which members of the service group have rolled back from.
+    pub release_blacklist: HashSet<PackageIdent>,
 
     local_member_id: MemberId,
     population: BTreeMap<MemberId, CensusMember>,
     update_leader_id: Option<MemberId>,
     changed_service_files: Vec<String>,
     service_files: HashMap<String, ServiceFile>,
+    #[serde(skip_serializing)]
+    changed_secrets: Vec<String>,
+    #[serde(skip_serializing)]
+    secrets: HashMap<String, ServiceSecret>,
 }
 
 impl CensusGroup {
@@ -288,8 +358,11 @@ impl CensusGroup {
             leader_id: None,
             update_leader_id: None,
             service_config: None,
+            release_blacklist: HashSet::new(),
             service_files: HashMap::new(),
             changed_service_files: Vec::new(),
+            secrets: HashMap::new(),
+            changed_secrets: Vec::new(),
         }
     }
 
@@ -324,6 +397,19 @@ impl CensusGroup {
             .collect()
     }
 
+    /// Returns the decrypted secrets of the service group.
+    pub fn secrets(&self) -> Vec<&ServiceSecret> {
+        self.secrets.values().collect()
+    }
+
+    /// Returns the secrets which changed with the last update from gossip.
+    pub fn changed_secrets(&self) -> Vec<&ServiceSecret> {
+        self.changed_secrets
+            .iter()
+            .map(|name| self.secrets.get(name).unwrap())
+            .collect()
+    }
+
     /// Return previous alive peer, the peer to your left in the ordered members list, or None if
     /// you have no alive peers.
     pub fn previous_peer(&self) -> Option<&CensusMember> {
@@ -348,7 +434,43 @@ impl CensusGroup {
         }
     }
 
+    /// Return the canaries of a canary update, the update leader followed by the alive peers to
+    /// its right in the ordered members list, or an empty list if there is no update leader.
+    pub fn canaries(&self, size: &CanarySize) -> Vec<&CensusMember> {
+        let alive_members: Vec<&CensusMember> = self.population
+            .values()
+            .filter(|cm| cm.alive)
+            .collect();
+        let leader_idx = match self.update_leader() {
+            Some(leader) => {
+                match alive_members
+                          .iter()
+                          .position(|cm| cm.member_id == leader.member_id) {
+                    Some(idx) => idx,
+                    None => return Vec::new(),
+                }
+            }
+            None => return Vec::new(),
+        };
+        alive_members
+            .iter()
+            .cycle()
+            .skip(leader_idx)
+            .take(size.count(alive_members.len()))
+            .map(|cm| *cm)
+            .collect()
+    }
+
     fn update_from_service_rumors(&mut self, rumors: &HashMap<String, ServiceRumor>) {
+        // Members whose service rumors are gone, such as departed members, leave the census.
+        let gone: Vec<MemberId> = self.population
+            .keys()
+            .filter(|member_id| !rumors.contains_key(*member_id))
+            .cloned()
+            .collect();
+        for member_id in gone {
+            self.population.remove(&member_id);
+        }
         for (member_id, service_rumor) in rumors.iter() {
             // Yeah - we are ourself - we're alive.
             let is_self = member_id == &self.local_member_id;
@@ -364,12 +486,15 @@ impl CensusGroup {
     }
 
     fn update_from_election_rumor(&mut self, election: &ElectionRumor) {
-        self.leader_id = None;
+        let previous_leader_id = self.leader_id.take();
         for census_member in self.population.values_mut() {
             if census_member.update_from_election_rumor(election) {
                 self.leader_id = Some(census_member.member_id.clone());
             }
         }
+        if self.leader_id != previous_leader_id {
+            events::publish(Event::leader_changed(&self.service_group, self.leader_id.clone()));
+        }
         match election.get_status() {
             ElectionStatusRumor::Running => {
                 self.election_status = ElectionStatus::ElectionInProgress;
@@ -448,6 +573,53 @@ impl CensusGroup {
         }
     }
 
+    fn update_from_service_secret_rumors(&mut self, rumors: &HashMap<String, ServiceSecretRumor>) {
+        self.changed_secrets.clear();
+        for (name, service_secret_rumor) in rumors.iter() {
+            let incarnation = service_secret_rumor.get_incarnation();
+            if self.secrets
+                   .get(name)
+                   .map_or(false, |secret| secret.incarnation >= incarnation) {
+                continue;
+            }
+            match service_secret_rumor.value() {
+                Ok(value) => {
+                    self.changed_secrets.push(name.clone());
+                    self.secrets.insert(name.clone(),
+                                        ServiceSecret {
+                                            name: name.clone(),
+                                            incarnation: incarnation,
+                                            value: value,
+                                        });
+                }
+                Err(e) => {
+                    warn!("Cannot decrypt service secret for {} {} {}: {}",
+                          self.service_group,
+                          name,
+                          incarnation,
+                          e)
+                }
+            }
+        }
+    }
+
+    fn update_from_release_blacklist_rumors(&mut self,
+                                            rumors: &HashMap<String, ReleaseBlacklistRumor>) {
+        for (pkg, rumor) in rumors.iter() {
+            match PackageIdent::from_str(pkg) {
+                Ok(ident) => {
+                    if self.release_blacklist.insert(ident) {
+                        debug!("{} blacklisted {} for {}",
+                               rumor.get_member_id(),
+                               pkg,
+                               self.service_group);
+                    }
+                }
+                Err(e) => warn!("Ignoring invalid blacklisted release {}: {}", pkg, e),
+            }
+        }
+    }
+
     fn find_member_mut(&mut self, member_id: &MemberId) -> Option<&mut CensusMember> {
         self.population.get_mut(member_id)
     }
@@ -457,6 +629,7 @@ impl CensusGroup {
 pub struct CensusMember {
     pub member_id: MemberId,
     pub pkg: Option<PackageIdent>,
+    pub health_check: HealthCheck,
 
     service: String,
     group: String,
@@ -545,6 +718,7 @@ impl CensusMember {
             Ok(ident) => self.pkg = Some(ident),
             Err(err) => warn!("Received a bad package ident from gossip data, err={}", err),
         };
+        self.health_check = HealthCheck::from(rumor.get_health_check());
         self.sys = rumor.get_sys().clone().into();
         self.cfg = toml::from_slice(rumor.get_cfg()).unwrap_or(toml::value::Table::default());
     }
@@ -606,17 +780,22 @@ fn service_group_from_str(sg: &str) -> Result<ServiceGroup, hcore::Error> {
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use hcore::package::ident::PackageIdent;
     use hcore::service::ServiceGroup;
     use butterfly::member::MemberList;
     use butterfly::rumor::service::Service as ServiceRumor;
     use butterfly::rumor::service_config::ServiceConfig as ServiceConfigRumor;
     use butterfly::rumor::service_file::ServiceFile as ServiceFileRumor;
+    use butterfly::rumor::service_secret::ServiceSecret as ServiceSecretRumor;
     use butterfly::rumor::election::Election as ElectionRumor;
     use butterfly::rumor::election::ElectionUpdate as ElectionUpdateRumor;
+    use butterfly::rumor::release_blacklist::ReleaseBlacklist as ReleaseBlacklistRumor;
     use butterfly::rumor::service::SysInfo;
     use butterfly::rumor::RumorStore;
-    use census::CensusRing;
+    use census::{CensusGroup, CensusRing, ServiceSecret};
+    use serde_json;
 
     #[test]
     fn update_from_rumors() {
@@ -660,13 +839,21 @@ mod tests {
 
         let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
         let service_file_store: RumorStore<ServiceFileRumor> = RumorStore::default();
+        let service_secret_store: RumorStore<ServiceSecretRumor> = RumorStore::default();
+        let release_blacklist_store: RumorStore<ReleaseBlacklistRumor> = RumorStore::default();
+        let blacklisted = "core/overwatch/1.2.4/20170101000000";
+        release_blacklist_store.insert(ReleaseBlacklistRumor::new("member-a",
+                                                                  sg_two.clone(),
+                                                                  blacklisted));
         let mut ring = CensusRing::new("member-b".to_string());
         ring.update_from_rumors(&service_store,
                                 &election_store,
                                 &election_update_store,
                                 &member_list,
                                 &service_config_store,
-                                &service_file_store);
+                                &service_file_store,
+                                &service_secret_store,
+                                &release_blacklist_store);
         let census_group_one = ring.census_group_for(&sg_one).unwrap();
         assert!(census_group_one.me().is_none());
         assert_eq!(census_group_one.leader().unwrap().member_id, "member-a");
@@ -681,5 +868,78 @@ mod tests {
         let members = census_group_two.members();
         assert_eq!(members[0].member_id, "member-a");
         assert_eq!(members[1].member_id, "member-b");
+        let blacklisted = PackageIdent::from_str(blacklisted).unwrap();
+        assert!(census_group_two.release_blacklist.contains(&blacklisted));
+        assert!(census_group_one.release_blacklist.is_empty());
+    }
+
+    #[test]
+    fn members_without_service_rumors_leave_the_census() {
+        let pg_id = PackageIdent::new("starkandwayne",
+                                      "shield",
+                                      Some("0.10.4"),
+                                      Some("20170419115548"));
+        let sg = ServiceGroup::new("shield", "one", None).unwrap();
+        let service_store: RumorStore<ServiceRumor> = RumorStore::default();
+        service_store.insert(ServiceRumor::new("member-a".to_string(),
+                                               &pg_id,
+                                               &sg,
+                                               &SysInfo::new(),
+                                               None));
+        service_store.insert(ServiceRumor::new("member-b".to_string(),
+                                               &pg_id,
+                                               &sg,
+                                               &SysInfo::new(),
+                                               None));
+        let election_store: RumorStore<ElectionRumor> = RumorStore::default();
+        let election_update_store: RumorStore<ElectionUpdateRumor> = RumorStore::default();
+        let member_list = MemberList::new();
+        let service_config_store: RumorStore<ServiceConfigRumor> = RumorStore::default();
+        let service_file_store: RumorStore<ServiceFileRumor> = RumorStore::default();
+        let service_secret_store: RumorStore<ServiceSecretRumor> = RumorStore::default();
+        let release_blacklist_store: RumorStore<ReleaseBlacklistRumor> = RumorStore::default();
+        let mut ring = CensusRing::new("member-b".to_string());
+        ring.update_from_rumors(&service_store,
+                                &election_store,
+                                &election_update_store,
+                                &member_list,
+                                &service_config_store,
+                                &service_file_store,
+                                &service_secret_store,
+                                &release_blacklist_store);
+        assert_eq!(ring.census_group_for(&sg).unwrap().members().len(), 2);
+
+        // This is what a departure of member-a does to the service store.
+        service_store.remove_id("member-a");
+        ring.update_from_rumors(&service_store,
+                                &election_store,
+                                &election_update_store,
+                                &member_list,
+                                &service_config_store,
+                                &service_file_store,
+                                &service_secret_store,
+                                &release_blacklist_store);
+        let members = ring.census_group_for(&sg).unwrap().members();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].member_id, "member-b");
+    }
+
+    #[test]
+    fn secrets_are_not_serialized() {
+        let sg = ServiceGroup::new("shield", "one", None).unwrap();
+        let mut census_group = CensusGroup::new(sg, &"member-a".to_string());
+        census_group.secrets.insert("password".to_string(),
+                                    ServiceSecret {
+                                        name: "password".to_string(),
+                                        incarnation: 1,
+                                        value: Vec::from("hunter2"),
+                                    });
+        census_group.changed_secrets.push("password".to_string());
+        assert_eq!(census_group.changed_secrets()[0].value, Vec::from("hunter2"));
+
+        let json = serde_json::to_string(&census_group).unwrap();
+        assert!(!json.contains("password"));
+        assert!(!json.contains("hunter2"));
+        assert!(!format!("{:?}", census_group).contains("hunter2"));
     }
 }
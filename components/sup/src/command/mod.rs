@@ -14,5 +14,6 @@
 
 //! The CLI commands.
 
+pub mod render;
 pub mod shell;
 pub mod start;
@@ -0,0 +1,544 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renders the configuration and hook templates of an installed package without running it.
+//!
+//! The templates are rendered with the same context a Supervisor renders them with, built from
+//! the package's `default.toml`, an optional user TOML overlay and an optional simulated census:
+//! a TOML or JSON file describing the other members of the service group, the service groups it
+//! binds to and its secrets. The Supervisor rendering the templates is always a member of the
+//! service group; `svc.me` flags it as a leader. Members of the service group run the rendered
+//! package unless they give a `pkg`, members of a bound service group run `simulated/<service>`.
+//!
+//! ```toml
+//! [svc]
+//! group = "production"
+//!
+//! [[svc.members]]
+//! member_id = "a1"
+//! leader = true
+//! [svc.members.sys]
+//! ip = "10.0.0.1"
+//!
+//! [bind.database]
+//! service_group = "postgresql.production"
+//! [[bind.database.members]]
+//! [bind.database.members.sys]
+//! ip = "10.0.0.10"
+//! [bind.database.members.cfg]
+//! port = 5432
+//!
+//! [secret]
+//! password = "not-the-real-one"
+//! ```
+//!
+//! Every template is checked for undefined variables and rendered, and any problem found makes
+//! the command fail.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab sup render acme/redis --user-toml user.toml --census census.toml
+//! ```
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use ansi_term::Colour::{Green, Red};
+use butterfly::member::{Health, Member, MemberList};
+use butterfly::rumor::RumorStore;
+use butterfly::rumor::election::{Election as ElectionRumor, ElectionUpdate as ElectionUpdateRumor};
+use butterfly::rumor::service::{Service as ServiceRumor, SysInfo};
+use hcore::fs::FS_ROOT_PATH;
+use hcore::package::{Identifiable, PackageIdent, PackageInstall};
+use hcore::service::ServiceGroup;
+use serde_json::{self, Value as Json};
+use toml;
+
+use census::CensusRing;
+use config::GossipListenAddr;
+use error::{Error, Result};
+use fs::write_private;
+use http_gateway;
+use manager::Sys;
+use manager::service::{Cfg, Pkg, ServiceBind};
+use templating::{RenderContext, Secrets, TemplateRenderer};
+use templating::lint;
+
+static LOGKEY: &'static str = "RN";
+
+/// A simulated census, as read from the census file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Census {
+    /// The census file, for error messages.
+    #[serde(skip)]
+    path: PathBuf,
+    svc: Group,
+    bind: HashMap<String, Group>,
+    secret: HashMap<String, String>,
+}
+
+/// A simulated service group: the group and organization of the rendered service, or the
+/// service group of a bind.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Group {
+    group: Option<String>,
+    org: Option<String>,
+    service_group: Option<String>,
+    me: SimulatedMember,
+    members: Vec<SimulatedMember>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SimulatedMember {
+    member_id: Option<String>,
+    pkg: Option<String>,
+    leader: bool,
+    update_leader: bool,
+    suspect: bool,
+    confirmed: bool,
+    sys: SimulatedSys,
+    cfg: toml::value::Table,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SimulatedSys {
+    ip: Option<String>,
+    hostname: Option<String>,
+    gossip_ip: Option<String>,
+    gossip_port: Option<u32>,
+    http_gateway_ip: Option<String>,
+    http_gateway_port: Option<u32>,
+}
+
+/// The rumors a simulated census is built from.
+struct Rumors {
+    services: RumorStore<ServiceRumor>,
+    elections: RumorStore<ElectionRumor>,
+    election_updates: RumorStore<ElectionUpdateRumor>,
+    members: MemberList,
+}
+
+pub fn run(ident: &PackageIdent,
+           config_from: Option<&Path>,
+           user_toml: Option<&Path>,
+           census: Option<&Path>,
+           output: Option<&Path>)
+           -> Result<()> {
+    let package = PackageInstall::load(ident, Some(Path::new(&*FS_ROOT_PATH)))?;
+    let pkg = Pkg::from_install(package)?;
+    let root = config_from.unwrap_or(pkg.path.as_path()).to_path_buf();
+    let mut cfg = Cfg::new(&pkg, Some(&root))?;
+    if let Some(path) = user_toml {
+        cfg.user = Some(toml::Value::Table(read_toml(path)?));
+    }
+    let sys = Sys::new(false,
+                       GossipListenAddr::default(),
+                       http_gateway::ListenAddr::default());
+    let census = match census {
+        Some(path) => read_census(path)?,
+        None => Census::default(),
+    };
+    let group = census.svc.group.as_ref().map_or("default", |g| g.as_str());
+    let org = census.svc.org.as_ref().map(|o| o.as_str());
+    let service_group = ServiceGroup::new(&pkg.name, group, org)?;
+    let mut binds = Vec::new();
+    for (name, group) in census.bind.iter() {
+        let bind_group = match group.service_group {
+            Some(ref sg) => ServiceGroup::from_str(sg)?,
+            None => ServiceGroup::new(name, service_group.group(), service_group.org())?,
+        };
+        binds.push(ServiceBind {
+                       name: name.clone(),
+                       service_group: bind_group,
+                   });
+    }
+    let me = ServiceRumor::new(sys.member_id.as_str(),
+                               &pkg.ident,
+                               &service_group,
+                               &sys.as_sys_info(),
+                               Some(&cfg.to_exported(&pkg)?));
+    let ring = simulate_census(me, &service_group, &pkg.ident, &binds, &census)?;
+    let mut ctx = RenderContext::new(&service_group, &sys, &pkg, &cfg, &ring, binds.iter());
+    ctx.secret = Secrets::from(census.secret.clone());
+    let ctx = serde_json::to_value(&ctx)
+        .map_err(|e| sup_error!(Error::RenderContextSerialization(e)))?;
+
+    let mut problems = 0;
+    for dir in &["config", "hooks"] {
+        for path in templates(&root.join(dir))? {
+            let name = format!("{}/{}", dir, path.file_name().unwrap().to_string_lossy());
+            problems += render(&name, &path, &root, &ctx, output)?;
+        }
+    }
+    if problems > 0 {
+        return Err(sup_error!(Error::InvalidTemplates(problems)));
+    }
+    Ok(())
+}
+
+/// Checks and renders one template, returning the number of problems found. Partials are
+/// included from the `config/` directory of `root`, as the Supervisor would.
+fn render(name: &str,
+          path: &Path,
+          root: &Path,
+          ctx: &Json,
+          output: Option<&Path>)
+          -> Result<usize> {
+    let mut template = String::new();
+    File::open(path)?.read_to_string(&mut template)?;
+    let mut renderer = TemplateRenderer::new();
+    renderer.include_from(root.join("config"));
+    if let Err(err) = renderer.register_template_string(name, template.clone()) {
+        outputln!("{} {}: {}", Red.bold().paint("✗"), name, err);
+        return Ok(1);
+    }
+    let mut problems = 0;
+    for variable in lint::undefined_variables(&template, ctx) {
+        outputln!("{} {}: {}", Red.bold().paint("✗"), name, variable);
+        problems += 1;
+    }
+    match renderer.render(name, ctx) {
+        Ok(rendered) => {
+            if let Some(dir) = output {
+                let dest = dir.join(name);
+                if let Some(parent) = dest.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                // Any template may render the secrets, so with any every file is kept private.
+                if ctx["secret"].as_object().map_or(false, |secret| !secret.is_empty()) {
+                    write_private(&dest, rendered.as_bytes(), 0o600, None)?;
+                } else {
+                    File::create(&dest)?.write_all(rendered.as_bytes())?;
+                }
+            }
+        }
+        Err(err) => {
+            outputln!("{} {}: {}", Red.bold().paint("✗"), name, err.err);
+            problems += 1;
+        }
+    }
+    if problems == 0 {
+        outputln!("{} {}", Green.bold().paint("✓"), name);
+    }
+    Ok(problems)
+}
+
+/// Returns the template files of the given directory, sorted by name.
+fn templates(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut templates = Vec::new();
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                templates.push(entry.path());
+            }
+        }
+    }
+    templates.sort();
+    Ok(templates)
+}
+
+fn read_toml(path: &Path) -> Result<toml::value::Table> {
+    let mut content = String::new();
+    File::open(path)?.read_to_string(&mut content)?;
+    Ok(toml::de::from_str(&content)?)
+}
+
+fn read_census(path: &Path) -> Result<Census> {
+    let mut content = String::new();
+    File::open(path)?.read_to_string(&mut content)?;
+    let census: ::std::result::Result<Census, String> =
+        if path.extension().map_or(false, |ext| ext == "json") {
+            serde_json::from_str(&content).map_err(|e| e.to_string())
+        } else {
+            toml::de::from_str(&content).map_err(|e| e.to_string())
+        };
+    let mut census = census.map_err(|e| sup_error!(Error::BadCensusFile(path.to_path_buf(), e)))?;
+    census.path = path.to_path_buf();
+    Ok(census)
+}
+
+/// Builds the census a Supervisor running the package would see, from its own service rumor and
+/// the rumors of the simulated members of its service group and binds.
+fn simulate_census(me: ServiceRumor,
+                   service_group: &ServiceGroup,
+                   ident: &PackageIdent,
+                   binds: &[ServiceBind],
+                   census: &Census)
+                   -> Result<CensusRing> {
+    let rumors = Rumors::new();
+    let member_id = me.get_member_id().to_string();
+    rumors.services.insert(me);
+    elect(&rumors, &member_id, service_group, &census.svc.me);
+    for (idx, member) in census.svc.members.iter().enumerate() {
+        rumors
+            .add(service_group, idx, member, ident)
+            .map_err(|e| sup_error!(Error::BadCensusFile(census.path.clone(), e)))?;
+    }
+    for bind in binds {
+        let ident = PackageIdent::new("simulated",
+                                      bind.service_group.service(),
+                                      Some("0.0.0"),
+                                      Some("19700101000000"));
+        for (idx, member) in census.bind[&bind.name].members.iter().enumerate() {
+            rumors
+                .add(&bind.service_group, idx, member, &ident)
+                .map_err(|e| sup_error!(Error::BadCensusFile(census.path.clone(), e)))?;
+        }
+    }
+    let mut ring = CensusRing::new(member_id);
+    ring.update_from_rumors(&rumors.services,
+                            &rumors.elections,
+                            &rumors.election_updates,
+                            &rumors.members,
+                            &RumorStore::default(),
+                            &RumorStore::default(),
+                            &RumorStore::default(),
+                            &RumorStore::default());
+    Ok(ring)
+}
+
+impl Rumors {
+    fn new() -> Self {
+        Rumors {
+            services: RumorStore::default(),
+            elections: RumorStore::default(),
+            election_updates: RumorStore::default(),
+            members: MemberList::new(),
+        }
+    }
+
+    /// Adds the rumors of a simulated member, which runs the given package unless it gives one.
+    fn add(&self,
+           service_group: &ServiceGroup,
+           idx: usize,
+           member: &SimulatedMember,
+           ident: &PackageIdent)
+           -> ::std::result::Result<(), String> {
+        let member_id = member
+            .member_id
+            .clone()
+            .unwrap_or_else(|| format!("{}-{}", service_group, idx));
+        let ident = match member.pkg {
+            Some(ref pkg) => PackageIdent::from_str(pkg).map_err(|e| e.to_string())?,
+            None => ident.clone(),
+        };
+        if !ident.fully_qualified() || ident.name != service_group.service() {
+            return Err(format!("member {} of {} can't run {}, a fully qualified {} package is \
+                                required",
+                               member_id,
+                               service_group,
+                               ident,
+                               service_group.service()));
+        }
+        self.services
+            .insert(ServiceRumor::new(member_id.as_str(),
+                                      &ident,
+                                      service_group,
+                                      &member.sys.as_sys_info(),
+                                      Some(&member.cfg)));
+        let mut proto = Member::default();
+        proto.set_id(member_id.clone());
+        let health = if member.confirmed {
+            Health::Confirmed
+        } else if member.suspect {
+            Health::Suspect
+        } else {
+            Health::Alive
+        };
+        self.members.insert(proto, health);
+        elect(self, &member_id, service_group, member);
+        Ok(())
+    }
+}
+
+/// Records the member as the leader or update leader of its service group if it is flagged so.
+fn elect(rumors: &Rumors,
+         member_id: &str,
+         service_group: &ServiceGroup,
+         member: &SimulatedMember) {
+    if member.leader {
+        let mut election = ElectionRumor::new(member_id, service_group.clone(), 0);
+        election.finish();
+        rumors.elections.insert(election);
+    }
+    if member.update_leader {
+        let mut election = ElectionUpdateRumor::new(member_id, service_group.clone(), 0);
+        election.finish();
+        rumors.election_updates.insert(election);
+    }
+}
+
+impl SimulatedSys {
+    /// The simulated system information, with the defaults of the gossip protocol for anything
+    /// left out.
+    fn as_sys_info(&self) -> SysInfo {
+        let mut sys_info = SysInfo::new();
+        if let Some(ref ip) = self.ip {
+            sys_info.set_ip(ip.clone());
+        }
+        if let Some(ref hostname) = self.hostname {
+            sys_info.set_hostname(hostname.clone());
+        }
+        if let Some(ref ip) = self.gossip_ip {
+            sys_info.set_gossip_ip(ip.clone());
+        }
+        if let Some(port) = self.gossip_port {
+            sys_info.set_gossip_port(port);
+        }
+        if let Some(ref ip) = self.http_gateway_ip {
+            sys_info.set_http_gateway_ip(ip.clone());
+        }
+        if let Some(port) = self.http_gateway_port {
+            sys_info.set_http_gateway_port(port);
+        }
+        sys_info
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::{Read, Write};
+    use std::str::FromStr;
+
+    use butterfly::rumor::service::{Service as ServiceRumor, SysInfo};
+    use hcore::package::PackageIdent;
+    use hcore::service::ServiceGroup;
+    use serde_json::Value as Json;
+    use tempdir::TempDir;
+    use toml;
+
+    use manager::service::ServiceBind;
+    use super::{render, simulate_census, Census};
+
+    fn census(content: &str) -> Census {
+        toml::de::from_str(content).unwrap()
+    }
+
+    fn redis() -> (ServiceGroup, PackageIdent) {
+        (ServiceGroup::from_str("redis.production").unwrap(),
+         PackageIdent::from_str("core/redis/3.2.4/20170101000000").unwrap())
+    }
+
+    fn me(service_group: &ServiceGroup, ident: &PackageIdent) -> ServiceRumor {
+        ServiceRumor::new("local", ident, service_group, &SysInfo::new(), None)
+    }
+
+    #[test]
+    fn simulated_census_without_members() {
+        let (sg, ident) = redis();
+        let ring = simulate_census(me(&sg, &ident), &sg, &ident, &[], &Census::default())
+            .unwrap();
+        let group = ring.census_group_for(&sg).unwrap();
+        assert_eq!(group.me().unwrap().member_id, "local");
+        assert_eq!(group.members().len(), 1);
+        assert!(group.leader().is_none());
+    }
+
+    #[test]
+    fn simulated_census_with_members() {
+        let (sg, ident) = redis();
+        let census = census(r#"
+            [svc.me]
+            update_leader = true
+
+            [[svc.members]]
+            member_id = "a"
+            [svc.members.sys]
+            ip = "10.0.0.1"
+
+            [[svc.members]]
+            member_id = "b"
+            leader = true
+            pkg = "core/redis/3.2.5/20170201000000"
+        "#);
+        let ring = simulate_census(me(&sg, &ident), &sg, &ident, &[], &census).unwrap();
+        let group = ring.census_group_for(&sg).unwrap();
+        assert_eq!(group.members().len(), 3);
+        assert_eq!(group.leader().unwrap().member_id, "b");
+        assert_eq!(group.leader().unwrap().pkg.as_ref().unwrap().version,
+                   Some("3.2.5".to_string()));
+        assert_eq!(group.update_leader().unwrap().member_id, "local");
+    }
+
+    #[test]
+    fn simulated_binds() {
+        let (sg, ident) = redis();
+        let census = census(r#"
+            [bind.database]
+            service_group = "postgresql.production"
+            [[bind.database.members]]
+            [bind.database.members.cfg]
+            port = 5432
+        "#);
+        let bind = ServiceBind {
+            name: "database".to_string(),
+            service_group: ServiceGroup::from_str("postgresql.production").unwrap(),
+        };
+        let ring = simulate_census(me(&sg, &ident), &sg, &ident, &[bind.clone()], &census)
+            .unwrap();
+        let group = ring.census_group_for(&bind.service_group).unwrap();
+        let members = group.members();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].member_id, "postgresql.production-0");
+        assert_eq!(members[0].pkg.as_ref().unwrap().name, "postgresql");
+    }
+
+    #[test]
+    fn simulated_member_running_another_package() {
+        let (sg, ident) = redis();
+        let census = census(r#"
+            [[svc.members]]
+            pkg = "core/nginx/1.11.10/20170301000000"
+        "#);
+        assert!(simulate_census(me(&sg, &ident), &sg, &ident, &[], &census).is_err());
+    }
+
+    #[test]
+    fn render_includes_partials_from_the_config_root() {
+        let root = TempDir::new("render").unwrap();
+        let output = TempDir::new("render-output").unwrap();
+        fs::create_dir_all(root.path().join("config").join("partials")).unwrap();
+        let template = root.path().join("config").join("redis.config");
+        File::create(&template)
+            .and_then(|mut file| file.write_all(b"{{include \"partials/port.conf\"}}"))
+            .unwrap();
+        File::create(root.path().join("config").join("partials").join("port.conf"))
+            .and_then(|mut file| file.write_all(b"port 6379"))
+            .unwrap();
+
+        let ctx = Json::Object(Default::default());
+        assert_eq!(render("config/redis.config",
+                          &template,
+                          root.path(),
+                          &ctx,
+                          Some(output.path()))
+                           .unwrap(),
+                   0);
+        let mut rendered = String::new();
+        File::open(output.path().join("config").join("redis.config"))
+            .and_then(|mut file| file.read_to_string(&mut rendered))
+            .unwrap();
+        assert_eq!(rendered, "port 6379");
+    }
+}
@@ -62,6 +62,7 @@ use serde_json;
 use toml;
 
 use output::StructuredOutput;
+use templating::lint::UndefinedVariable;
 use PROGRAM_NAME;
 
 static LOGKEY: &'static str = "ER";
@@ -102,14 +103,26 @@ impl SupError {
 /// All the kinds of errors we produce.
 #[derive(Debug)]
 pub enum Error {
+    BadCanarySize(String),
+    BadCensusFile(PathBuf, String),
     BadDataFile(PathBuf, io::Error),
     BadDataPath(PathBuf, io::Error),
     BadDesiredState(String),
     BadElectionStatus(String),
+    BadMemberKey(PathBuf, hcore::error::Error),
     BadPackage(PackageInstall, hcore::error::Error),
+    BadRestartPolicy(String),
     BadSpecsPath(PathBuf, io::Error),
     BadStartStyle(String),
+    BadTaskSchedule(String),
+    BadUpdateTimezone(String),
+    BadUpdateWindow(String),
+    BadVersionConstraint(String),
     ButterflyError(butterfly::error::Error),
+    CgroupIO(PathBuf, io::Error),
+    ConfigHistorySerialization(serde_json::Error),
+    DependencyCycle(Vec<String>),
+    Departed(String),
     DepotClient(depot_client::Error),
     EnvJoinPathsError(env::JoinPathsError),
     ExecCommandNotFound(String),
@@ -120,10 +133,15 @@ pub enum Error {
     TemplateRenderError(handlebars::RenderError),
     InvalidBinding(String),
     InvalidBinds(Vec<String>),
+    InvalidDependency(String),
     InvalidKeyParameter(String),
     InvalidPidFile,
     InvalidTopology(String),
     InvalidUpdateStrategy(String),
+    InvalidHealthProbe(String),
+    InvalidProbeKind(String),
+    InvalidResourceLimits(String),
+    InvalidTemplates(usize),
     Io(io::Error),
     IPFailed,
     MissingRequiredBind(Vec<String>),
@@ -137,6 +155,7 @@ pub enum Error {
     ProcessLocked(process::Pid),
     ProcessLockIO(PathBuf, io::Error),
     RenderContextSerialization(serde_json::Error),
+    RetiredRingKey(String),
     ServiceDeserializationError(serde_json::Error),
     ServiceLoaded(package::PackageIdent),
     ServiceNotLoaded(package::PackageIdent),
@@ -154,6 +173,7 @@ pub enum Error {
     TomlMergeError(String),
     TomlParser(toml::de::Error),
     TryRecvError(mpsc::TryRecvError),
+    UndefinedTemplateVariables(String, Vec<UndefinedVariable>),
     UnpackFailed,
 }
 
@@ -175,15 +195,58 @@ impl fmt::Display for SupError {
             Error::BadDesiredState(ref state) => {
                 format!("Unknown service desired state style '{}'", state)
             }
+            Error::BadCensusFile(ref path, ref err) => {
+                format!("Unable to read simulated census file {}, {}",
+                        path.display(),
+                        err)
+            }
+            Error::BadCanarySize(ref size) => {
+                format!("Invalid canary size '{}', expected a number of members or a percentage",
+                        size)
+            }
             Error::BadElectionStatus(ref status) => format!("Unknown election status '{}'", status),
+            Error::BadMemberKey(ref path, ref err) => {
+                format!("Unable to read the member key issued by the ring CA, {}, {}",
+                        path.display(),
+                        err)
+            }
             Error::BadPackage(ref pkg, ref err) => format!("Bad package, {}, {}", pkg, err),
             Error::BadSpecsPath(ref path, ref err) => {
                 format!("Unable to create the specs directory '{}' ({})",
                         path.display(),
                         err)
             }
+            Error::BadRestartPolicy(ref policy) => {
+                format!("Unknown service restart policy '{}'", policy)
+            }
             Error::BadStartStyle(ref style) => format!("Unknown service start style '{}'", style),
+            Error::BadTaskSchedule(ref schedule) => {
+                format!("Invalid task schedule '{}', expected a cron expression such as \
+                        '0 3 * * *'",
+                        schedule)
+            }
+            Error::BadUpdateTimezone(ref tz) => {
+                format!("Invalid update timezone '{}', expected UTC, local or an offset such as \
+                        +02:00",
+                        tz)
+            }
+            Error::BadUpdateWindow(ref window) => {
+                format!("Invalid update window '{}', expected a cron expression or a day and \
+                        time range such as 'Mon-Fri 22:00-02:00'",
+                        window)
+            }
+            Error::BadVersionConstraint(ref constraint) => {
+                format!("Invalid version constraint '{}', expected requirements such as 1.4.x, \
+                        ~1.4.2 or '>=1.2, <2.0'",
+                        constraint)
+            }
             Error::ButterflyError(ref err) => format!("Butterfly error: {}", err),
+            Error::CgroupIO(ref path, ref err) => {
+                format!("Unable to configure cgroup at {}, {}", path.display(), err)
+            }
+            Error::ConfigHistorySerialization(ref e) => {
+                format!("Can't serialize configuration history: {}", e)
+            }
             Error::ExecCommandNotFound(ref c) => {
                 format!("`{}' was not found on the filesystem or in PATH", c)
             }
@@ -192,6 +255,16 @@ impl fmt::Display for SupError {
             Error::HabitatCore(ref err) => format!("{}", err),
             Error::TemplateFileError(ref err) => format!("{:?}", err),
             Error::TemplateRenderError(ref err) => format!("{}", err),
+            Error::DependencyCycle(ref names) => {
+                format!("Services can't require or be started after each other in a cycle, {}",
+                        names.join(" -> "))
+            }
+            Error::Departed(ref member_id) => {
+                format!("This Supervisor's member {} was departed from the ring; it cannot \
+                         rejoin under the same member id. Remove the MEMBER_ID file from the \
+                         Supervisor's data path to rejoin as a new member.",
+                        member_id)
+            }
             Error::DepotClient(ref err) => format!("{}", err),
             Error::EnvJoinPathsError(ref err) => format!("{}", err),
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
@@ -201,12 +274,21 @@ impl fmt::Display for SupError {
                         binding)
             }
             Error::InvalidBinds(ref e) => format!("Invalid bind(s), {}", e.join(", ")),
+            Error::InvalidDependency(ref name) => {
+                format!("Service {} can't require or be started after itself", name)
+            }
             Error::InvalidKeyParameter(ref e) => {
                 format!("Invalid parameter for key generation: {:?}", e)
             }
             Error::InvalidPidFile => format!("Invalid child process PID file"),
             Error::InvalidTopology(ref t) => format!("Invalid topology: {}", t),
             Error::InvalidUpdateStrategy(ref s) => format!("Invalid update strategy: {}", s),
+            Error::InvalidHealthProbe(ref e) => format!("Invalid health probe: {}", e),
+            Error::InvalidProbeKind(ref k) => format!("Invalid health probe kind: {}", k),
+            Error::InvalidResourceLimits(ref e) => format!("Invalid resource limits: {}", e),
+            Error::InvalidTemplates(count) => {
+                format!("Found {} problem(s) rendering templates", count)
+            }
             Error::Io(ref err) => format!("{}", err),
             Error::IPFailed => format!("Failed to discover this hosts outbound IP address"),
             Error::MissingRequiredBind(ref e) => {
@@ -240,6 +322,11 @@ impl fmt::Display for SupError {
             Error::RenderContextSerialization(ref e) => {
                 format!("Unable to serialize rendering context, {}", e)
             }
+            Error::RetiredRingKey(ref name_with_rev) => {
+                format!("Ring key {} was retired by the ring, and no newer revision of it is in \
+                         the key cache",
+                        name_with_rev)
+            }
             Error::ServiceDeserializationError(ref e) => {
                 format!("Can't deserialize service status: {}", e)
             }
@@ -274,6 +361,12 @@ impl fmt::Display for SupError {
             Error::TomlMergeError(ref e) => format!("Failed to merge TOML: {}", e),
             Error::TomlParser(ref err) => format!("Failed to parse TOML: {}", err),
             Error::TryRecvError(ref err) => format!("{}", err),
+            Error::UndefinedTemplateVariables(ref template, ref variables) => {
+                let variables: Vec<String> = variables.iter().map(|v| v.to_string()).collect();
+                format!("Template {} refers to undefined variables, {}",
+                        template,
+                        variables.join(", "))
+            }
             Error::UnpackFailed => format!("Failed to unpack a package"),
         };
         let cstring = Red.bold().paint(content).to_string();
@@ -293,27 +386,46 @@ impl error::Error for SupError {
     fn description(&self) -> &str {
         match self.err {
             Error::BadDataFile(_, _) => "Unable to read or write to a data file",
+            Error::BadCanarySize(_) => "Invalid canary size in service spec",
+            Error::BadCensusFile(_, _) => "Unable to read simulated census file",
             Error::BadDataPath(_, _) => "Unable to read or write to data directory",
             Error::BadElectionStatus(_) => "Unknown election status",
             Error::BadDesiredState(_) => "Unknown desired state in service spec",
+            Error::BadMemberKey(_, _) => "Unable to read the member key issued by the ring CA",
             Error::BadPackage(_, _) => "Package was malformed or contained malformed contents",
             Error::BadSpecsPath(_, _) => "Unable to create the specs directory",
+            Error::BadRestartPolicy(_) => "Unknown restart policy in service spec",
             Error::BadStartStyle(_) => "Unknown start style in service spec",
+            Error::BadTaskSchedule(_) => "Invalid task schedule in service spec",
+            Error::BadUpdateTimezone(_) => "Invalid update timezone in service spec",
+            Error::BadUpdateWindow(_) => "Invalid update window in service spec",
+            Error::BadVersionConstraint(_) => "Invalid version constraint in service spec",
             Error::ButterflyError(ref err) => err.description(),
+            Error::CgroupIO(_, _) => "Unable to configure cgroup",
+            Error::ConfigHistorySerialization(_) => "Can't serialize configuration history",
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
             Error::TemplateFileError(ref err) => err.description(),
             Error::TemplateRenderError(ref err) => err.description(),
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::DependencyCycle(_) => {
+                "Services can't require or be started after each other in a cycle"
+            }
+            Error::Departed(_) => "This Supervisor was departed from the ring",
             Error::DepotClient(ref err) => err.description(),
             Error::EnvJoinPathsError(ref err) => err.description(),
             Error::FileNotFound(_) => "File not found",
             Error::InvalidBinding(_) => "Invalid binding parameter",
             Error::InvalidBinds(_) => "Service binds detected that are neither required nor optional package binds",
+            Error::InvalidDependency(_) => "Service can't require or be started after itself",
             Error::InvalidKeyParameter(_) => "Key parameter error",
             Error::InvalidPidFile => "Invalid child process PID file",
             Error::InvalidTopology(_) => "Invalid topology",
             Error::InvalidUpdateStrategy(_) => "Invalid update strategy",
+            Error::InvalidHealthProbe(_) => "Invalid health probe",
+            Error::InvalidProbeKind(_) => "Invalid health probe kind",
+            Error::InvalidResourceLimits(_) => "Invalid resource limits",
+            Error::InvalidTemplates(_) => "Problems found rendering templates",
             Error::Io(ref err) => err.description(),
             Error::IPFailed => "Failed to discover the outbound IP address",
             Error::MissingRequiredBind(_) => "A service to start without specifying a service group for all required binds",
@@ -327,6 +439,7 @@ impl error::Error for SupError {
             Error::ProcessLocked(_) => "Another instance of the Habitat Supervisor is already running",
             Error::ProcessLockIO(_, _) => "Unable to write or read to a process lock",
             Error::RenderContextSerialization(_) => "Unable to serialize rendering context",
+            Error::RetiredRingKey(_) => "Ring key was retired by the ring",
             Error::ServiceDeserializationError(_) => "Can't deserialize service status",
             Error::ServiceNotLoaded(_) => "Service status called when service not loaded",
             Error::ServiceLoaded(_) => "Service load or start called when service already loaded",
@@ -344,6 +457,7 @@ impl error::Error for SupError {
             Error::TomlMergeError(_) => "Failed to merge TOML!",
             Error::TomlParser(_) => "Failed to parse TOML!",
             Error::TryRecvError(_) => "A channel failed to receive a response",
+            Error::UndefinedTemplateVariables(_, _) => "Template refers to undefined variables",
             Error::UnpackFailed => "Failed to unpack a package",
         }
     }
@@ -12,8 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+
 use hcore::fs::FS_ROOT_PATH;
+use hcore::util::perm::{set_owner, set_permissions};
+
+use error::Result;
 
 lazy_static! {
     /// The root path containing all runtime service directories and files
@@ -42,6 +48,11 @@ pub fn svc_files_path<T: AsRef<Path>>(service_name: T) -> PathBuf {
     svc_path(service_name).join("files")
 }
 
+/// Returns the path to a given service's gossiped secrets.
+pub fn svc_secrets_path<T: AsRef<Path>>(service_name: T) -> PathBuf {
+    svc_path(service_name).join("secrets")
+}
+
 /// Returns the path to a given service's hooks.
 pub fn svc_hooks_path<T: AsRef<Path>>(service_name: T) -> PathBuf {
     svc_path(service_name).join("hooks")
@@ -66,3 +77,103 @@ pub fn svc_logs_path<T: AsRef<Path>>(service_name: T) -> PathBuf {
 pub fn svc_pid_file<T: AsRef<Path>>(service_name: T) -> PathBuf {
     svc_path(service_name).join("PID")
 }
+
+/// Writes a file through a temporary file next to it, which is created with the given permissions
+/// and given the owner before it replaces the file. Files which may hold secrets are written this
+/// way, so their contents are never readable by others, not even while they are written or if the
+/// file already existed with looser permissions.
+pub fn write_private<P>(path: P,
+                        contents: &[u8],
+                        permissions: u32,
+                        owner: Option<(&str, &str)>)
+                        -> Result<()>
+    where P: AsRef<Path>
+{
+    let mut tmp_path = path.as_ref().as_os_str().to_owned();
+    tmp_path.push(".write");
+    let tmp_path = PathBuf::from(tmp_path);
+    create_with_permissions(&tmp_path, permissions)?
+        .write_all(contents)?;
+    if let Some((user, group)) = owner {
+        set_owner(&tmp_path, user, group)?;
+    }
+    // The permissions given at creation are masked by the umask.
+    set_permissions(&tmp_path, permissions)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// Creates a new file which already has the given permissions, so that the contents of secret
+/// files are never readable by others, not even until their permissions are set. A file left
+/// over by an earlier write is replaced.
+#[cfg(unix)]
+pub fn create_with_permissions<P>(path: P, permissions: u32) -> io::Result<File>
+    where P: AsRef<Path>
+{
+    use std::os::unix::fs::OpenOptionsExt;
+
+    if let Err(e) = fs::remove_file(&path) {
+        if e.kind() != io::ErrorKind::NotFound {
+            return Err(e);
+        }
+    }
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(permissions)
+        .open(path)
+}
+
+#[cfg(not(unix))]
+pub fn create_with_permissions<P>(path: P, _permissions: u32) -> io::Result<File>
+    where P: AsRef<Path>
+{
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::{self, File};
+    use std::io::Read;
+    use tempdir::TempDir;
+
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn files_are_created_with_their_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmpdir = TempDir::new("cache-file").unwrap();
+        let path = tmpdir.path().join("password.write");
+        File::create(&path).unwrap();
+        create_with_permissions(&path, 0o600).unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn write_private_replaces_a_readable_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmpdir = TempDir::new("private-file").unwrap();
+        let path = tmpdir.path().join("redis.config");
+        File::create(&path).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        write_private(&path, b"requirepass secret", 0o600, None).unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        let mut contents = String::new();
+        File::open(&path)
+            .and_then(|mut file| file.read_to_string(&mut contents))
+            .unwrap();
+        assert_eq!(contents, "requirepass secret");
+        assert!(!tmpdir.path().join("redis.config.write").exists());
+    }
+}
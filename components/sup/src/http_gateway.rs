@@ -14,33 +14,50 @@
 
 use std::collections::HashMap;
 use std::fmt;
-use std::fs::File;
-use std::io::{self, Read};
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
 use std::option;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, ATOMIC_USIZE_INIT, Ordering};
+use std::sync::mpsc::{Receiver, RecvTimeoutError, Sender};
 use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
+use hcore::env as henv;
+use hcore::os::process::Signal;
 use hcore::service::ServiceGroup;
 use iron::prelude::*;
 use iron::{headers, status, typemap};
 use iron::modifiers::Header;
+use iron::response::WriteBody;
 use persistent;
 use prometheus::{self, CounterVec, HistogramVec, TextEncoder, Encoder};
 use router::Router;
 use serde_json::{self, Value as Json};
 
 use error::{Result, Error, SupError};
-use manager;
-use manager::service::HealthCheck;
+use manager::{self, ServiceCommand};
+use manager::events::{self, Event};
+use manager::service::{ConfigHistory, DesiredState, HealthCheck, ServiceSpec};
 use manager::service::hooks::{self, HealthCheckHook};
 
 static LOGKEY: &'static str = "HG";
+/// Environment variable holding the bearer token required by the service control endpoints.
+pub const AUTH_TOKEN_ENVVAR: &'static str = "HAB_SUP_HTTP_AUTH_TOKEN";
 const APIDOCS: &'static str = include_str!(concat!(env!("OUT_DIR"), "/api.html"));
+/// How long an event stream may be idle before a keep-alive comment is sent to the client.
+const EVENT_STREAM_KEEPALIVE_SECS: u64 = 15;
+/// How many clients may stream events at once. Each one holds a gateway worker thread for as long
+/// as it is connected, so the rest are refused to leave threads for the other endpoints.
+const MAX_EVENT_SUBSCRIBERS: usize = 4;
+
+/// How many clients are currently streaming events.
+static EVENT_SUBSCRIBERS: AtomicUsize = ATOMIC_USIZE_INIT;
 
 // Simple macro to encapsulate the HTTP metrics for each endpoint
 macro_rules! with_metrics {
@@ -139,14 +156,37 @@ impl typemap::Key for ManagerFs {
     type Value = manager::FsCfg;
 }
 
+struct ManagerCtl;
+
+impl typemap::Key for ManagerCtl {
+    type Value = Mutex<Sender<ServiceCommand>>;
+}
+
+struct AuthToken;
+
+impl typemap::Key for AuthToken {
+    type Value = Option<String>;
+}
+
+/// Returns the bearer token required by the service control endpoints, if one is set in the
+/// environment.
+pub fn auth_token_from_env() -> Option<String> {
+    henv::var(AUTH_TOKEN_ENVVAR).ok()
+}
+
 pub struct Server(Iron<Chain>, ListenAddr);
 
 impl Server {
-    pub fn new(manager_state: Arc<manager::FsCfg>, listen_addr: ListenAddr) -> Self {
+    pub fn new(manager_state: Arc<manager::FsCfg>,
+               listen_addr: ListenAddr,
+               manager_ctl: Sender<ServiceCommand>,
+               auth_token: Option<String>)
+               -> Self {
         let router = router!(
             doc: get "/" => with_metrics!(doc, "doc"),
             butterfly: get "/butterfly" => with_metrics!(butterfly, "butterfly"),
             census: get "/census" => with_metrics!(census, "census"),
+            events: get "/events" => with_metrics!(events, "events"),
             metrics: get "/metrics" => with_metrics!(metrics, "metrics"),
             services: get "/services" => with_metrics!(services, "services"),
             service: get "/services/:svc/:group" => {
@@ -162,12 +202,53 @@ impl Server {
             service_config_org: get "/services/:svc/:group/:org/config" => {
                 with_metrics!(config, "config")
             },
+            service_config_history: get "/services/:svc/:group/config/history" => {
+                with_metrics!(config_history, "config_history")
+            },
+            service_config_history_org: get "/services/:svc/:group/:org/config/history" => {
+                with_metrics!(config_history, "config_history")
+            },
+            service_config_history_entry:
+                get "/services/:svc/:group/config/history/:incarnation" => {
+                with_metrics!(config_history_entry, "config_history_entry")
+            },
+            service_config_history_entry_org:
+                get "/services/:svc/:group/:org/config/history/:incarnation" => {
+                with_metrics!(config_history_entry, "config_history_entry")
+            },
             service_health_org: get "/services/:svc/:group/:org/health" => {
                 with_metrics!(health, "config")
+            },
+            service_load: post "/services" => with_metrics!(load, "load"),
+            service_unload: delete "/services/:svc/:group" => with_metrics!(unload, "unload"),
+            service_unload_org: delete "/services/:svc/:group/:org" => {
+                with_metrics!(unload, "unload")
+            },
+            service_start: post "/services/:svc/:group/start" => with_metrics!(start, "start"),
+            service_start_org: post "/services/:svc/:group/:org/start" => {
+                with_metrics!(start, "start")
+            },
+            service_stop: post "/services/:svc/:group/stop" => with_metrics!(stop, "stop"),
+            service_stop_org: post "/services/:svc/:group/:org/stop" => {
+                with_metrics!(stop, "stop")
+            },
+            service_restart: post "/services/:svc/:group/restart" => {
+                with_metrics!(restart, "restart")
+            },
+            service_restart_org: post "/services/:svc/:group/:org/restart" => {
+                with_metrics!(restart, "restart")
+            },
+            service_signal: post "/services/:svc/:group/signal" => {
+                with_metrics!(signal, "signal")
+            },
+            service_signal_org: post "/services/:svc/:group/:org/signal" => {
+                with_metrics!(signal, "signal")
             }
         );
         let mut chain = Chain::new(router);
         chain.link(persistent::Read::<ManagerFs>::both(manager_state));
+        chain.link(persistent::Read::<ManagerCtl>::both(Mutex::new(manager_ctl)));
+        chain.link(persistent::Read::<AuthToken>::both(auth_token));
         Server(Iron::new(chain), listen_addr)
     }
 
@@ -183,6 +264,45 @@ impl Server {
     }
 }
 
+/// Response body of the `/events` endpoint. Writes each published event to the client as a
+/// server-sent event until the client goes away.
+struct EventStream(Receiver<Event>);
+
+impl EventStream {
+    /// Subscribes to events, unless `MAX_EVENT_SUBSCRIBERS` clients are already streaming them.
+    fn subscribe() -> Option<EventStream> {
+        if EVENT_SUBSCRIBERS.fetch_add(1, Ordering::SeqCst) >= MAX_EVENT_SUBSCRIBERS {
+            EVENT_SUBSCRIBERS.fetch_sub(1, Ordering::SeqCst);
+            return None;
+        }
+        Some(EventStream(events::subscribe()))
+    }
+}
+
+impl Drop for EventStream {
+    fn drop(&mut self) {
+        EVENT_SUBSCRIBERS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl WriteBody for EventStream {
+    fn write_body(&mut self, res: &mut Write) -> io::Result<()> {
+        loop {
+            match self.0
+                      .recv_timeout(Duration::from_secs(EVENT_STREAM_KEEPALIVE_SECS)) {
+                Ok(event) => {
+                    let data = serde_json::to_string(&event)
+                        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+                    write!(res, "event: {}\ndata: {}\n\n", event.name(), data)?;
+                }
+                Err(RecvTimeoutError::Timeout) => write!(res, ":\n\n")?,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+            res.flush()?;
+        }
+    }
+}
+
 #[derive(Default, Serialize)]
 struct HealthCheckBody {
     stdout: String,
@@ -205,6 +325,17 @@ fn census(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn events(_req: &mut Request) -> IronResult<Response> {
+    let body: Box<WriteBody> = match EventStream::subscribe() {
+        Some(stream) => Box::new(stream),
+        None => return Ok(Response::with(status::ServiceUnavailable)),
+    };
+    Ok(Response::with((status::Ok,
+                       Header(headers::ContentType("text/event-stream".parse().unwrap())),
+                       Header(headers::CacheControl(vec![headers::CacheDirective::NoCache])),
+                       body)))
+}
+
 fn config(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     let service_group = match build_service_group(req) {
@@ -222,6 +353,51 @@ fn config(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn config_history(req: &mut Request) -> IronResult<Response> {
+    if let Err(response) = authorize(req) {
+        return Ok(response);
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let history = ConfigHistory::load(state.config_history(&service_group));
+    Ok(Response::with((status::Ok,
+                       Header(headers::ContentType::json()),
+                       serde_json::to_string(history.entries()).unwrap())))
+}
+
+fn config_history_entry(req: &mut Request) -> IronResult<Response> {
+    if let Err(response) = authorize(req) {
+        return Ok(response);
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let incarnation = match req.extensions
+              .get::<Router>()
+              .unwrap()
+              .find("incarnation")
+              .and_then(|i| u64::from_str(i).ok()) {
+        Some(incarnation) => incarnation,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let history = ConfigHistory::load(state.config_history(&service_group));
+    match history.get(incarnation) {
+        Some(entry) => {
+            Ok(Response::with((status::Ok,
+                               Header(headers::ContentType("application/toml"
+                                                               .parse()
+                                                               .unwrap())),
+                               entry.config.clone())))
+        }
+        None => Ok(Response::with(status::NotFound)),
+    }
+}
+
 fn health(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
     let (health_file, stdout_path, stderr_path) = match build_service_group(req) {
@@ -278,6 +454,94 @@ fn services(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn load(req: &mut Request) -> IronResult<Response> {
+    if let Err(response) = authorize(req) {
+        return Ok(response);
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let mut body = String::new();
+    if req.body.read_to_string(&mut body).is_err() {
+        return Ok(Response::with(status::BadRequest));
+    }
+    let spec = match ServiceSpec::from_str(&body) {
+        Ok(spec) => spec,
+        Err(err) => return Ok(Response::with((status::BadRequest, err.to_string()))),
+    };
+    let spec_file = state.spec_path_for(&spec.ident.name);
+    if spec_file.is_file() && !query_flag(req, "force") {
+        return Ok(Response::with((status::Conflict,
+                                  format!("Service already loaded for {}, use force=true to \
+                                           replace it",
+                                          &spec.ident))));
+    }
+    match spec.to_file(&spec_file) {
+        Ok(_) => Ok(Response::with(status::Accepted)),
+        Err(err) => Ok(Response::with((status::InternalServerError, err.to_string()))),
+    }
+}
+
+fn unload(req: &mut Request) -> IronResult<Response> {
+    if let Err(response) = authorize(req) {
+        return Ok(response);
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let spec_file = match spec_file_for(&state, &service_group) {
+        Some(spec_file) => spec_file,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+    match fs::remove_file(&spec_file) {
+        Ok(_) => Ok(Response::with(status::Accepted)),
+        Err(err) => Ok(Response::with((status::InternalServerError, err.to_string()))),
+    }
+}
+
+fn start(req: &mut Request) -> IronResult<Response> {
+    set_desired_state(req, DesiredState::Up)
+}
+
+fn stop(req: &mut Request) -> IronResult<Response> {
+    set_desired_state(req, DesiredState::Down)
+}
+
+fn restart(req: &mut Request) -> IronResult<Response> {
+    if let Err(response) = authorize(req) {
+        return Ok(response);
+    }
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    send_command(req, &service_group, ServiceCommand::Restart(service_group.clone()))
+}
+
+fn signal(req: &mut Request) -> IronResult<Response> {
+    if let Err(response) = authorize(req) {
+        return Ok(response);
+    }
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let mut body = String::new();
+    if req.body.read_to_string(&mut body).is_err() {
+        return Ok(Response::with(status::BadRequest));
+    }
+    let signal = match Signal::from_str(body.trim()) {
+        Ok(signal) => signal,
+        Err(_) => {
+            return Ok(Response::with((status::BadRequest,
+                                      format!("Unknown signal, {}", body.trim()))))
+        }
+    };
+    send_command(req,
+                 &service_group,
+                 ServiceCommand::Signal(service_group.clone(), signal))
+}
+
 fn doc(_req: &mut Request) -> IronResult<Response> {
     Ok(Response::with((status::Ok, Header(headers::ContentType::html()), APIDOCS)))
 }
@@ -342,3 +606,230 @@ fn service_from_file<T>(service_group: &ServiceGroup,
         Err(err) => Err(err),
     }
 }
+
+/// Verifies the request carries the bearer token the Supervisor was started with. All requests
+/// are rejected if no token was configured.
+fn authorize(req: &mut Request) -> result::Result<(), Response> {
+    let auth_token = req.get::<persistent::Read<AuthToken>>().unwrap();
+    let token = req.headers
+        .get::<headers::Authorization<headers::Bearer>>()
+        .map(|&headers::Authorization(headers::Bearer { ref token })| token.as_str());
+    check_token(auth_token.as_ref().map(String::as_str), token).map_err(Response::with)
+}
+
+/// Checks the bearer token of a request against the one the Supervisor was started with.
+fn check_token(expected: Option<&str>, token: Option<&str>) -> result::Result<(), status::Status> {
+    let expected = match expected {
+        Some(expected) => expected,
+        None => return Err(status::Forbidden),
+    };
+    match token {
+        Some(token) if constant_time_eq(token.as_bytes(), expected.as_bytes()) => Ok(()),
+        _ => Err(status::Unauthorized),
+    }
+}
+
+/// Compares two byte strings in a time which does not depend on where they differ, so that
+/// timing a request does not tell how much of a guessed token is right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn query_flag(req: &Request, name: &str) -> bool {
+    match req.url.query() {
+        Some(query) => {
+            query
+                .split('&')
+                .any(|pair| pair == name || pair == format!("{}=true", name))
+        }
+        None => false,
+    }
+}
+
+/// Returns the path to the spec file of the given service group if one is loaded.
+fn spec_file_for(state: &manager::FsCfg, service_group: &ServiceGroup) -> Option<PathBuf> {
+    let spec_file = state.spec_path_for(service_group.service());
+    match ServiceSpec::from_file(&spec_file) {
+        Ok(ref spec) if spec.group == service_group.group() => Some(spec_file),
+        _ => None,
+    }
+}
+
+fn set_desired_state(req: &mut Request, desired_state: DesiredState) -> IronResult<Response> {
+    if let Err(response) = authorize(req) {
+        return Ok(response);
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let spec_file = match spec_file_for(&state, &service_group) {
+        Some(spec_file) => spec_file,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+    let result = ServiceSpec::from_file(&spec_file).and_then(|mut spec| {
+        spec.desired_state = desired_state;
+        spec.to_file(&spec_file)
+    });
+    match result {
+        Ok(_) => Ok(Response::with(status::Accepted)),
+        Err(err) => Ok(Response::with((status::InternalServerError, err.to_string()))),
+    }
+}
+
+fn send_command(req: &mut Request,
+                service_group: &ServiceGroup,
+                command: ServiceCommand)
+                -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    match service_from_file(service_group, &state.services_data_path) {
+        Ok(Some(_)) => (),
+        Ok(None) => return Ok(Response::with(status::NotFound)),
+        Err(_) => return Ok(Response::with(status::ServiceUnavailable)),
+    }
+    let ctl = req.get::<persistent::Read<ManagerCtl>>().unwrap();
+    let result = ctl.lock()
+        .expect("Manager control channel lock is poisoned!")
+        .send(command);
+    match result {
+        Ok(_) => Ok(Response::with(status::Accepted)),
+        Err(_) => Ok(Response::with(status::ServiceUnavailable)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::net::{IpAddr, Ipv4Addr, TcpListener};
+    use std::sync::Arc;
+    use std::sync::mpsc::channel;
+    use std::thread;
+    use std::time::Duration;
+
+    use hyper::client::{Client, RequestBuilder};
+    use hyper::header::{Authorization, Bearer};
+    use hyper::status::StatusCode;
+    use iron::status;
+    use tempdir::TempDir;
+
+    use super::*;
+    use manager::FsCfg;
+
+    /// Starts a gateway on a free local port with the auth token found in the environment, and
+    /// returns the URL it serves.
+    fn start_gateway(tmpdir: &TempDir) -> String {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let (ctl_tx, _) = channel();
+        Server::new(Arc::new(FsCfg::new(tmpdir.path())),
+                    ListenAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), port),
+                    ctl_tx,
+                    auth_token_from_env())
+                .start()
+                .unwrap();
+        format!("http://127.0.0.1:{}", port)
+    }
+
+    /// Sends a request, retrying while the gateway is still starting up.
+    fn send<'a, F>(request: F) -> StatusCode
+        where F: Fn() -> RequestBuilder<'a>
+    {
+        for _ in 0..50 {
+            match request().send() {
+                Ok(res) => return res.status,
+                Err(_) => thread::sleep(Duration::from_millis(100)),
+            }
+        }
+        panic!("The http-gateway never started");
+    }
+
+    fn bearer(token: &str) -> Authorization<Bearer> {
+        Authorization(Bearer { token: token.to_string() })
+    }
+
+    #[test]
+    fn check_token_without_a_token_configured() {
+        assert_eq!(check_token(None, None), Err(status::Forbidden));
+        assert_eq!(check_token(None, Some("secret")), Err(status::Forbidden));
+    }
+
+    #[test]
+    fn check_token_missing() {
+        assert_eq!(check_token(Some("secret"), None), Err(status::Unauthorized));
+    }
+
+    #[test]
+    fn check_token_wrong() {
+        assert_eq!(check_token(Some("secret"), Some("secreT")),
+                   Err(status::Unauthorized));
+        assert_eq!(check_token(Some("secret"), Some("secret2")),
+                   Err(status::Unauthorized));
+        assert_eq!(check_token(Some("secret"), Some("")), Err(status::Unauthorized));
+    }
+
+    #[test]
+    fn check_token_correct() {
+        assert_eq!(check_token(Some("secret"), Some("secret")), Ok(()));
+    }
+
+    #[test]
+    fn constant_time_eq_compares_bytes() {
+        assert!(constant_time_eq(b"", b""));
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"Secret"));
+        assert!(!constant_time_eq(b"secret", b"secre"));
+    }
+
+    // Both cases run in one test, as tests run in parallel and share the environment.
+    #[test]
+    fn control_requests_are_rejected_without_the_auth_token() {
+        let client = Client::new();
+        let tmpdir = TempDir::new("http-gateway").unwrap();
+
+        env::remove_var(AUTH_TOKEN_ENVVAR);
+        let url = start_gateway(&tmpdir);
+        let start = format!("{}/services/redis/default/start", url);
+        let unload = format!("{}/services/redis/default", url);
+        assert_eq!(send(|| client.post(start.as_str()).body("")), StatusCode::Forbidden);
+        assert_eq!(send(|| client.post(start.as_str()).header(bearer("secret")).body("")),
+                   StatusCode::Forbidden);
+        assert_eq!(send(|| client.delete(unload.as_str()).header(bearer("secret"))),
+                   StatusCode::Forbidden);
+        let history = format!("{}/services/redis/default/config/history", url);
+        assert_eq!(send(|| client.get(history.as_str()).header(bearer("secret"))),
+                   StatusCode::Forbidden);
+
+        env::set_var(AUTH_TOKEN_ENVVAR, "secret");
+        let url = start_gateway(&tmpdir);
+        env::remove_var(AUTH_TOKEN_ENVVAR);
+        let start = format!("{}/services/redis/default/start", url);
+        let unload = format!("{}/services/redis/default", url);
+        assert_eq!(send(|| client.post(start.as_str()).body("")), StatusCode::Unauthorized);
+        assert_eq!(send(|| client.post(start.as_str()).header(bearer("wrong")).body("")),
+                   StatusCode::Unauthorized);
+        assert_eq!(send(|| client.delete(unload.as_str())), StatusCode::Unauthorized);
+        assert_eq!(send(|| client.delete(unload.as_str()).header(bearer("wrong"))),
+                   StatusCode::Unauthorized);
+        let history = format!("{}/services/redis/default/config/history", url);
+        let entry = format!("{}/services/redis/default/config/history/1", url);
+        assert_eq!(send(|| client.get(history.as_str())), StatusCode::Unauthorized);
+        assert_eq!(send(|| client.get(entry.as_str()).header(bearer("wrong"))),
+                   StatusCode::Unauthorized);
+        // Authorized requests get through to the handlers, which find no such service loaded.
+        assert_eq!(send(|| client.post(start.as_str()).header(bearer("secret")).body("")),
+                   StatusCode::NotFound);
+        assert_eq!(send(|| client.delete(unload.as_str()).header(bearer("secret"))),
+                   StatusCode::NotFound);
+        assert_eq!(send(|| client.get(history.as_str()).header(bearer("secret"))),
+                   StatusCode::Ok);
+        assert_eq!(send(|| client.get(entry.as_str()).header(bearer("secret"))),
+                   StatusCode::NotFound);
+    }
+}
@@ -40,6 +40,7 @@
 //! * [The Habitat Supervisor Sidecar; http interface to promises](sidecar)
 
 extern crate ansi_term;
+extern crate base64;
 #[macro_use]
 extern crate bitflags;
 extern crate byteorder;
@@ -55,6 +56,8 @@ extern crate habitat_depot_client as depot_client;
 extern crate habitat_eventsrv as eventsrv;
 extern crate habitat_eventsrv_client as eventsrv_client;
 extern crate handlebars;
+#[cfg(test)]
+extern crate hyper;
 extern crate iron;
 #[macro_use]
 extern crate lazy_static;
@@ -62,6 +65,7 @@ extern crate libc;
 #[macro_use]
 extern crate log;
 extern crate notify;
+extern crate num_cpus;
 extern crate persistent;
 #[macro_use]
 extern crate prometheus;
@@ -36,6 +36,7 @@ use common::ui::UI;
 use hcore::env as henv;
 use hcore::crypto::{default_cache_key_path, SymKey};
 use hcore::crypto::init as crypto_init;
+use hcore::os::process::Signal;
 use hcore::package::{PackageArchive, PackageIdent};
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 use url::Url;
@@ -47,8 +48,10 @@ use sup::feat;
 use sup::command;
 use sup::http_gateway;
 use sup::manager::{Manager, ManagerConfig};
-use sup::manager::service::{DesiredState, ServiceBind, Topology, UpdateStrategy};
-use sup::manager::service::{ServiceSpec, StartStyle};
+use sup::manager::service::{CanarySize, DesiredState, ServiceBind, TaskSchedule, Topology,
+                            UpdateStrategy};
+use sup::manager::service::{ServiceSpec, StartStyle, UpdateTimezone, UpdateWindow,
+                            VersionConstraint};
 use sup::util;
 
 /// Our output key
@@ -56,6 +59,7 @@ static LOGKEY: &'static str = "MN";
 
 static RING_ENVVAR: &'static str = "HAB_RING";
 static RING_KEY_ENVVAR: &'static str = "HAB_RING_KEY";
+static RING_CA_ENVVAR: &'static str = "HAB_RING_CA";
 
 fn main() {
     env_logger::init().unwrap();
@@ -73,6 +77,7 @@ fn start() -> Result<()> {
         ("bash", Some(m)) => sub_bash(m),
         ("config", Some(m)) => sub_config(m),
         ("load", Some(m)) => sub_load(m),
+        ("render", Some(m)) => sub_render(m),
         ("run", Some(m)) => sub_run(m),
         ("sh", Some(m)) => sub_sh(m),
         ("start", Some(m)) => sub_start(m),
@@ -93,6 +98,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         (@setting SubcommandRequiredElseHelp)
         (@arg VERBOSE: -v +global "Verbose output; shows line numbers")
         (@arg NO_COLOR: --("no-color") +global "Turn ANSI color off")
+        (@arg JSON_LOGGING: --("json-logging") +global
+            "Print every line of Supervisor and service output as a JSON object")
         (@subcommand bash =>
             (about: "Start an interactive Bash-like shell")
             (aliases: &["b", "ba", "bas"])
@@ -120,11 +127,50 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Receive package updates from the Depot at the specified URL \
                 [default: https://bldr.habitat.sh/v1/depot]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
-                "Service topology; [default: none]")
+                "Service topology; [default: none] [values: standalone, leader, task]")
+            (@arg TASK_SCHEDULE: --("task-schedule") +takes_value {valid_task_schedule}
+                "Cron expression, evaluated in UTC, on which a service with the task topology is \
+                run [default: run once]")
+            (@arg TASK_LEADER_ONLY: --("task-leader-only")
+                "Only run a service with the task topology on the elected leader of its service \
+                group")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
+            (@arg CANARY_SIZE: --("canary-size") +takes_value {valid_canary_size}
+                "Number of members, or percentage of members (ex: 25%), updated first by the \
+                canary update strategy [default: 1]")
+            (@arg CANARY_SOAK_PERIOD: --("canary-soak-period") +takes_value {valid_soak_period}
+                "Seconds the canaries must stay healthy before the rest of the service group is \
+                updated [default: 300]")
+            (@arg ROLLBACK_WINDOW: --("rollback-window") +takes_value {valid_rollback_window}
+                "Seconds an updated package may keep failing before rolling back to the last \
+                known good release, which is then blacklisted [default: no rollbacks]")
+            (@arg UPDATE_WINDOW: --("update-window") +takes_value +multiple
+                "One or more periods during which updates may be applied, either cron expressions \
+                or day and time ranges such as 'Mon-Fri 22:00-02:00' [default: any time]")
+            (@arg UPDATE_TIMEZONE: --("update-timezone") +takes_value
+                "Timezone of the update windows: UTC, local or an offset such as +02:00 \
+                [default: UTC]")
+            (@arg UPDATE_CONSTRAINT: --("update-constraint") +takes_value
+                "Only update to releases whose version meets these requirements, such as 1.4.x, \
+                ~1.4.2 or '>=1.2, <2.0' [default: any version]")
+            (@arg STRICT_TEMPLATES: --("strict-templates")
+                "Refuse to render templates which refer to undefined variables, keeping the \
+                previously rendered files")
+            (@arg STOP_SIGNAL: --("stop-signal") +takes_value {valid_stop_signal}
+                "Signal sent to the service's process to stop it, after its pre-stop hook has run \
+                [default: TERM]")
+            (@arg STOP_TIMEOUT: --("stop-timeout") +takes_value {valid_stop_timeout}
+                "Seconds to wait for the service's process to exit after the stop signal before \
+                killing it [default: 8]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg REQUIRES: --requires +takes_value +multiple
+                "One or more services loaded on this Supervisor which must be up and healthy \
+                before this service is started")
+            (@arg AFTER: --after +takes_value +multiple
+                "One or more services loaded on this Supervisor which are started before this \
+                service")
             (@arg FORCE: --force -f "Load or reload an already loaded service. If the service was \
                 previously loaded and running this operation will also restart the service")
         )
@@ -138,6 +184,20 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The name for the state directory if there is more than one Supervisor running \
                 [default: default]")
         )
+        (@subcommand render =>
+            (about: "Render the configuration and hook templates of an installed package without \
+                running it, reporting undefined variables and rendering errors")
+            (@arg PKG_IDENT: +required +takes_value
+                "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+            (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
+                "Use package config from this path, rather than the package itself")
+            (@arg USER_TOML: --("user-toml") +takes_value {file_exists}
+                "A user.toml to overlay on the package's default configuration")
+            (@arg CENSUS: --census +takes_value {file_exists}
+                "A TOML or JSON file describing the members and binds of the service group")
+            (@arg OUTPUT: --output -o +takes_value
+                "Write the rendered templates to this directory")
+        )
         (@subcommand run =>
             (about: "Run the Habitat Supervisor")
             (aliases: &["r", "ru"])
@@ -154,6 +214,14 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
             (@arg RING: --ring -r +takes_value "Ring key name")
+            (@arg RING_CA: --("ring-ca") +takes_value
+                "Ring CA key name; gossip is then authenticated with the member key issued to \
+                this Supervisor by the ring CA [default: none]")
+            (@arg RUMOR_TTL: --("rumor-ttl") +takes_value {valid_rumor_ttl}
+                "Seconds the rumors of a confirmed member, or of a service group without live \
+                members, are kept before being expired [default: 86400]")
+            (@arg SUP_STRICT_TEMPLATES: --("strict-templates")
+                "Refuse to render the templates of any service which refer to undefined variables")
         )
         (@subcommand sh =>
             (about: "Start an interactive Bourne-like shell")
@@ -178,6 +246,12 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The listen address of an initial peer (IP[:PORT])")
             (@arg PERMANENT_PEER: --("permanent-peer") -I "If this Supervisor is a permanent peer")
             (@arg RING: --ring -r +takes_value "Ring key name")
+            (@arg RING_CA: --("ring-ca") +takes_value
+                "Ring CA key name; gossip is then authenticated with the member key issued to \
+                this Supervisor by the ring CA [default: none]")
+            (@arg RUMOR_TTL: --("rumor-ttl") +takes_value {valid_rumor_ttl}
+                "Seconds the rumors of a confirmed member, or of a service group without live \
+                members, are kept before being expired [default: 86400]")
             (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
                 "A Habitat package identifier (ex: core/redis) or filepath to a Habitat Artifact \
                 (ex: /home/core-redis-3.0.7-21120102031201-x86_64-linux.hart)")
@@ -189,11 +263,50 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "Receive package updates from the Depot at the specified URL \
                 [default: https://bldr.habitat.sh/v1/depot]")
             (@arg TOPOLOGY: --topology -t +takes_value {valid_topology}
-                "Service topology; [default: none]")
+                "Service topology; [default: none] [values: standalone, leader, task]")
+            (@arg TASK_SCHEDULE: --("task-schedule") +takes_value {valid_task_schedule}
+                "Cron expression, evaluated in UTC, on which a service with the task topology is \
+                run [default: run once]")
+            (@arg TASK_LEADER_ONLY: --("task-leader-only")
+                "Only run a service with the task topology on the elected leader of its service \
+                group")
             (@arg STRATEGY: --strategy -s +takes_value {valid_update_strategy}
-                "The update strategy; [default: none] [values: none, at-once, rolling]")
+                "The update strategy; [default: none] [values: none, at-once, rolling, canary]")
+            (@arg CANARY_SIZE: --("canary-size") +takes_value {valid_canary_size}
+                "Number of members, or percentage of members (ex: 25%), updated first by the \
+                canary update strategy [default: 1]")
+            (@arg CANARY_SOAK_PERIOD: --("canary-soak-period") +takes_value {valid_soak_period}
+                "Seconds the canaries must stay healthy before the rest of the service group is \
+                updated [default: 300]")
+            (@arg ROLLBACK_WINDOW: --("rollback-window") +takes_value {valid_rollback_window}
+                "Seconds an updated package may keep failing before rolling back to the last \
+                known good release, which is then blacklisted [default: no rollbacks]")
+            (@arg UPDATE_WINDOW: --("update-window") +takes_value +multiple
+                "One or more periods during which updates may be applied, either cron expressions \
+                or day and time ranges such as 'Mon-Fri 22:00-02:00' [default: any time]")
+            (@arg UPDATE_TIMEZONE: --("update-timezone") +takes_value
+                "Timezone of the update windows: UTC, local or an offset such as +02:00 \
+                [default: UTC]")
+            (@arg UPDATE_CONSTRAINT: --("update-constraint") +takes_value
+                "Only update to releases whose version meets these requirements, such as 1.4.x, \
+                ~1.4.2 or '>=1.2, <2.0' [default: any version]")
+            (@arg STRICT_TEMPLATES: --("strict-templates")
+                "Refuse to render templates which refer to undefined variables, keeping the \
+                previously rendered files")
+            (@arg STOP_SIGNAL: --("stop-signal") +takes_value {valid_stop_signal}
+                "Signal sent to the service's process to stop it, after its pre-stop hook has run \
+                [default: TERM]")
+            (@arg STOP_TIMEOUT: --("stop-timeout") +takes_value {valid_stop_timeout}
+                "Seconds to wait for the service's process to exit after the stop signal before \
+                killing it [default: 8]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg REQUIRES: --requires +takes_value +multiple
+                "One or more services loaded on this Supervisor which must be up and healthy \
+                before this service is started")
+            (@arg AFTER: --after +takes_value +multiple
+                "One or more services loaded on this Supervisor which are started before this \
+                service")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
                 "Use package config from this path, rather than the package itself")
         )
@@ -228,6 +341,9 @@ fn sub_bash(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         sup::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        sup::output::set_json(true);
+    }
 
     command::shell::bash()
 }
@@ -246,6 +362,9 @@ fn sub_load(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         sup::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        sup::output::set_json(true);
+    }
     let cfg = mgrcfg_from_matches(m)?;
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
     let default_spec = ServiceSpec::default_for(ident);
@@ -271,6 +390,9 @@ fn sub_unload(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         sup::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        sup::output::set_json(true);
+    }
     let cfg = mgrcfg_from_matches(m)?;
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
     let spec = spec_from_matches(ident, m)?;
@@ -281,7 +403,34 @@ fn sub_unload(m: &ArgMatches) -> Result<()> {
                                              })
 }
 
+fn sub_render(m: &ArgMatches) -> Result<()> {
+    if m.is_present("VERBOSE") {
+        sup::output::set_verbose(true);
+    }
+    if m.is_present("NO_COLOR") {
+        sup::output::set_no_color(true);
+    }
+    if m.is_present("JSON_LOGGING") {
+        sup::output::set_json(true);
+    }
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
+    command::render::run(&ident,
+                         m.value_of("CONFIG_DIR").map(Path::new),
+                         m.value_of("USER_TOML").map(Path::new),
+                         m.value_of("CENSUS").map(Path::new),
+                         m.value_of("OUTPUT").map(Path::new))
+}
+
 fn sub_run(m: &ArgMatches) -> Result<()> {
+    if m.is_present("VERBOSE") {
+        sup::output::set_verbose(true);
+    }
+    if m.is_present("NO_COLOR") {
+        sup::output::set_no_color(true);
+    }
+    if m.is_present("JSON_LOGGING") {
+        sup::output::set_json(true);
+    }
     let cfg = mgrcfg_from_matches(m)?;
     let mut manager = Manager::load(cfg)?;
     manager.run()
@@ -294,6 +443,9 @@ fn sub_sh(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         sup::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        sup::output::set_json(true);
+    }
 
     command::shell::sh()
 }
@@ -305,6 +457,9 @@ fn sub_start(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         sup::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        sup::output::set_json(true);
+    }
     let cfg = mgrcfg_from_matches(m)?;
     let mut maybe_local_artifact: Option<&str> = None;
     let maybe_spec = match m.value_of("PKG_IDENT_OR_ARTIFACT") {
@@ -362,6 +517,9 @@ fn sub_status(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         sup::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        sup::output::set_json(true);
+    }
     let cfg = mgrcfg_from_matches(m)?;
     if !Manager::is_running(&cfg)? {
         println!("The supervisor is not running.");
@@ -398,6 +556,9 @@ fn sub_stop(m: &ArgMatches) -> Result<()> {
     if m.is_present("NO_COLOR") {
         sup::output::set_no_color(true);
     }
+    if m.is_present("JSON_LOGGING") {
+        sup::output::set_json(true);
+    }
     let cfg = mgrcfg_from_matches(m)?;
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
     let spec_file = Manager::spec_path_for(&cfg, &ServiceSpec::default_for(ident));
@@ -442,6 +603,8 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     }
     cfg.organization = m.value_of("ORGANIZATION").map(|org| org.to_string());
     cfg.gossip_permanent = m.is_present("PERMANENT_PEER");
+    cfg.strict_templates = m.is_present("SUP_STRICT_TEMPLATES");
+    cfg.rumor_ttl = m.value_of("RUMOR_TTL").map(|ttl| ttl.parse().unwrap());
     // TODO fn: Clean this up--using a for loop doesn't feel good however an iterator was
     // causing a lot of developer/compiler type confusion
     let mut gossip_peers: Vec<SocketAddr> = Vec::new();
@@ -488,6 +651,11 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     if let Some(ring) = ring {
         cfg.ring = Some(ring.name_with_rev());
     }
+    cfg.ring_ca = match m.value_of("RING_CA") {
+        Some(val) => Some(val.to_string()),
+        None => henv::var(RING_CA_ENVVAR).ok(),
+    };
+    cfg.http_auth_token = http_gateway::auth_token_from_env();
     Ok(cfg)
 }
 
@@ -504,9 +672,42 @@ fn spec_from_matches(ident: PackageIdent, m: &ArgMatches) -> Result<ServiceSpec>
     if let Some(topology) = m.value_of("TOPOLOGY") {
         spec.topology = Topology::from_str(topology)?;
     }
+    if let Some(schedule) = m.value_of("TASK_SCHEDULE") {
+        spec.task_schedule = Some(TaskSchedule::from_str(schedule)?);
+    }
+    spec.task_leader_only = m.is_present("TASK_LEADER_ONLY");
     if let Some(ref strategy) = m.value_of("STRATEGY") {
         spec.update_strategy = UpdateStrategy::from_str(strategy)?;
     }
+    if let Some(size) = m.value_of("CANARY_SIZE") {
+        spec.canary_size = CanarySize::from_str(size)?;
+    }
+    if let Some(soak_period) = m.value_of("CANARY_SOAK_PERIOD") {
+        spec.canary_soak_period = soak_period.parse().unwrap();
+    }
+    if let Some(window) = m.value_of("ROLLBACK_WINDOW") {
+        spec.rollback_window = Some(window.parse().unwrap());
+    }
+    if let Some(windows) = m.values_of("UPDATE_WINDOW") {
+        let mut update_windows = Vec::new();
+        for window in windows {
+            update_windows.push(UpdateWindow::from_str(window)?);
+        }
+        spec.update_windows = update_windows;
+    }
+    if let Some(timezone) = m.value_of("UPDATE_TIMEZONE") {
+        spec.update_timezone = UpdateTimezone::from_str(timezone)?;
+    }
+    if let Some(constraint) = m.value_of("UPDATE_CONSTRAINT") {
+        spec.update_constraint = Some(VersionConstraint::from_str(constraint)?);
+    }
+    spec.strict_templates = m.is_present("STRICT_TEMPLATES");
+    if let Some(signal) = m.value_of("STOP_SIGNAL") {
+        spec.stop_signal = Signal::from_str(signal)?;
+    }
+    if let Some(timeout) = m.value_of("STOP_TIMEOUT") {
+        spec.stop_timeout = timeout.parse().unwrap();
+    }
     if let Some(bind_strs) = m.values_of("BIND") {
         let mut binds = Vec::new();
         for bind_str in bind_strs {
@@ -514,6 +715,12 @@ fn spec_from_matches(ident: PackageIdent, m: &ArgMatches) -> Result<ServiceSpec>
         }
         spec.binds = binds;
     }
+    if let Some(names) = m.values_of("REQUIRES") {
+        spec.requires = names.map(|name| name.to_string()).collect();
+    }
+    if let Some(names) = m.values_of("AFTER") {
+        spec.after = names.map(|name| name.to_string()).collect();
+    }
     if let Some(ref config_from) = m.value_of("CONFIG_DIR") {
         spec.config_from = Some(PathBuf::from(config_from));
         outputln!("");
@@ -536,6 +743,14 @@ fn dir_exists(val: String) -> result::Result<(), String> {
     }
 }
 
+fn file_exists(val: String) -> result::Result<(), String> {
+    if Path::new(&val).is_file() {
+        Ok(())
+    } else {
+        Err(format!("File: '{}' cannot be found", &val))
+    }
+}
+
 fn valid_topology(val: String) -> result::Result<(), String> {
     match Topology::from_str(&val) {
         Ok(_) => Ok(()),
@@ -543,6 +758,13 @@ fn valid_topology(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_task_schedule(val: String) -> result::Result<(), String> {
+    match TaskSchedule::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Task schedule: '{}' is not a cron expression", &val)),
+    }
+}
+
 fn valid_update_strategy(val: String) -> result::Result<(), String> {
     match UpdateStrategy::from_str(&val) {
         Ok(_) => Ok(()),
@@ -550,6 +772,48 @@ fn valid_update_strategy(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_canary_size(val: String) -> result::Result<(), String> {
+    match CanarySize::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Canary size: '{}' is not valid", &val)),
+    }
+}
+
+fn valid_soak_period(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Soak period: '{}' is not a number of seconds", &val)),
+    }
+}
+
+fn valid_rollback_window(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Rollback window: '{}' is not a number of seconds", &val)),
+    }
+}
+
+fn valid_stop_signal(val: String) -> result::Result<(), String> {
+    match Signal::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("{}", e)),
+    }
+}
+
+fn valid_stop_timeout(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("Stop timeout: '{}' is not a number of seconds", &val)),
+    }
+}
+
+fn valid_rumor_ttl(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(ttl) if ttl > 0 => Ok(()),
+        _ => Err(format!("Rumor TTL: '{}' is not a positive number of seconds", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
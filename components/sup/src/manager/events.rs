@@ -0,0 +1,190 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lifecycle events of the services running in a Supervisor.
+//!
+//! Events are published from wherever the state change happens and fanned out to every
+//! subscriber, such as the `/events` endpoint of the HTTP gateway. Subscribers which have gone
+//! away are dropped the next time an event is published.
+
+use std::sync::Mutex;
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use hcore::service::ServiceGroup;
+use time;
+
+use super::service::HealthCheck;
+
+lazy_static! {
+    static ref SUBSCRIBERS: Mutex<Vec<Sender<Event>>> = Mutex::new(Vec::new());
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum Event {
+    ProcessStateChanged {
+        service_group: ServiceGroup,
+        state: String,
+        timestamp: i64,
+    },
+    HookStarted {
+        service_group: ServiceGroup,
+        hook: String,
+        timestamp: i64,
+    },
+    HookExited {
+        service_group: ServiceGroup,
+        hook: String,
+        exit_code: Option<i32>,
+        timestamp: i64,
+    },
+    HealthCheckChanged {
+        service_group: ServiceGroup,
+        health_check: HealthCheck,
+        timestamp: i64,
+    },
+    UpdateApplied {
+        service_group: ServiceGroup,
+        ident: String,
+        timestamp: i64,
+    },
+    LeaderChanged {
+        service_group: ServiceGroup,
+        leader_id: Option<String>,
+        timestamp: i64,
+    },
+}
+
+impl Event {
+    pub fn process_state_changed<T: ToString>(service_group: &ServiceGroup, state: T) -> Self {
+        Event::ProcessStateChanged {
+            service_group: service_group.clone(),
+            state: state.to_string(),
+            timestamp: now(),
+        }
+    }
+
+    pub fn hook_started(service_group: &ServiceGroup, hook: &str) -> Self {
+        Event::HookStarted {
+            service_group: service_group.clone(),
+            hook: hook.to_string(),
+            timestamp: now(),
+        }
+    }
+
+    pub fn hook_exited(service_group: &ServiceGroup, hook: &str, exit_code: Option<i32>) -> Self {
+        Event::HookExited {
+            service_group: service_group.clone(),
+            hook: hook.to_string(),
+            exit_code: exit_code,
+            timestamp: now(),
+        }
+    }
+
+    pub fn health_check_changed(service_group: &ServiceGroup, health_check: HealthCheck) -> Self {
+        Event::HealthCheckChanged {
+            service_group: service_group.clone(),
+            health_check: health_check,
+            timestamp: now(),
+        }
+    }
+
+    pub fn update_applied<T: ToString>(service_group: &ServiceGroup, ident: T) -> Self {
+        Event::UpdateApplied {
+            service_group: service_group.clone(),
+            ident: ident.to_string(),
+            timestamp: now(),
+        }
+    }
+
+    pub fn leader_changed(service_group: &ServiceGroup, leader_id: Option<String>) -> Self {
+        Event::LeaderChanged {
+            service_group: service_group.clone(),
+            leader_id: leader_id,
+            timestamp: now(),
+        }
+    }
+
+    /// Name of the event, used as the `event` field of a server-sent event.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Event::ProcessStateChanged { .. } => "process-state-changed",
+            Event::HookStarted { .. } => "hook-started",
+            Event::HookExited { .. } => "hook-exited",
+            Event::HealthCheckChanged { .. } => "health-check-changed",
+            Event::UpdateApplied { .. } => "update-applied",
+            Event::LeaderChanged { .. } => "leader-changed",
+        }
+    }
+}
+
+/// Send an event to all current subscribers.
+pub fn publish(event: Event) {
+    let mut subscribers = SUBSCRIBERS.lock().expect("Event subscribers lock is poisoned!");
+    subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+}
+
+/// Returns a receiver for all events published from now on. Dropping the receiver unsubscribes.
+pub fn subscribe() -> Receiver<Event> {
+    let (tx, rx) = channel();
+    SUBSCRIBERS
+        .lock()
+        .expect("Event subscribers lock is poisoned!")
+        .push(tx);
+    rx
+}
+
+fn now() -> i64 {
+    time::get_time().sec
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::service::ServiceGroup;
+
+    use super::*;
+
+    #[test]
+    fn publish_to_subscribers() {
+        let sg = ServiceGroup::from_str("redis.events-test").unwrap();
+        let rx1 = subscribe();
+        let rx2 = subscribe();
+        publish(Event::hook_exited(&sg, "init", Some(0)));
+        for rx in vec![rx1, rx2] {
+            let event = rx.try_iter()
+                .find(|e| match *e {
+                          Event::HookExited { ref service_group, .. } => *service_group == sg,
+                          _ => false,
+                      })
+                .unwrap();
+            assert_eq!(event.name(), "hook-exited");
+        }
+    }
+
+    #[test]
+    fn publish_drops_closed_subscribers() {
+        let sg = ServiceGroup::from_str("redis.events-test-closed").unwrap();
+        drop(subscribe());
+        let rx = subscribe();
+        publish(Event::leader_changed(&sg, None));
+        assert!(rx.try_iter().any(|e| match e {
+                                      Event::LeaderChanged { ref service_group, .. } => {
+                                          *service_group == sg
+                                      }
+                                      _ => false,
+                                  }));
+    }
+}
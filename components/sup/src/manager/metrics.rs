@@ -0,0 +1,95 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Gossip metrics exported by the HTTP gateway's `/metrics` endpoint, describing the size of the
+//! rumor stores and the membership of the ring as seen by this Supervisor.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+
+use butterfly;
+use butterfly::member::Health;
+use prometheus::{Counter, Gauge, GaugeVec};
+
+/// Number of expired rumors already added to the expired rumors counter.
+static EXPIRED_RUMORS_COUNTED: AtomicUsize = ATOMIC_USIZE_INIT;
+
+lazy_static! {
+    static ref BUTTERFLY_RUMORS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_butterfly_rumors",
+            "Number of rumors held in a rumor store."),
+        &["store"]).unwrap();
+
+    static ref BUTTERFLY_MEMBERS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_butterfly_members",
+            "Number of members of the ring with a given health."),
+        &["health"]).unwrap();
+
+    static ref BUTTERFLY_EXPIRED_RUMORS: Counter = register_counter!(
+        opts!(
+            "hab_butterfly_expired_rumors",
+            "Number of stale rumors dropped since the Supervisor started.")).unwrap();
+
+    static ref BUTTERFLY_LOCAL_HEALTH: Gauge = register_gauge!(
+        opts!(
+            "hab_butterfly_local_health",
+            "Local health score of the Supervisor; 0 while it keeps up with the ring.")).unwrap();
+}
+
+pub fn butterfly(server: &butterfly::Server) {
+    rumors("service", server.service_store.len());
+    rumors("service_config", server.service_config_store.len());
+    rumors("service_file", server.service_file_store.len());
+    rumors("service_secret", server.service_secret_store.len());
+    rumors("election", server.election_store.len());
+    rumors("election_update", server.update_store.len());
+    rumors("release_blacklist", server.release_blacklist_store.len());
+    rumors("departure", server.departure_store.len());
+    rumors("ring_key", server.ring_key_store.len());
+
+    let mut members: HashMap<String, usize> = HashMap::new();
+    for health in &[Health::Alive, Health::Suspect, Health::Confirmed, Health::Departed] {
+        members.insert(health.to_string(), 0);
+    }
+    for health in server
+            .member_list
+            .health
+            .read()
+            .expect("Health lock is poisoned")
+            .values() {
+        *members.entry(health.to_string()).or_insert(0) += 1;
+    }
+    for (health, count) in members.iter() {
+        let mut labels = HashMap::new();
+        labels.insert("health", health.as_str());
+        BUTTERFLY_MEMBERS.with(&labels).set(*count as f64);
+    }
+
+    let expired_rumors = server.expired_rumors();
+    let counted = EXPIRED_RUMORS_COUNTED.swap(expired_rumors, Ordering::Relaxed);
+    if expired_rumors > counted {
+        BUTTERFLY_EXPIRED_RUMORS
+            .inc_by((expired_rumors - counted) as f64)
+            .unwrap();
+    }
+    BUTTERFLY_LOCAL_HEALTH.set(server.local_health() as f64);
+}
+
+fn rumors(store: &str, count: usize) {
+    let mut labels = HashMap::new();
+    labels.insert("store", store);
+    BUTTERFLY_RUMORS.with(&labels).set(count as f64);
+}
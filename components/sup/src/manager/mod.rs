@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod events;
 pub mod service;
+mod metrics;
 mod signals;
 mod service_updater;
 mod spec_watcher;
@@ -27,18 +29,20 @@ use std::path::{Path, PathBuf};
 use std::result;
 use std::thread;
 use std::sync::{Arc, RwLock};
-use std::sync::mpsc::channel;
+use std::sync::mpsc::{channel, Receiver, TryRecvError};
 use std::time::Duration;
 
 use byteorder::{ByteOrder, LittleEndian};
 use butterfly;
+use butterfly::auth::Auth;
 use butterfly::member::Member;
+use butterfly::ring_keys::RingKeys;
 use butterfly::trace::Trace;
 use butterfly::server::timing::Timing;
 use butterfly::server::Suitability;
 use eventsrv::message::event::{EventEnvelope, EventEnvelope_Type, CensusEntry as CensusEntryProto};
 use eventsrv_client::EventSrvClient;
-use hcore::crypto::{default_cache_key_path, SymKey};
+use hcore::crypto::{default_cache_key_path, MemberKey, SigKeyPair, SymKey};
 use hcore::fs::FS_ROOT_PATH;
 use hcore::service::ServiceGroup;
 use hcore::os::process::{self, OsSignal, Signal};
@@ -50,7 +54,9 @@ use time::{self, Timespec, Duration as TimeDuration};
 
 pub use self::service::{Service, ServiceSpec, UpdateStrategy, Topology};
 pub use self::sys::Sys;
-use self::service::{DesiredState, Pkg, ProcessState, StartStyle};
+use self::service::{stop_order, DesiredState, LocalServices, Pkg, ProcessState, StartStyle,
+                    TaskStatus, SPEC_FILE_EXT};
+use self::events::Event;
 use self::service_updater::ServiceUpdater;
 use self::spec_watcher::{SpecWatcher, SpecWatcherEvent};
 use error::{Error, Result, SupError};
@@ -60,6 +66,7 @@ use manager::signals::SignalEvent;
 use http_gateway;
 
 const MEMBER_ID_FILE: &'static str = "MEMBER_ID";
+const MEMBER_KEY_FILE: &'static str = "MEMBER_KEY";
 const PROC_LOCK_FILE: &'static str = "LOCK";
 
 static LOGKEY: &'static str = "MR";
@@ -84,11 +91,12 @@ pub struct FsCfg {
     data_path: PathBuf,
     specs_path: PathBuf,
     member_id_file: PathBuf,
+    member_key_file: PathBuf,
     proc_lock_file: PathBuf,
 }
 
 impl FsCfg {
-    fn new<T>(sup_svc_root: T) -> Self
+    pub fn new<T>(sup_svc_root: T) -> Self
         where T: Into<PathBuf>
     {
         let sup_svc_root = sup_svc_root.into();
@@ -100,6 +108,7 @@ impl FsCfg {
             specs_path: sup_svc_root.join("specs"),
             data_path: data_path,
             member_id_file: sup_svc_root.join(MEMBER_ID_FILE),
+            member_key_file: sup_svc_root.join(MEMBER_KEY_FILE),
             proc_lock_file: sup_svc_root.join(PROC_LOCK_FILE),
         }
     }
@@ -108,6 +117,32 @@ impl FsCfg {
         self.data_path
             .join(format!("{}.health", service_group.service()))
     }
+
+    /// Returns the path to the file holding the history of gossiped configurations applied to
+    /// the given service.
+    pub fn config_history(&self, service_group: &ServiceGroup) -> PathBuf {
+        self.data_path
+            .join(format!("{}.config-history", service_group.service()))
+    }
+
+    /// Returns the path to the file holding the last known good release of the given service.
+    pub fn known_good_release(&self, service_group: &ServiceGroup) -> PathBuf {
+        self.data_path
+            .join(format!("{}.known-good", service_group.service()))
+    }
+
+    /// Returns the path to the file listing the releases of the given service which were rolled
+    /// back from.
+    pub fn release_blacklist(&self, service_group: &ServiceGroup) -> PathBuf {
+        self.data_path
+            .join(format!("{}.blacklist", service_group.service()))
+    }
+
+    /// Returns the path to the service spec file for the given service name.
+    pub fn spec_path_for(&self, service_name: &str) -> PathBuf {
+        self.specs_path
+            .join(format!("{}.{}", service_name, SPEC_FILE_EXT))
+    }
 }
 
 #[derive(Clone, Default)]
@@ -117,9 +152,27 @@ pub struct ManagerConfig {
     pub gossip_peers: Vec<SocketAddr>,
     pub gossip_permanent: bool,
     pub ring: Option<String>,
+    /// The name of the ring CA key which issued this Supervisor's member key. When set, every
+    /// message gossiped in the ring must be signed by a member key issued by that CA.
+    pub ring_ca: Option<String>,
     pub name: Option<String>,
     custom_state_path: Option<PathBuf>,
     pub organization: Option<String>,
+    pub http_auth_token: Option<String>,
+    /// Whether the templates of every service referring to variables the render context doesn't
+    /// define fail to render.
+    pub strict_templates: bool,
+    /// Seconds the rumors of a confirmed member, or of a service group without live members, are
+    /// kept before being expired, if not the gossip layer's default.
+    pub rumor_ttl: Option<u64>,
+}
+
+/// Commands sent to a running Manager by the HTTP gateway which can't be expressed as a change to
+/// a service spec on disk.
+#[derive(Debug)]
+pub enum ServiceCommand {
+    Restart(ServiceGroup),
+    Signal(ServiceGroup, Signal),
 }
 
 pub struct Manager {
@@ -132,6 +185,10 @@ pub struct Manager {
     organization: Option<String>,
     service_states: HashMap<PackageIdent, Timespec>,
     sys: Arc<Sys>,
+    http_auth_token: Option<String>,
+    strict_templates: bool,
+    rumor_ttl: Option<u64>,
+    ring_key_counter: usize,
 }
 
 impl Manager {
@@ -200,14 +257,18 @@ impl Manager {
 
     fn new(cfg: ManagerConfig, fs_cfg: FsCfg) -> Result<Manager> {
         let mut sys = Sys::new(cfg.gossip_permanent, cfg.gossip_listen, cfg.http_listen);
-        let member = Self::load_member(&mut sys, &fs_cfg)?;
+        let auth = match cfg.ring_ca {
+            Some(ref ring_ca) => Some(Self::load_auth(ring_ca, &fs_cfg)?),
+            None => None,
+        };
+        let member = Self::load_member(&mut sys, &fs_cfg, auth.as_ref())?;
 
-        let ring_key = match cfg.ring {
+        let ring_keys = match cfg.ring {
             Some(ref ring_with_revision) => {
                 outputln!("Joining ring {}", ring_with_revision);
-                Some(SymKey::get_pair_for(&ring_with_revision, &default_cache_key_path(None))?)
+                Self::load_ring_keys(ring_with_revision, &default_cache_key_path(None))?
             }
-            None => None,
+            None => RingKeys::default(),
         };
 
         let services = Arc::new(RwLock::new(Vec::new()));
@@ -215,7 +276,8 @@ impl Manager {
                                             sys.gossip_listen(),
                                             member,
                                             Trace::default(),
-                                            ring_key,
+                                            ring_keys,
+                                            auth,
                                             None,
                                             Some(&fs_cfg.data_path),
                                             Box::new(SuitabilityLookup(services.clone())))?;
@@ -227,35 +289,92 @@ impl Manager {
             peer.set_gossip_port(peer_addr.port() as i32);
             server.member_list.add_initial_member(peer);
         }
+        let fs_cfg = Arc::new(fs_cfg);
         Ok(Manager {
-               updater: ServiceUpdater::new(server.clone()),
+               updater: ServiceUpdater::new(server.clone(), fs_cfg.clone()),
                census_ring: CensusRing::new(sys.member_id.clone()),
                butterfly: server,
                services: services,
                watcher: SpecWatcher::run(&fs_cfg.specs_path)?,
-               fs_cfg: Arc::new(fs_cfg),
+               fs_cfg: fs_cfg,
                organization: cfg.organization,
                service_states: HashMap::new(),
                sys: Arc::new(sys),
+               http_auth_token: cfg.http_auth_token,
+               strict_templates: cfg.strict_templates,
+               rumor_ttl: cfg.rumor_ttl,
+               ring_key_counter: 0,
            })
     }
 
+    /// Loads the ring key this Supervisor encrypts its gossip with, along with the other
+    /// revisions of it in the key cache, which it still decrypts gossip with while the ring moves
+    /// to a new revision. The ring may have moved to a newer revision and retired the given one
+    /// before a restart, so the newest revision the ring didn't retire is encrypted with instead.
+    fn load_ring_keys(ring_with_revision: &str, cache: &Path) -> Result<RingKeys> {
+        let requested = SymKey::get_pair_for(ring_with_revision, cache)?;
+        let retired_before = read_ring_key_retirement(&requested.name, cache);
+        let mut keys: Vec<SymKey> = SymKey::get_pairs_for(&requested.name, cache)?
+            .into_iter()
+            .filter(|key| match retired_before {
+                        Some(ref rev) => &key.rev >= rev,
+                        None => true,
+                    })
+            .collect();
+        keys.sort_by(|a, b| b.rev.cmp(&a.rev));
+        if keys.is_empty() {
+            return Err(sup_error!(Error::RetiredRingKey(ring_with_revision.to_string())));
+        }
+        let primary = keys.remove(0);
+        if primary.rev != requested.rev {
+            outputln!("Encrypting with ring key {}, the newest revision the ring didn't retire, \
+                       instead of {}",
+                      primary.name_with_rev(),
+                      ring_with_revision);
+        }
+        let mut ring_keys = RingKeys::new(primary, keys);
+        if let Some(rev) = retired_before {
+            ring_keys.restore_retired_before(rev);
+        }
+        remove_retired_ring_keys(&ring_keys, cache);
+        Ok(ring_keys)
+    }
+
+    /// Loads the member key issued to this Supervisor, along with every revision of the ring CA
+    /// key which verifies the member keys of the rest of the ring.
+    fn load_auth(ring_ca: &str, fs_cfg: &FsCfg) -> Result<Auth> {
+        let member_key = MemberKey::read_file(&fs_cfg.member_key_file)
+            .map_err(|e| sup_error!(Error::BadMemberKey(fs_cfg.member_key_file.clone(), e)))?;
+        let ring_ca_pairs = SigKeyPair::get_pairs_for(ring_ca, &default_cache_key_path(None))?;
+        outputln!("Authenticating gossip with ring CA {}", ring_ca);
+        Ok(Auth::new(member_key, ring_ca_pairs)?)
+    }
+
     /// Load the initial Butterly Member which is used in initializing the Butterfly server. This
     /// will load the member-id for the initial Member from disk if a previous manager has been
     /// run.
     ///
     /// The mutable ref to `Sys` will be configured with Butterfly Member details and will also
     /// populate the initial Member.
-    fn load_member(sys: &mut Sys, fs_cfg: &FsCfg) -> Result<Member> {
+    fn load_member(sys: &mut Sys, fs_cfg: &FsCfg, auth: Option<&Auth>) -> Result<Member> {
         let mut member = Member::default();
-        match File::open(&fs_cfg.member_id_file) {
-            Ok(mut file) => {
+        let member_id_file = match auth {
+            // A member key can only sign for the member it was issued to, so it overrides any
+            // member ID persisted before.
+            Some(auth) => {
+                member.set_id(auth.member_id().to_string());
+                None
+            }
+            None => File::open(&fs_cfg.member_id_file).ok(),
+        };
+        match member_id_file {
+            Some(mut file) => {
                 let mut member_id = String::new();
                 file.read_to_string(&mut member_id)
                     .map_err(|e| sup_error!(Error::BadDataFile(fs_cfg.member_id_file.clone(), e)))?;
                 member.set_id(member_id);
             }
-            Err(_) => {
+            None => {
                 match File::create(&fs_cfg.member_id_file) {
                     Ok(mut file) => {
                         file.write(member.get_id().as_bytes())
@@ -282,7 +401,16 @@ impl Manager {
         Self::specs_path(&Self::state_path_from(cfg)).join(spec.file_name())
     }
 
+    /// Saves the spec in the Supervisor's specs directory. A service to be started must not
+    /// depend on itself through the specs already loaded.
     pub fn save_spec_for(cfg: &ManagerConfig, spec: ServiceSpec) -> Result<()> {
+        if spec.desired_state == DesiredState::Up {
+            let specs_path = Self::specs_path(&Self::state_path_from(cfg));
+            if specs_path.is_dir() {
+                let loaded = SpecWatcher::specs_from_path(&specs_path)?;
+                spec.validate_dependencies(loaded.values())?;
+            }
+        }
         spec.to_file(Self::spec_path_for(cfg, &spec))
     }
 
@@ -357,6 +485,16 @@ impl Manager {
 
     fn add_service(&mut self, spec: ServiceSpec) {
         outputln!("Starting {}", &spec.ident);
+        let loaded = self.watcher
+            .specs_from_watch_path()
+            .unwrap_or_default();
+        if let Err(err) = spec.validate_dependencies(loaded.values()) {
+            outputln!("Unable to start {}, {}", &spec.ident, err);
+            if spec.start_style == StartStyle::Transient {
+                self.remove_spec(&spec);
+            }
+            return;
+        }
         // JW TODO: This clone sucks, but our data structures are a bit messy here. What we really
         // want is the service to hold the spec and, on failure, return an error with the spec
         // back to us. Since we consume and deconstruct the spec in `Service::new()` which
@@ -365,7 +503,8 @@ impl Manager {
         let service = match Service::load(self.sys.clone(),
                                           spec.clone(),
                                           self.fs_cfg.clone(),
-                                          self.organization.as_ref().map(|org| &**org)) {
+                                          self.organization.as_ref().map(|org| &**org),
+                                          self.strict_templates) {
             Ok(service) => service,
             Err(err) => {
                 outputln!("Unable to start {}, {}", &spec.ident, err);
@@ -388,7 +527,7 @@ impl Manager {
         }
 
         self.gossip_latest_service_rumor(&service);
-        if service.topology == Topology::Leader {
+        if service.elects_leader() {
             self.butterfly
                 .start_election(service.service_group.clone(), 0);
         }
@@ -418,6 +557,7 @@ impl Manager {
                       service,
                       err);
         }
+        service::metrics::remove(&service.service_group);
     }
 
     pub fn run(&mut self) -> Result<()> {
@@ -426,13 +566,25 @@ impl Manager {
 
         outputln!("Starting gossip-listener on {}",
                   self.butterfly.gossip_addr());
-        self.butterfly.start(Timing::default())?;
+        let mut timing = Timing::default();
+        if let Some(rumor_ttl) = self.rumor_ttl {
+            timing.rumor_ttl_ms = rumor_ttl as i64 * 1000;
+        }
+        self.butterfly.start(timing)?;
         debug!("gossip-listener started");
         self.persist_state();
         let http_listen_addr = self.sys.http_listen();
+        let (ctl_tx, ctl_rx) = channel::<ServiceCommand>();
         outputln!("Starting http-gateway on {}", &http_listen_addr);
-        http_gateway::Server::new(self.fs_cfg.clone(), http_listen_addr)
-            .start()?;
+        if self.http_auth_token.is_none() {
+            outputln!("No http-gateway auth token configured, service control endpoints are \
+                       disabled");
+        }
+        http_gateway::Server::new(self.fs_cfg.clone(),
+                                  http_listen_addr,
+                                  ctl_tx,
+                                  self.http_auth_token.clone())
+                .start()?;
         debug!("http-gateway started");
 
         let (event_tx, event_rx) = channel::<Vec<CensusEntryProto>>();
@@ -483,7 +635,13 @@ impl Manager {
                 self.shutdown();
                 return Ok(());
             }
+            if self.butterfly.departed() {
+                outputln!("This Supervisor was departed from the ring, shutting down");
+                self.shutdown();
+                return Err(sup_error!(Error::Departed(self.sys.member_id.clone())));
+            }
             self.update_running_services_from_watcher()?;
+            self.check_for_service_commands(&ctl_rx);
             self.check_for_updated_packages();
             self.restart_elections();
             self.census_ring
@@ -492,7 +650,11 @@ impl Manager {
                                     &self.butterfly.update_store,
                                     &self.butterfly.member_list,
                                     &self.butterfly.service_config_store,
-                                    &self.butterfly.service_file_store);
+                                    &self.butterfly.service_file_store,
+                                    &self.butterfly.service_secret_store,
+                                    &self.butterfly.release_blacklist_store);
+            metrics::butterfly(&self.butterfly);
+            self.persist_ring_keys();
 
             if self.check_for_changed_services() {
                 self.persist_state();
@@ -522,12 +684,15 @@ impl Manager {
                 }
             }
 
-            for service in self.services
+            {
+                let mut services = self.services
                     .write()
-                    .expect("Services lock is poisoned!")
-                    .iter_mut() {
-                if service.tick(&self.census_ring) {
-                    self.gossip_latest_service_rumor(&service);
+                    .expect("Services lock is poisoned!");
+                let local_services = LocalServices::new(services.iter());
+                for service in services.iter_mut() {
+                    if service.tick(&self.census_ring, &local_services) {
+                        self.gossip_latest_service_rumor(&service);
+                    }
                 }
             }
             let time_to_wait = (next_check - time::get_time()).num_milliseconds();
@@ -578,6 +743,62 @@ impl Manager {
         }
     }
 
+    /// Apply any commands received from the HTTP gateway since the last tick to the services they
+    /// are addressed to. Commands for service groups which are not running are dropped.
+    fn check_for_service_commands(&mut self, rx: &Receiver<ServiceCommand>) {
+        loop {
+            let command = match rx.try_recv() {
+                Ok(command) => command,
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => {
+                    warn!("Service command channel has disconnected");
+                    return;
+                }
+            };
+            debug!("Received service command, {:?}", command);
+            let mut services = self.services
+                .write()
+                .expect("Services lock is poisoned!");
+            match command {
+                ServiceCommand::Restart(ref service_group) => {
+                    match services
+                              .iter_mut()
+                              .find(|s| s.service_group == *service_group) {
+                        Some(service) => {
+                            outputln!("Restarting {}", service);
+                            service.restart();
+                        }
+                        None => {
+                            outputln!("Tried to restart {} but could not find it running, \
+                                       skipping",
+                                      service_group)
+                        }
+                    }
+                }
+                ServiceCommand::Signal(ref service_group, signal) => {
+                    match services
+                              .iter()
+                              .find(|s| s.service_group == *service_group) {
+                        Some(service) => {
+                            outputln!("Sending signal {} to {}", signal.os_signal(), service);
+                            if let Err(e) = service.send_signal(signal) {
+                                outputln!("Failed to send signal {} to {}: {}",
+                                          signal.os_signal(),
+                                          service,
+                                          e);
+                            }
+                        }
+                        None => {
+                            outputln!("Tried to signal {} but could not find it running, \
+                                       skipping",
+                                      service_group)
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     /// Walk each service and check if it has an updated package installed via the Update Strategy.
     /// This updates the Service to point to the new service struct, and then marks it for
     /// restarting.
@@ -591,6 +812,8 @@ impl Manager {
                 .iter_mut() {
             if self.updater
                    .check_for_updated_package(service, &self.census_ring) {
+                events::publish(Event::update_applied(&service.service_group,
+                                                      &service.pkg.ident));
                 self.gossip_latest_service_rumor(&service);
             }
         }
@@ -701,6 +924,37 @@ impl Manager {
         }
     }
 
+    /// Writes the revisions of the ring key gossiped to this Supervisor to the key cache, so it
+    /// keeps decrypting with them after a restart, along with the revision which retired every
+    /// older one, and removes the revisions the ring retired.
+    fn persist_ring_keys(&mut self) {
+        let counter = self.butterfly.ring_key_store.get_update_counter();
+        if counter == self.ring_key_counter {
+            return;
+        }
+        self.ring_key_counter = counter;
+        let cache = default_cache_key_path(None);
+        let ring_keys = self.butterfly.ring_keys();
+        for ring_key in ring_keys.keys() {
+            let result = ring_key
+                .to_secret_string()
+                .and_then(|content| SymKey::write_file_from_str(&content, &cache));
+            if let Err(err) = result {
+                warn!("Couldn't write ring key {} to the key cache, {}",
+                      ring_key.name_with_rev(),
+                      err);
+            }
+        }
+        if let (Some(name), Some(rev)) = (ring_keys.name(), ring_keys.retired_before()) {
+            if let Err(err) = write_ring_key_retirement(name, rev, &cache) {
+                warn!("Couldn't write the retired revisions of ring key {} to the key cache, {}",
+                      name,
+                      err);
+            }
+        }
+        remove_retired_ring_keys(&ring_keys, &cache);
+    }
+
     fn persist_services_state(&self) {
         let tmp_file = self.fs_cfg.services_data_path.with_extension("dat.tmp");
         let file = match File::create(&tmp_file) {
@@ -741,7 +995,8 @@ impl Manager {
             match Service::load(self.sys.clone(),
                                 down.clone(),
                                 self.fs_cfg.clone(),
-                                self.organization.as_ref().map(|org| &**org)) {
+                                self.organization.as_ref().map(|org| &**org),
+                                self.strict_templates) {
                 Ok(service) => {
                     if let Some(err) = self.write_service(&service, is_first, writer.get_mut())
                            .err() {
@@ -784,12 +1039,20 @@ impl Manager {
         self.butterfly.restart_elections();
     }
 
+    /// Stops all services, each before the local services it requires or is started after.
     fn shutdown(&self) {
         let mut services = self.services
             .write()
             .expect("Services lock is poisend!");
-        for mut service in services.drain(..) {
-            self.remove_service(&mut service);
+        let dependencies: Vec<(String, Vec<String>)> = services
+            .iter()
+            .map(|service| (service.pkg.name.clone(), service.dependencies()))
+            .collect();
+        let mut stopping: Vec<Option<Service>> = services.drain(..).map(Some).collect();
+        for i in stop_order(&dependencies) {
+            if let Some(mut service) = stopping[i].take() {
+                self.remove_service(&mut service);
+            }
         }
         release_process_lock(&self.fs_cfg);
         outputln!("Hasta la vista, services.");
@@ -874,6 +1137,8 @@ pub struct ProcessStatus {
     pub elapsed: TimeDuration,
     pub pid: Option<u32>,
     pub state: ProcessState,
+    #[serde(default)]
+    pub restart_count: u32,
 }
 
 impl fmt::Display for ProcessStatus {
@@ -887,8 +1152,11 @@ impl fmt::Display for ProcessStatus {
                        pid)
             }
             None => write!(f, "state:{}, time:{}", self.state, self.elapsed),
+        }?;
+        if self.restart_count > 0 {
+            write!(f, ", restarts:{}", self.restart_count)?;
         }
-
+        Ok(())
     }
 }
 
@@ -898,6 +1166,8 @@ pub struct ServiceStatus {
     pub process: ProcessStatus,
     pub service_group: ServiceGroup,
     pub start_style: StartStyle,
+    #[serde(default)]
+    pub task: Option<TaskStatus>,
 }
 
 impl fmt::Display for ServiceStatus {
@@ -907,7 +1177,11 @@ impl fmt::Display for ServiceStatus {
                self.pkg.ident,
                self.process,
                self.service_group,
-               self.start_style)
+               self.start_style)?;
+        if let Some(ref task) = self.task {
+            write!(f, ", task:{}", task)?;
+        }
+        Ok(())
     }
 }
 
@@ -974,6 +1248,49 @@ fn obtain_process_lock(fs_cfg: &FsCfg) -> Result<()> {
     }
 }
 
+/// Returns the file in the key cache holding the revision of a ring key which retired every older
+/// one.
+fn ring_key_retirement_path(name: &str, cache: &Path) -> PathBuf {
+    cache.join(format!("{}.retired", name))
+}
+
+fn read_ring_key_retirement(name: &str, cache: &Path) -> Option<String> {
+    let mut rev = String::new();
+    match File::open(ring_key_retirement_path(name, cache))
+              .and_then(|mut file| file.read_to_string(&mut rev)) {
+        Ok(_) if !rev.trim().is_empty() => Some(rev.trim().to_string()),
+        _ => None,
+    }
+}
+
+fn write_ring_key_retirement(name: &str, rev: &str, cache: &Path) -> io::Result<()> {
+    let mut file = File::create(ring_key_retirement_path(name, cache))?;
+    write!(&mut file, "{}", rev)
+}
+
+/// Removes the revisions of the ring key which the ring retired from the key cache.
+fn remove_retired_ring_keys(ring_keys: &RingKeys, cache: &Path) {
+    let name = match ring_keys.name() {
+        Some(name) => name,
+        None => return,
+    };
+    let cached = SymKey::get_pairs_for(name, cache).unwrap_or_default();
+    for ring_key in cached.iter().filter(|k| ring_keys.is_retired(&k.rev)) {
+        let name_with_rev = ring_key.name_with_rev();
+        let result = SymKey::get_secret_key_path(&name_with_rev, cache)
+            .map_err(|e| format!("{}", e))
+            .and_then(|path| fs::remove_file(path).map_err(|e| format!("{}", e)));
+        match result {
+            Ok(()) => outputln!("Removed retired ring key {} from the key cache", name_with_rev),
+            Err(err) => {
+                warn!("Couldn't remove retired ring key {} from the key cache, {}",
+                      name_with_rev,
+                      err)
+            }
+        }
+    }
+}
+
 fn read_process_lock<T>(lock_path: T) -> Result<process::Pid>
     where T: AsRef<Path>
 {
@@ -1021,9 +1338,19 @@ fn write_process_lock<T>(lock_path: T) -> Result<()>
 
 #[cfg(test)]
 mod test {
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
+
+    use hcore::crypto::SymKey;
+    use tempdir::TempDir;
 
-    use super::{Manager, ManagerConfig, STATE_PATH_PREFIX};
+    use super::{write_ring_key_retirement, Manager, ManagerConfig, STATE_PATH_PREFIX};
+
+    fn cache_ring_key(rev: &str, cache: &Path) -> SymKey {
+        let mut ring_key = SymKey::generate_in_memory("beyonce").unwrap();
+        ring_key.rev = String::from(rev);
+        SymKey::write_file_from_str(&ring_key.to_secret_string().unwrap(), cache).unwrap();
+        ring_key
+    }
 
     #[test]
     fn manager_state_path_default() {
@@ -1062,4 +1389,39 @@ mod test {
 
         assert_eq!(PathBuf::from("/tmp/partay"), path);
     }
+
+    #[test]
+    fn load_ring_keys_encrypts_with_the_newest_revision() {
+        let cache = TempDir::new("key-cache").unwrap();
+        let old = cache_ring_key("20170101000000", cache.path());
+        cache_ring_key("20170102000000", cache.path());
+
+        let ring_keys = Manager::load_ring_keys(&old.name_with_rev(), cache.path()).unwrap();
+        assert_eq!(ring_keys.primary().unwrap().rev, "20170102000000");
+        assert!(ring_keys.contains("20170101000000"));
+        assert_eq!(ring_keys.retired_before(), None);
+    }
+
+    #[test]
+    fn load_ring_keys_skips_retired_revisions() {
+        let cache = TempDir::new("key-cache").unwrap();
+        let old = cache_ring_key("20170101000000", cache.path());
+        cache_ring_key("20170102000000", cache.path());
+        write_ring_key_retirement("beyonce", "20170102000000", cache.path()).unwrap();
+
+        let ring_keys = Manager::load_ring_keys(&old.name_with_rev(), cache.path()).unwrap();
+        assert_eq!(ring_keys.primary().unwrap().rev, "20170102000000");
+        assert_eq!(ring_keys.keys().len(), 1);
+        assert_eq!(ring_keys.retired_before(), Some("20170102000000"));
+        assert!(SymKey::get_pair_for(&old.name_with_rev(), cache.path()).is_err());
+    }
+
+    #[test]
+    fn load_ring_keys_fails_when_every_revision_was_retired() {
+        let cache = TempDir::new("key-cache").unwrap();
+        let old = cache_ring_key("20170101000000", cache.path());
+        write_ring_key_retirement("beyonce", "20170102000000", cache.path()).unwrap();
+
+        assert!(Manager::load_ring_keys(&old.name_with_rev(), cache.path()).is_err());
+    }
 }
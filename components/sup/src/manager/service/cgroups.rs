@@ -0,0 +1,374 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resource limits for supervised processes, enforced with cgroups v2.
+//!
+//! Each service with resource limits gets its own cgroup under `<cgroup root>/hab`, named after
+//! its service group. The limits are written when the service's process is started and the
+//! process joins the cgroup after it is forked but before it executes the service, so it never
+//! runs unconstrained and every child it forks is accounted for too. Resource limits are ignored
+//! on platforms without a cgroup v2 unified hierarchy.
+
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+use hcore::env as henv;
+use hcore::service::ServiceGroup;
+use prometheus::GaugeVec;
+
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "CG";
+/// Environment variable to override the mount point of the cgroup v2 hierarchy.
+pub static CGROUP_ROOT_ENVVAR: &'static str = "HAB_CGROUP_ROOT";
+const DEFAULT_CGROUP_ROOT: &'static str = "/sys/fs/cgroup";
+const SUPERVISOR_CGROUP: &'static str = "hab";
+const CPU_PERIOD_USEC: u64 = 100_000;
+
+lazy_static! {
+    static ref SERVICE_MEMORY_BYTES: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_service_memory_bytes",
+            "Memory currently used by the cgroup of a service."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_CPU_SECONDS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_service_cpu_seconds_total",
+            "Total CPU time consumed by the cgroup of a service."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_PIDS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_service_pids",
+            "Number of processes in the cgroup of a service."),
+        &["service_group"]).unwrap();
+}
+
+/// Resource limits of a service, declared either in its spec or under a `[resource_limits]`
+/// table in the package's `default.toml`, for example:
+///
+/// ```toml
+/// [resource_limits]
+/// memory_max = 536870912
+/// cpu_weight = 200
+/// cpu_quota_percent = 150
+/// pids_max = 256
+/// io_weight = 50
+/// ```
+///
+/// `memory_max` is in bytes, `cpu_quota_percent` is the share of one CPU the service may use
+/// (`150` is one and a half CPUs) and the weights range from 1 to 10000. Unset limits are left at
+/// the kernel's defaults.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct ResourceLimits {
+    pub memory_max: Option<u64>,
+    pub cpu_weight: Option<u32>,
+    pub cpu_quota_percent: Option<u32>,
+    pub pids_max: Option<u64>,
+    pub io_weight: Option<u32>,
+}
+
+impl ResourceLimits {
+    pub fn validate(&self) -> Result<()> {
+        for &(name, weight) in [("cpu_weight", self.cpu_weight), ("io_weight", self.io_weight)]
+                .iter() {
+            if let Some(weight) = weight {
+                if weight < 1 || weight > 10_000 {
+                    return Err(sup_error!(Error::InvalidResourceLimits(format!("{} must be \
+                                                                               between 1 and \
+                                                                               10000, got {}",
+                                                                              name,
+                                                                              weight))));
+                }
+            }
+        }
+        if self.cpu_quota_percent == Some(0) {
+            return Err(sup_error!(Error::InvalidResourceLimits(String::from("cpu_quota_percent \
+                                                                             must be greater \
+                                                                             than zero"))));
+        }
+        Ok(())
+    }
+
+    /// Returns the contents of the cgroup interface files for the limits which are set.
+    fn to_interface_files(&self) -> Vec<(&'static str, String)> {
+        let mut files = Vec::new();
+        if let Some(memory_max) = self.memory_max {
+            files.push(("memory.max", memory_max.to_string()));
+        }
+        if let Some(cpu_weight) = self.cpu_weight {
+            files.push(("cpu.weight", cpu_weight.to_string()));
+        }
+        if let Some(percent) = self.cpu_quota_percent {
+            let quota = CPU_PERIOD_USEC * percent as u64 / 100;
+            files.push(("cpu.max", format!("{} {}", quota, CPU_PERIOD_USEC)));
+        }
+        if let Some(pids_max) = self.pids_max {
+            files.push(("pids.max", pids_max.to_string()));
+        }
+        if let Some(io_weight) = self.io_weight {
+            files.push(("io.weight", format!("default {}", io_weight)));
+        }
+        files
+    }
+}
+
+/// Current resource usage of a service's cgroup.
+#[derive(Debug, Default, Serialize)]
+pub struct ResourceUsage {
+    pub memory_bytes: Option<u64>,
+    pub cpu_usage_usec: Option<u64>,
+    pub pids: Option<u64>,
+}
+
+#[derive(Debug)]
+pub struct Cgroup {
+    path: PathBuf,
+    service_group: ServiceGroup,
+}
+
+impl Cgroup {
+    /// Creates, or reuses, the cgroup for the given service group and applies the given limits to
+    /// it. Returns `None` if cgroups v2 is not available on this system.
+    pub fn create(service_group: &ServiceGroup, limits: &ResourceLimits) -> Result<Option<Self>> {
+        let root = cgroup_root();
+        if !cfg!(target_os = "linux") || !root.join("cgroup.controllers").is_file() {
+            outputln!(preamble service_group,
+                      "cgroups v2 is not available at {}, ignoring resource limits",
+                      root.display());
+            return Ok(None);
+        }
+        let parent = root.join(SUPERVISOR_CGROUP);
+        let path = parent.join(service_group.as_ref());
+        fs::create_dir_all(&path).map_err(|e| cgroup_err(&path, e))?;
+        // Controllers have to be enabled for the children of every cgroup between the root and
+        // the service's cgroup.
+        for cgroup in [&root, &parent].iter() {
+            enable_controllers(cgroup)?;
+        }
+        for (file, value) in limits.to_interface_files() {
+            write_interface_file(&path.join(file), &value)?;
+        }
+        Ok(Some(Cgroup {
+                    path: path,
+                    service_group: service_group.clone(),
+                }))
+    }
+
+    /// Makes the process spawned by `cmd` join this cgroup before it executes. The returned file
+    /// has to be kept open until the process has been spawned.
+    ///
+    /// The cgroup's `cgroup.procs` is opened here, by the Supervisor, and the child only writes to
+    /// the open file, as it has already dropped to the service's user by then.
+    pub fn join_before_exec(&self, cmd: &mut Command) -> Result<File> {
+        let path = self.path.join("cgroup.procs");
+        let procs = OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .map_err(|e| cgroup_err(&path, e))?;
+        join_before_exec(cmd, &procs);
+        Ok(procs)
+    }
+
+    pub fn usage(&self) -> ResourceUsage {
+        ResourceUsage {
+            memory_bytes: read_u64(&self.path.join("memory.current")),
+            cpu_usage_usec: read_stat(&self.path.join("cpu.stat"), "usage_usec"),
+            pids: read_u64(&self.path.join("pids.current")),
+        }
+    }
+
+    /// Publishes the current usage of the cgroup to the Supervisor's metrics.
+    pub fn update_metrics(&self) {
+        let usage = self.usage();
+        let mut labels = HashMap::new();
+        labels.insert("service_group", self.service_group.as_ref());
+        if let Some(bytes) = usage.memory_bytes {
+            SERVICE_MEMORY_BYTES.with(&labels).set(bytes as f64);
+        }
+        if let Some(usec) = usage.cpu_usage_usec {
+            SERVICE_CPU_SECONDS
+                .with(&labels)
+                .set(usec as f64 / 1_000_000.0);
+        }
+        if let Some(pids) = usage.pids {
+            SERVICE_PIDS.with(&labels).set(pids as f64);
+        }
+    }
+
+    /// Removes the cgroup. This fails while processes are still in it.
+    pub fn remove(&self) {
+        if let Err(err) = fs::remove_dir(&self.path) {
+            debug!("Unable to remove cgroup {}, {}", self.path.display(), err);
+        }
+    }
+}
+
+#[cfg(unix)]
+fn join_before_exec(cmd: &mut Command, procs: &File) {
+    use std::os::unix::io::AsRawFd;
+    use std::os::unix::process::CommandExt;
+    use libc;
+
+    let fd = procs.as_raw_fd();
+    // Writing 0 to `cgroup.procs` moves the writing process. Only async-signal-safe calls are
+    // allowed between fork and exec, hence the raw write.
+    cmd.before_exec(move || {
+                        let written = unsafe {
+                            libc::write(fd, b"0".as_ptr() as *const libc::c_void, 1)
+                        };
+                        if written < 0 {
+                            return Err(io::Error::last_os_error());
+                        }
+                        Ok(())
+                    });
+}
+
+#[cfg(not(unix))]
+fn join_before_exec(_cmd: &mut Command, _procs: &File) {}
+
+fn cgroup_root() -> PathBuf {
+    match henv::var(CGROUP_ROOT_ENVVAR) {
+        Ok(root) => PathBuf::from(root),
+        Err(_) => PathBuf::from(DEFAULT_CGROUP_ROOT),
+    }
+}
+
+fn enable_controllers(cgroup: &Path) -> Result<()> {
+    let mut available = String::new();
+    File::open(cgroup.join("cgroup.controllers"))
+        .and_then(|mut f| f.read_to_string(&mut available))
+        .map_err(|e| cgroup_err(cgroup, e))?;
+    let wanted = ["memory", "cpu", "pids", "io"];
+    let controllers: Vec<String> = available
+        .split_whitespace()
+        .filter(|c| wanted.contains(c))
+        .map(|c| format!("+{}", c))
+        .collect();
+    if controllers.is_empty() {
+        return Ok(());
+    }
+    write_interface_file(&cgroup.join("cgroup.subtree_control"),
+                         &controllers.join(" "))
+}
+
+fn write_interface_file(path: &Path, value: &str) -> Result<()> {
+    OpenOptions::new()
+        .write(true)
+        .open(path)
+        .and_then(|mut f| f.write_all(value.as_bytes()))
+        .map_err(|e| cgroup_err(path, e))
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    let mut buf = String::new();
+    match File::open(path).and_then(|mut f| f.read_to_string(&mut buf)) {
+        Ok(_) => u64::from_str(buf.trim()).ok(),
+        Err(_) => None,
+    }
+}
+
+/// Reads a single value from a flat keyed cgroup file such as `cpu.stat`.
+fn read_stat(path: &Path, key: &str) -> Option<u64> {
+    let mut buf = String::new();
+    if File::open(path)
+           .and_then(|mut f| f.read_to_string(&mut buf))
+           .is_err() {
+        return None;
+    }
+    buf.lines()
+        .filter_map(|line| {
+                        let mut parts = line.split_whitespace();
+                        match (parts.next(), parts.next()) {
+                            (Some(k), Some(v)) if k == key => u64::from_str(v).ok(),
+                            _ => None,
+                        }
+                    })
+        .next()
+}
+
+fn cgroup_err(path: &Path, err: io::Error) -> SupError {
+    sup_error!(Error::CgroupIO(path.to_path_buf(), err))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::Write;
+
+    use tempdir::TempDir;
+    use toml;
+
+    use super::*;
+
+    #[test]
+    fn resource_limits_toml_deserialize() {
+        let limits: ResourceLimits = toml::from_str(r#"
+            memory_max = 1048576
+            cpu_quota_percent = 50
+            "#)
+                .unwrap();
+
+        assert_eq!(limits.memory_max, Some(1048576));
+        assert_eq!(limits.cpu_quota_percent, Some(50));
+        assert_eq!(limits.cpu_weight, None);
+        assert_eq!(limits.pids_max, None);
+    }
+
+    #[test]
+    fn resource_limits_to_interface_files() {
+        let limits = ResourceLimits {
+            memory_max: Some(1024),
+            cpu_weight: Some(200),
+            cpu_quota_percent: Some(150),
+            pids_max: None,
+            io_weight: Some(50),
+        };
+
+        assert_eq!(limits.to_interface_files(),
+                   vec![("memory.max", String::from("1024")),
+                        ("cpu.weight", String::from("200")),
+                        ("cpu.max", String::from("150000 100000")),
+                        ("io.weight", String::from("default 50"))]);
+    }
+
+    #[test]
+    fn resource_limits_validate() {
+        let mut limits = ResourceLimits::default();
+        assert!(limits.validate().is_ok());
+        limits.cpu_weight = Some(0);
+        assert!(limits.validate().is_err());
+        limits.cpu_weight = Some(100);
+        limits.io_weight = Some(10_001);
+        assert!(limits.validate().is_err());
+    }
+
+    #[test]
+    fn read_stat_value() {
+        let tmpdir = TempDir::new("cgroups").unwrap();
+        let path = tmpdir.path().join("cpu.stat");
+        let mut file = File::create(&path).unwrap();
+        file.write_all(b"usage_usec 123456\nuser_usec 100000\nsystem_usec 23456\n")
+            .unwrap();
+
+        assert_eq!(read_stat(&path, "usage_usec"), Some(123456));
+        assert_eq!(read_stat(&path, "nr_throttled"), None);
+    }
+}
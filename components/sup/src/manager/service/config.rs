@@ -30,6 +30,7 @@ use toml;
 use super::Pkg;
 use census::CensusGroup;
 use error::{Error, Result};
+use fs;
 use templating::{TemplateRenderer, RenderContext};
 
 static LOGKEY: &'static str = "CF";
@@ -40,6 +41,9 @@ static ENV_VAR_PREFIX: &'static str = "HAB";
 /// is deeper than this value crosses into overly complex territory when describing configuration
 /// for a single service.
 static TOML_MAX_MERGE_DEPTH: u16 = 30;
+/// Permissions of the configuration files of a service group with secrets, which only the
+/// service's user may read.
+const SECRET_CONFIG_PERMISSIONS: u32 = 0o600;
 
 #[derive(Clone, Debug, Default)]
 pub struct Cfg {
@@ -86,6 +90,11 @@ impl Cfg {
         }
     }
 
+    /// Returns the incarnation of the census group's service config last applied.
+    pub fn gossip_incarnation(&self) -> u64 {
+        self.gossip_incarnation
+    }
+
     /// Returns a subset of the overall configuration whitelisted by the given package's exports.
     pub fn to_exported(&self, pkg: &Pkg) -> Result<toml::value::Table> {
         let mut map = toml::value::Table::default();
@@ -225,6 +234,7 @@ impl CfgRenderer {
         where T: AsRef<Path>
     {
         let mut template = TemplateRenderer::new();
+        template.include_from(templates_path.as_ref());
         if let Ok(entries) = std::fs::read_dir(templates_path) {
             for entry in entries {
                 if let Ok(entry) = entry {
@@ -252,14 +262,26 @@ impl CfgRenderer {
     }
 
     /// Compile and write all configuration files to the configuration directory.
-    pub fn compile(&self, pkg: &Pkg, ctx: &RenderContext) -> Result<bool> {
+    ///
+    /// Every template is rendered before any file is written, so a template which fails to
+    /// render leaves all of the previously compiled files in place. In strict mode, a template
+    /// referring to a variable the context doesn't define fails to render.
+    pub fn compile(&self, pkg: &Pkg, ctx: &RenderContext, strict: bool) -> Result<bool> {
         // JW TODO: This function is loaded with IO errors that will be converted a Supervisor
         // error resulting in the end-user not knowing what the fuck happned at all. We need to go
         // through this and pipe the service group through to let people know which service is
         // having issues and be more descriptive about what happened.
-        let mut changed = false;
+        let mut compiled_templates = Vec::new();
         for (template, _) in self.0.get_templates() {
-            let compiled = self.0.render(&template, ctx)?;
+            let compiled = if strict {
+                self.0.render_strict(&template, ctx)?
+            } else {
+                self.0.render(&template, ctx)?
+            };
+            compiled_templates.push((template, compiled));
+        }
+        let mut changed = false;
+        for (template, compiled) in compiled_templates {
             let compiled_hash = crypto::hash::hash_string(&compiled);
             let cfg_dest = pkg.svc_config_path.join(&template);
             let file_hash = match crypto::hash::hash_file(&cfg_dest) {
@@ -275,8 +297,7 @@ impl CfgRenderer {
                 outputln!(preamble ctx.svc.group, "Updated {} {}",
                           Purple.bold().paint(template.as_str()),
                           compiled_hash);
-                let mut config_file = File::create(&cfg_dest)?;
-                config_file.write_all(&compiled.into_bytes())?;
+                self.write(pkg, ctx, &cfg_dest, compiled)?;
                 changed = true
             } else {
                 if file_hash == compiled_hash {
@@ -290,14 +311,29 @@ impl CfgRenderer {
                     outputln!(preamble ctx.svc.group,"Updated {} {}",
                               Purple.bold().paint(template.as_str()),
                               compiled_hash);
-                    let mut config_file = File::create(&cfg_dest)?;
-                    config_file.write_all(&compiled.into_bytes())?;
+                    self.write(pkg, ctx, &cfg_dest, compiled)?;
                     changed = true;
                 }
             }
         }
         Ok(changed)
     }
+
+    /// Writes a compiled template. Any template may render the secrets of the service group, so
+    /// when it has any every file is written so that only the service's user can read it.
+    fn write(&self, pkg: &Pkg, ctx: &RenderContext, dest: &Path, compiled: String) -> Result<()> {
+        if ctx.secret.is_empty() {
+            let mut config_file = File::create(dest)?;
+            config_file.write_all(&compiled.into_bytes())?;
+            Ok(())
+        } else {
+            let owner = (pkg.svc_user.as_str(), pkg.svc_group.as_str());
+            fs::write_private(dest,
+                              compiled.as_bytes(),
+                              SECRET_CONFIG_PERMISSIONS,
+                              Some(owner))
+        }
+    }
 }
 
 // Recursively merges the `other` TOML table into `me`
@@ -0,0 +1,184 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Bounded history of the gossiped configurations a Supervisor applied to one of its services,
+//! kept on disk so an earlier configuration can be looked up and re-applied after a bad
+//! `hab config apply`.
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use serde_json;
+use time;
+use toml;
+
+use error::{Error, Result, SupError};
+use fs;
+
+static LOGKEY: &'static str = "CH";
+/// Number of applied configurations kept for each service group.
+pub const CONFIG_HISTORY_LIMIT: usize = 10;
+/// Permissions of the history files.
+const CONFIG_HISTORY_PERMISSIONS: u32 = 0o600;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConfigHistoryEntry {
+    /// Incarnation of the service config rumor the configuration came from.
+    pub incarnation: u64,
+    /// When the Supervisor applied the configuration, in seconds since the epoch.
+    pub applied_at: i64,
+    /// The configuration, as TOML.
+    pub config: String,
+}
+
+#[derive(Debug)]
+pub struct ConfigHistory {
+    path: PathBuf,
+    /// Applied configurations, most recent first.
+    entries: Vec<ConfigHistoryEntry>,
+}
+
+impl ConfigHistory {
+    /// Loads the history kept in the given file. A missing or unreadable file starts an empty
+    /// history.
+    pub fn load<T>(path: T) -> Self
+        where T: Into<PathBuf>
+    {
+        let path = path.into();
+        ConfigHistory {
+            entries: read_entries(&path),
+            path: path,
+        }
+    }
+
+    pub fn entries(&self) -> &[ConfigHistoryEntry] {
+        &self.entries
+    }
+
+    /// Returns the configuration applied for the given incarnation, if it is still kept.
+    pub fn get(&self, incarnation: u64) -> Option<&ConfigHistoryEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.incarnation == incarnation)
+    }
+
+    /// Records that the configuration of the given incarnation was applied and writes the
+    /// history back to disk, dropping the oldest entries beyond `CONFIG_HISTORY_LIMIT`.
+    pub fn record(&mut self, incarnation: u64, config: &toml::Value) -> Result<()> {
+        let config = config.to_string();
+        if self.entries.first().map_or(false, |entry| {
+            entry.incarnation == incarnation && entry.config == config
+        }) {
+            return Ok(());
+        }
+        self.entries
+            .retain(|entry| entry.incarnation != incarnation);
+        self.entries
+            .insert(0,
+                    ConfigHistoryEntry {
+                        incarnation: incarnation,
+                        applied_at: time::get_time().sec,
+                        config: config,
+                    });
+        self.entries.truncate(CONFIG_HISTORY_LIMIT);
+        self.write()
+    }
+
+    /// Writes the history so only the Supervisor's user can read it, as the configurations may
+    /// have been applied encrypted.
+    fn write(&self) -> Result<()> {
+        let body = serde_json::to_string(&self.entries)
+            .map_err(|e| sup_error!(Error::ConfigHistorySerialization(e)))?;
+        fs::write_private(&self.path, body.as_bytes(), CONFIG_HISTORY_PERMISSIONS, None)
+    }
+}
+
+fn read_entries(path: &Path) -> Vec<ConfigHistoryEntry> {
+    match File::open(path) {
+        Ok(file) => serde_json::from_reader(file).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tempdir::TempDir;
+    use toml;
+
+    use super::*;
+
+    fn config(port: i64) -> toml::Value {
+        let mut table = toml::value::Table::new();
+        table.insert("port".to_string(), toml::Value::Integer(port));
+        toml::Value::Table(table)
+    }
+
+    #[test]
+    fn record_keeps_most_recent_first() {
+        let tmpdir = TempDir::new("config-history").unwrap();
+        let path = tmpdir.path().join("redis.config-history");
+        let mut history = ConfigHistory::load(&path);
+        history.record(1, &config(6379)).unwrap();
+        history.record(2, &config(6380)).unwrap();
+
+        let history = ConfigHistory::load(&path);
+        let incarnations: Vec<u64> = history.entries().iter().map(|e| e.incarnation).collect();
+        assert_eq!(incarnations, vec![2, 1]);
+        assert_eq!(history.get(1).unwrap().config, config(6379).to_string());
+        assert!(history.get(3).is_none());
+    }
+
+    #[test]
+    fn record_drops_oldest_entries() {
+        let tmpdir = TempDir::new("config-history").unwrap();
+        let mut history = ConfigHistory::load(tmpdir.path().join("redis.config-history"));
+        for incarnation in 1..(CONFIG_HISTORY_LIMIT as u64 + 3) {
+            history.record(incarnation, &config(6379)).unwrap();
+        }
+        assert_eq!(history.entries().len(), CONFIG_HISTORY_LIMIT);
+        assert!(history.get(2).is_none());
+        assert!(history.get(3).is_some());
+    }
+
+    #[test]
+    fn record_replaces_entry_of_same_incarnation() {
+        let tmpdir = TempDir::new("config-history").unwrap();
+        let mut history = ConfigHistory::load(tmpdir.path().join("redis.config-history"));
+        history.record(1, &config(6379)).unwrap();
+        history.record(1, &config(6380)).unwrap();
+        assert_eq!(history.entries().len(), 1);
+        assert_eq!(history.get(1).unwrap().config, config(6380).to_string());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn record_writes_a_private_file() {
+        use std::fs;
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmpdir = TempDir::new("config-history").unwrap();
+        let path = tmpdir.path().join("redis.config-history");
+        ConfigHistory::load(&path)
+            .record(1, &config(6379))
+            .unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn load_of_missing_file() {
+        let tmpdir = TempDir::new("config-history").unwrap();
+        let history = ConfigHistory::load(tmpdir.path().join("redis.config-history"));
+        assert!(history.entries().is_empty());
+    }
+}
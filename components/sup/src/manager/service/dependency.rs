@@ -0,0 +1,147 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Start and stop ordering of the services loaded on the same Supervisor, following the
+//! `requires` and `after` lists of their specs.
+
+use std::collections::HashSet;
+
+use super::{HealthCheck, Service, Topology};
+
+/// Which of the services loaded on a Supervisor are up and healthy, taken before the services are
+/// ticked so a service waiting for others can tell whether it may start. A task service counts as
+/// both once its last run exited successfully, and as neither while it runs.
+#[derive(Debug, Default)]
+pub struct LocalServices {
+    loaded: HashSet<String>,
+    up: HashSet<String>,
+    healthy: HashSet<String>,
+}
+
+impl LocalServices {
+    pub fn new<'a, T>(services: T) -> Self
+        where T: IntoIterator<Item = &'a Service>
+    {
+        let mut local_services = LocalServices::default();
+        for service in services {
+            if service.topology == Topology::Task {
+                let succeeded = service
+                    .task
+                    .as_ref()
+                    .map_or(false, |task| task.succeeded());
+                local_services.insert(&service.pkg.name, succeeded, succeeded);
+                continue;
+            }
+            let up = service.initialized && !service.is_down();
+            let healthy = up && service.health_check == HealthCheck::Ok;
+            local_services.insert(&service.pkg.name, up, healthy);
+        }
+        local_services
+    }
+
+    fn insert(&mut self, name: &str, up: bool, healthy: bool) {
+        self.loaded.insert(name.to_string());
+        if up {
+            self.up.insert(name.to_string());
+        }
+        if healthy {
+            self.healthy.insert(name.to_string());
+        }
+    }
+
+    /// Returns the names of the services a service with the given `requires` and `after` lists
+    /// still has to wait for: required services which aren't up and healthy, whether they are
+    /// loaded or not, and loaded services it starts after which aren't up.
+    pub fn waiting_for(&self, requires: &[String], after: &[String]) -> Vec<String> {
+        let mut waiting = Vec::new();
+        for name in requires {
+            if !self.healthy.contains(name) {
+                waiting.push(name.clone());
+            }
+        }
+        for name in after {
+            if self.loaded.contains(name) && !self.up.contains(name) && !waiting.contains(name) {
+                waiting.push(name.clone());
+            }
+        }
+        waiting
+    }
+}
+
+/// Returns the indices of the given services, each a name and the names it requires or starts
+/// after, in the order they are stopped: every service is stopped before the services it depends
+/// on. Services depending on each other in a cycle are stopped in the order given.
+pub fn stop_order(services: &[(String, Vec<String>)]) -> Vec<usize> {
+    let mut remaining: Vec<usize> = (0..services.len()).collect();
+    let mut order = Vec::with_capacity(services.len());
+    while !remaining.is_empty() {
+        let next = remaining
+            .iter()
+            .position(|&i| {
+                          !remaining
+                               .iter()
+                               .any(|&j| j != i && services[j].1.contains(&services[i].0))
+                      })
+            .unwrap_or(0);
+        order.push(remaining.remove(next));
+    }
+    order
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn waiting_for_required_services() {
+        let mut local_services = LocalServices::default();
+        local_services.insert("postgresql", true, false);
+        local_services.insert("redis", true, true);
+
+        assert_eq!(local_services.waiting_for(&names(&["postgresql", "redis", "consul"]), &[]),
+                   names(&["postgresql", "consul"]));
+    }
+
+    #[test]
+    fn waiting_for_services_started_before() {
+        let mut local_services = LocalServices::default();
+        local_services.insert("postgresql", false, false);
+        local_services.insert("redis", true, false);
+
+        assert_eq!(local_services.waiting_for(&[], &names(&["postgresql", "redis", "consul"])),
+                   names(&["postgresql"]));
+    }
+
+    #[test]
+    fn stop_order_stops_dependents_first() {
+        let services = vec![(String::from("postgresql"), Vec::new()),
+                            (String::from("migrator"), names(&["postgresql"])),
+                            (String::from("app"), names(&["migrator", "postgresql"])),
+                            (String::from("redis"), Vec::new())];
+
+        assert_eq!(stop_order(&services), vec![2, 1, 0, 3]);
+    }
+
+    #[test]
+    fn stop_order_with_cycle() {
+        let services = vec![(String::from("a"), names(&["b"])),
+                            (String::from("b"), names(&["a"])),
+                            (String::from("c"), names(&["a"]))];
+
+        assert_eq!(stop_order(&services), vec![2, 0, 1]);
+    }
+}
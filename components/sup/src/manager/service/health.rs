@@ -40,6 +40,19 @@ impl From<i8> for HealthCheck {
     }
 }
 
+/// Parses the health check gossiped in a service rumor, which is its `Display` form. Members which
+/// don't gossip it are `Unknown`.
+impl<'a> From<&'a str> for HealthCheck {
+    fn from(value: &str) -> HealthCheck {
+        match value {
+            "OK" => HealthCheck::Ok,
+            "WARNING" => HealthCheck::Warning,
+            "CRITICAL" => HealthCheck::Critical,
+            _ => HealthCheck::Unknown,
+        }
+    }
+}
+
 impl fmt::Display for HealthCheck {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
@@ -20,20 +20,39 @@ use std::fs::File;
 use std::path::{Path, PathBuf};
 use std::process::{Child, ExitStatus};
 use std::result;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::Duration;
 
 use ansi_term::Colour;
-use hcore;
+use hcore::os::process::{HabChild, Signal};
 use hcore::service::ServiceGroup;
 use serde::{Serialize, Serializer};
 
-use super::{exec, health, Pkg};
+use super::{exec, health, metrics, Pkg};
 use error::Result;
 use fs;
+use manager::events::{self, Event};
+use output::{Stream, StructuredOutput};
 use templating::{RenderContext, TemplateRenderer};
 
 pub const HOOK_PERMISSIONS: u32 = 0o755;
+/// Permissions of the hooks of a service group with secrets, which only the service's user may
+/// read.
+pub const SECRET_HOOK_PERMISSIONS: u32 = 0o700;
 static LOGKEY: &'static str = "HK";
 
+/// Permissions of the hooks compiled with the given render context. Any template may render the
+/// secrets of the service group in ways no linting can follow, so all the hooks of a service group
+/// with secrets are kept from other users.
+pub fn permissions(ctx: &RenderContext) -> u32 {
+    if ctx.secret.is_empty() {
+        HOOK_PERMISSIONS
+    } else {
+        SECRET_HOOK_PERMISSIONS
+    }
+}
+
 pub fn stdout_log_path<T>(service_group: &ServiceGroup) -> PathBuf
     where T: Hook
 {
@@ -88,13 +107,16 @@ pub trait Hook: fmt::Debug + Sized {
 
     fn new(service_group: &ServiceGroup, render_pair: RenderPair) -> Self;
 
-    /// Compile a hook into it's destination service directory.
-    fn compile(&self, ctx: &RenderContext) -> Result<()> {
-        let data = self.renderer().render("hook", ctx)?;
-        let mut file = File::create(self.path())?;
-        file.write_all(data.as_bytes())?;
-        hcore::util::perm::set_owner(self.path(), &ctx.pkg.svc_user, &ctx.pkg.svc_group)?;
-        hcore::util::perm::set_permissions(self.path(), HOOK_PERMISSIONS)?;
+    /// Compile a hook into it's destination service directory. In strict mode, a hook referring
+    /// to a variable the context doesn't define isn't compiled.
+    fn compile(&self, ctx: &RenderContext, strict: bool) -> Result<()> {
+        let data = if strict {
+            self.renderer().render_strict("hook", ctx)?
+        } else {
+            self.renderer().render("hook", ctx)?
+        };
+        let owner = (ctx.pkg.svc_user.as_str(), ctx.pkg.svc_group.as_str());
+        fs::write_private(self.path(), data.as_bytes(), permissions(ctx), Some(owner))?;
         debug!("{} compiled to {}",
                Self::file_name(),
                self.path().display());
@@ -103,6 +125,16 @@ pub trait Hook: fmt::Debug + Sized {
 
     /// Run a compiled hook.
     fn run(&self, service_group: &ServiceGroup, pkg: &Pkg) -> Self::ExitValue {
+        self.run_with_timeout(service_group, pkg, None)
+    }
+
+    /// Run a compiled hook, killing it along with the processes it started if it is still running
+    /// once the timeout has passed.
+    fn run_with_timeout(&self,
+                        service_group: &ServiceGroup,
+                        pkg: &Pkg,
+                        timeout: Option<Duration>)
+                        -> Self::ExitValue {
         let mut cmd = match exec::run_cmd(self.path(), &pkg) {
             Ok(c) => c,
             Err(err) => {
@@ -119,10 +151,35 @@ pub trait Hook: fmt::Debug + Sized {
                 return Self::ExitValue::default();
             }
         };
+        let cancel_timeout = match timeout {
+            Some(timeout) => {
+                match kill_on_timeout(service_group, Self::file_name(), &mut child, timeout) {
+                    Ok(cancel) => Some(cancel),
+                    Err(err) => {
+                        outputln!(preamble service_group,
+                            "Hook timeout failed to be set, {}, {}", Self::file_name(), err);
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+        events::publish(Event::hook_started(service_group, Self::file_name()));
         let mut hook_output = HookOutput::new(self.stdout_log_path(), self.stderr_log_path());
         hook_output.stream_output::<Self>(service_group, &mut child);
-        match child.wait() {
-            Ok(status) => self.handle_exit(service_group, &hook_output, &status),
+        let exit = child.wait();
+        if let Some(cancel) = cancel_timeout {
+            // Fails when the timeout already passed and the hook was killed.
+            let _ = cancel.send(());
+        }
+        match exit {
+            Ok(status) => {
+                metrics::hook_exited(service_group, Self::file_name(), status.code());
+                events::publish(Event::hook_exited(service_group,
+                                                   Self::file_name(),
+                                                   status.code()));
+                self.handle_exit(service_group, &hook_output, &status)
+            }
             Err(err) => {
                 outputln!(preamble service_group,
                     "Hook failed to run, {}, {}", Self::file_name(), err);
@@ -146,6 +203,35 @@ pub trait Hook: fmt::Debug + Sized {
     fn stderr_log_path(&self) -> &Path;
 }
 
+/// Kills the hook's process, and the processes in its group, if it hasn't exited once the timeout
+/// has passed. Returns a channel to cancel the timeout when the hook exits in time.
+fn kill_on_timeout(service_group: &ServiceGroup,
+                   hook_name: &'static str,
+                   child: &mut Child,
+                   timeout: Duration)
+                   -> Result<Sender<()>> {
+    let mut hook_process = HabChild::from(child)?;
+    let service_group = service_group.clone();
+    let (cancel, cancelled) = mpsc::channel();
+    thread::Builder::new()
+        .name(format!("{}-{}", hook_name, service_group))
+        .spawn(move || {
+            if let Err(RecvTimeoutError::Timeout) = cancelled.recv_timeout(timeout) {
+                outputln!(preamble service_group,
+                          "Hook {} still running after {} seconds, killing it",
+                          hook_name,
+                          timeout.as_secs());
+                if let Err(err) = hook_process.stop(Signal::KILL, Duration::from_secs(0)) {
+                    outputln!(preamble service_group,
+                              "Hook {} failed to be killed, {}",
+                              hook_name,
+                              err);
+                }
+            }
+        })?;
+    Ok(cancel)
+}
+
 #[derive(Debug, Serialize)]
 pub struct FileUpdatedHook {
     render_pair: RenderPair,
@@ -427,6 +513,60 @@ impl Hook for PostRunHook {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct PreStopHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for PreStopHook {
+    type ExitValue = ExitCode;
+
+    fn file_name() -> &'static str {
+        "pre-stop"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        PreStopHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(&self,
+                       service_group: &ServiceGroup,
+                       _: &'a HookOutput,
+                       status: &ExitStatus)
+                       -> Self::ExitValue {
+        match status.code() {
+            Some(code) => ExitCode(code),
+            None => {
+                outputln!(preamble service_group,
+                    "{} exited without a status code", Self::file_name());
+                ExitCode::default()
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn renderer(&self) -> &TemplateRenderer {
+        &self.render_pair.renderer
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct ReloadHook {
     render_pair: RenderPair,
@@ -680,6 +820,80 @@ impl Hook for SuitabilityHook {
     }
 }
 
+/// Outcome of the `validate-config` hook. A configuration is only valid if the hook ran and
+/// said so.
+#[derive(Debug, PartialEq)]
+pub enum ConfigValidation {
+    Valid,
+    Rejected(String),
+}
+
+impl Default for ConfigValidation {
+    fn default() -> ConfigValidation {
+        ConfigValidation::Rejected(format!("{} hook failed to run",
+                                           ValidateConfigHook::file_name()))
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ValidateConfigHook {
+    render_pair: RenderPair,
+    stdout_log_path: PathBuf,
+    stderr_log_path: PathBuf,
+}
+
+impl Hook for ValidateConfigHook {
+    type ExitValue = ConfigValidation;
+
+    fn file_name() -> &'static str {
+        "validate-config"
+    }
+
+    fn new(service_group: &ServiceGroup, pair: RenderPair) -> Self {
+        ValidateConfigHook {
+            render_pair: pair,
+            stdout_log_path: stdout_log_path::<Self>(service_group),
+            stderr_log_path: stderr_log_path::<Self>(service_group),
+        }
+    }
+
+    fn handle_exit<'a>(&self,
+                       _: &ServiceGroup,
+                       hook_output: &'a HookOutput,
+                       status: &ExitStatus)
+                       -> Self::ExitValue {
+        match status.code() {
+            Some(0) => ConfigValidation::Valid,
+            Some(code) => {
+                let reason = hook_output.last_stderr_line().unwrap_or_else(|| {
+                    format!("{} exited with status code {}", Self::file_name(), code)
+                });
+                ConfigValidation::Rejected(reason)
+            }
+            None => {
+                ConfigValidation::Rejected(format!("{} exited without a status code",
+                                                   Self::file_name()))
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.render_pair.path
+    }
+
+    fn renderer(&self) -> &TemplateRenderer {
+        &self.render_pair.renderer
+    }
+
+    fn stdout_log_path(&self) -> &Path {
+        &self.stdout_log_path
+    }
+
+    fn stderr_log_path(&self) -> &Path {
+        &self.stderr_log_path
+    }
+}
+
 #[derive(Debug, Default, Serialize)]
 pub struct HookTable {
     pub health_check: Option<HealthCheckHook>,
@@ -690,7 +904,9 @@ pub struct HookTable {
     pub suitability: Option<SuitabilityHook>,
     pub run: Option<RunHook>,
     pub post_run: Option<PostRunHook>,
+    pub pre_stop: Option<PreStopHook>,
     pub smoke_test: Option<SmokeTestHook>,
+    pub validate_config: Option<ValidateConfigHook>,
 }
 
 impl HookTable {
@@ -710,7 +926,10 @@ impl HookTable {
                 table.reconfigure = ReconfigureHook::load(service_group, &hooks, &templates);
                 table.run = RunHook::load(service_group, &hooks, &templates);
                 table.post_run = PostRunHook::load(service_group, &hooks, &templates);
+                table.pre_stop = PreStopHook::load(service_group, &hooks, &templates);
                 table.smoke_test = SmokeTestHook::load(service_group, &hooks, &templates);
+                table.validate_config =
+                    ValidateConfigHook::load(service_group, &hooks, &templates);
             }
         }
         debug!("{}, Hooks loaded, destination={}, templates={}",
@@ -720,42 +939,50 @@ impl HookTable {
         table
     }
 
-    /// Compile all loaded hooks from the table into their destination service directory.
-    pub fn compile(&self, service_group: &ServiceGroup, ctx: &RenderContext) {
+    /// Compile all loaded hooks from the table into their destination service directory. The
+    /// `validate-config` hook is left out, it is compiled against the configuration it validates.
+    pub fn compile(&self, service_group: &ServiceGroup, ctx: &RenderContext, strict: bool) {
         if let Some(ref hook) = self.file_updated {
-            self.compile_one(hook, service_group, ctx);
+            self.compile_one(hook, service_group, ctx, strict);
         }
         if let Some(ref hook) = self.health_check {
-            self.compile_one(hook, service_group, ctx);
+            self.compile_one(hook, service_group, ctx, strict);
         }
         if let Some(ref hook) = self.init {
-            self.compile_one(hook, service_group, ctx);
+            self.compile_one(hook, service_group, ctx, strict);
         }
         if let Some(ref hook) = self.reload {
-            self.compile_one(hook, service_group, ctx);
+            self.compile_one(hook, service_group, ctx, strict);
         }
         if let Some(ref hook) = self.reconfigure {
-            self.compile_one(hook, service_group, ctx);
+            self.compile_one(hook, service_group, ctx, strict);
         }
         if let Some(ref hook) = self.suitability {
-            self.compile_one(hook, service_group, ctx);
+            self.compile_one(hook, service_group, ctx, strict);
         }
         if let Some(ref hook) = self.run {
-            self.compile_one(hook, service_group, ctx);
+            self.compile_one(hook, service_group, ctx, strict);
         }
         if let Some(ref hook) = self.post_run {
-            self.compile_one(hook, service_group, ctx);
+            self.compile_one(hook, service_group, ctx, strict);
+        }
+        if let Some(ref hook) = self.pre_stop {
+            self.compile_one(hook, service_group, ctx, strict);
         }
         if let Some(ref hook) = self.smoke_test {
-            self.compile_one(hook, service_group, ctx);
+            self.compile_one(hook, service_group, ctx, strict);
         }
         debug!("{}, Hooks compiled", service_group);
     }
 
-    fn compile_one<H>(&self, hook: &H, service_group: &ServiceGroup, ctx: &RenderContext)
+    fn compile_one<H>(&self,
+                      hook: &H,
+                      service_group: &ServiceGroup,
+                      ctx: &RenderContext,
+                      strict: bool)
         where H: Hook
     {
-        hook.compile(ctx)
+        hook.compile(ctx, strict)
             .unwrap_or_else(|e| {
                                 outputln!(preamble service_group,
                 "Failed to compile {} hook: {}", H::file_name(), e);
@@ -817,7 +1044,6 @@ impl<'a> HookOutput<'a> {
         }
     }
 
-    #[allow(dead_code)]
     fn stderr(&self) -> Option<BufReader<File>> {
         match File::open(&self.stderr_log_file) {
             Ok(f) => Some(BufReader::new(f)),
@@ -825,17 +1051,28 @@ impl<'a> HookOutput<'a> {
         }
     }
 
+    /// Returns the last line the hook printed to stderr, if any.
+    fn last_stderr_line(&self) -> Option<String> {
+        self.stderr().and_then(|reader| {
+                                   reader
+                                       .lines()
+                                       .filter_map(|line| line.ok())
+                                       .filter(|line| !line.trim().is_empty())
+                                       .last()
+                               })
+    }
+
     fn stream_output<H: Hook>(&mut self, service_group: &ServiceGroup, process: &mut Child) {
         let mut stdout_log =
             File::create(&self.stdout_log_file).expect("couldn't create log output file");
         let mut stderr_log =
             File::create(&self.stderr_log_file).expect("couldn't create log output file");
 
-        let preamble_str = self.stream_preamble::<H>(service_group);
+        let preamble_str = service_group.to_string();
         if let Some(ref mut stdout) = process.stdout {
             for line in BufReader::new(stdout).lines() {
                 if let Some(ref l) = line.ok() {
-                    outputln!(preamble preamble_str, l);
+                    Self::output_line::<H>(&preamble_str, Stream::Stdout, l);
                     stdout_log
                         .write_fmt(format_args!("{}\n", l))
                         .expect("couldn't write line");
@@ -845,7 +1082,7 @@ impl<'a> HookOutput<'a> {
         if let Some(ref mut stderr) = process.stderr {
             for line in BufReader::new(stderr).lines() {
                 if let Some(ref l) = line.ok() {
-                    outputln!(preamble preamble_str, l);
+                    Self::output_line::<H>(&preamble_str, Stream::Stderr, l);
                     stderr_log
                         .write_fmt(format_args!("{}\n", l))
                         .expect("couldn't write line");
@@ -854,8 +1091,11 @@ impl<'a> HookOutput<'a> {
         }
     }
 
-    fn stream_preamble<H: Hook>(&self, service_group: &ServiceGroup) -> String {
-        format!("{} hook[{}]:", service_group, H::file_name())
+    fn output_line<H: Hook>(preamble: &str, stream: Stream, line: &str) {
+        let mut so = StructuredOutput::new(preamble, LOGKEY, line!(), file!(), column!(), line);
+        so.hook = Some(H::file_name());
+        so.stream = Some(stream);
+        println!("{}", so);
     }
 }
 
@@ -866,7 +1106,7 @@ mod tests {
     use std::fs::{self, DirBuilder};
     use tempdir::TempDir;
     use std::process::{Command, Stdio};
-
+    use std::time::Instant;
 
     fn hook_fixtures_path() -> PathBuf {
         PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -920,4 +1160,55 @@ mod tests {
 
         fs::remove_dir_all(tmp_dir).expect("remove temp dir");
     }
+
+    #[test]
+    fn validate_config_hook_rejects_with_last_stderr_line() {
+        let tmp_dir = TempDir::new("habitat_hooks_test").expect("create temp dir");
+        let template = hook_fixtures_path().join(ValidateConfigHook::file_name());
+        let hook = ValidateConfigHook {
+            render_pair: RenderPair::new(tmp_dir.path().join(ValidateConfigHook::file_name()),
+                                         &template)
+                    .expect("couldn't load hook"),
+            stdout_log_path: tmp_dir.path().join("validate-config.stdout.log"),
+            stderr_log_path: tmp_dir.path().join("validate-config.stderr.log"),
+        };
+        let mut cmd = Command::new(&template);
+        cmd.stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let mut child = cmd.spawn().expect("couldn't run hook");
+        let mut hook_output = HookOutput::new(&hook.stdout_log_path, &hook.stderr_log_path);
+        let service_group =
+            ServiceGroup::new("dummy", "service", None).expect("couldn't create ServiceGroup");
+
+        hook_output.stream_output::<ValidateConfigHook>(&service_group, &mut child);
+        let status = child.wait().expect("couldn't wait for hook");
+
+        assert_eq!(hook.handle_exit(&service_group, &hook_output, &status),
+                   ConfigValidation::Rejected("port must be above 1024".to_string()));
+    }
+
+    #[test]
+    fn hook_is_killed_on_timeout() {
+        let mut child = Command::new("sleep")
+            .arg("30")
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("couldn't run hook");
+        let service_group =
+            ServiceGroup::new("dummy", "service", None).expect("couldn't create ServiceGroup");
+        let started = Instant::now();
+
+        kill_on_timeout(&service_group,
+                        PreStopHook::file_name(),
+                        &mut child,
+                        Duration::from_millis(100))
+                .expect("couldn't set hook timeout");
+        // Either reaps the killed hook or finds it already reaped by the timeout thread.
+        let _ = child.wait();
+
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
 }
@@ -0,0 +1,300 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-service metrics exported by the HTTP gateway's `/metrics` endpoint. All metrics are
+//! labelled with the service group they belong to, and removed when the service is unloaded.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use hcore::package::PackageIdent;
+use hcore::service::ServiceGroup;
+use prometheus::{CounterVec, GaugeVec};
+use time::Timespec;
+
+use super::HealthCheck;
+use super::supervisor::ProcessState;
+
+lazy_static! {
+    static ref SERVICE_UP: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_service_up",
+            "Whether the process of a service is up (1) or not (0)."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_STATE_CHANGE: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_service_last_state_change_timestamp_seconds",
+            "Time the process of a service last changed state."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_RESTARTS: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_service_restarts_total",
+            "Total number of times the process of a service was restarted."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_HEALTH_CHECK: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_service_health_check",
+            "Last health check result of a service: 0 ok, 1 warning, 2 critical, 3 unknown."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_HOOK_RUNS: CounterVec = register_counter_vec!(
+        opts!(
+            "hab_service_hook_runs_total",
+            "Total number of times a hook of a service was run."),
+        &["service_group", "hook"]).unwrap();
+
+    static ref SERVICE_HOOK_EXIT_CODE: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_service_hook_exit_code",
+            "Exit code of the last run of a hook of a service, -1 if it had none."),
+        &["service_group", "hook"]).unwrap();
+
+    /// The hooks run by each service group, whose series are removed when the service is
+    /// unloaded.
+    static ref SERVICE_HOOKS: Mutex<HashMap<String, HashSet<String>>> =
+        Mutex::new(HashMap::new());
+
+    static ref SERVICE_PACKAGE_INFO: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_service_package_info",
+            "Package currently running for a service, in its labels; always 1."),
+        &["service_group", "origin", "name", "version", "release"]).unwrap();
+
+    /// The package last exported for each service group, whose series is removed when the
+    /// package changes.
+    static ref SERVICE_PACKAGES: Mutex<HashMap<String, PackageIdent>> =
+        Mutex::new(HashMap::new());
+
+    static ref SERVICE_CONFIG_INCARNATION: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_service_config_incarnation",
+            "Incarnation of the gossiped configuration applied to a service."),
+        &["service_group"]).unwrap();
+
+    static ref SERVICE_FILE_INCARNATION: GaugeVec = register_gauge_vec!(
+        opts!(
+            "hab_service_file_incarnation",
+            "Highest incarnation of the gossiped files applied to a service."),
+        &["service_group"]).unwrap();
+}
+
+pub fn process_state_changed(service_group: &ServiceGroup,
+                             state: &ProcessState,
+                             entered: Timespec) {
+    let labels = service_labels(service_group);
+    let up = if *state == ProcessState::Up { 1.0 } else { 0.0 };
+    SERVICE_UP.with(&labels).set(up);
+    SERVICE_STATE_CHANGE
+        .with(&labels)
+        .set(entered.sec as f64);
+}
+
+pub fn process_restarted(service_group: &ServiceGroup) {
+    SERVICE_RESTARTS
+        .with(&service_labels(service_group))
+        .inc();
+}
+
+pub fn health_check(service_group: &ServiceGroup, health_check: HealthCheck) {
+    let value = match health_check {
+        HealthCheck::Ok => 0.0,
+        HealthCheck::Warning => 1.0,
+        HealthCheck::Critical => 2.0,
+        HealthCheck::Unknown => 3.0,
+    };
+    SERVICE_HEALTH_CHECK
+        .with(&service_labels(service_group))
+        .set(value);
+}
+
+pub fn hook_exited(service_group: &ServiceGroup, hook: &str, exit_code: Option<i32>) {
+    SERVICE_HOOKS
+        .lock()
+        .expect("Service hooks lock is poisoned")
+        .entry(service_group.to_string())
+        .or_insert_with(HashSet::new)
+        .insert(hook.to_string());
+    let mut labels = service_labels(service_group);
+    labels.insert("hook", hook);
+    SERVICE_HOOK_RUNS.with(&labels).inc();
+    SERVICE_HOOK_EXIT_CODE
+        .with(&labels)
+        .set(exit_code.unwrap_or(-1) as f64);
+}
+
+pub fn package(service_group: &ServiceGroup, ident: &PackageIdent) {
+    let mut packages = SERVICE_PACKAGES
+        .lock()
+        .expect("Service packages lock is poisoned");
+    if let Some(previous) = packages.insert(service_group.to_string(), ident.clone()) {
+        if previous != *ident {
+            let _ = SERVICE_PACKAGE_INFO.remove(&package_labels(service_group, &previous));
+        }
+    }
+    SERVICE_PACKAGE_INFO
+        .with(&package_labels(service_group, ident))
+        .set(1.0);
+}
+
+pub fn config_incarnation(service_group: &ServiceGroup, incarnation: u64) {
+    SERVICE_CONFIG_INCARNATION
+        .with(&service_labels(service_group))
+        .set(incarnation as f64);
+}
+
+pub fn file_incarnation(service_group: &ServiceGroup, incarnation: u64) {
+    SERVICE_FILE_INCARNATION
+        .with(&service_labels(service_group))
+        .set(incarnation as f64);
+}
+
+/// Removes every series of an unloaded service group, so it is no longer reported.
+pub fn remove(service_group: &ServiceGroup) {
+    let labels = service_labels(service_group);
+    let _ = SERVICE_UP.remove(&labels);
+    let _ = SERVICE_STATE_CHANGE.remove(&labels);
+    let _ = SERVICE_RESTARTS.remove(&labels);
+    let _ = SERVICE_HEALTH_CHECK.remove(&labels);
+    let _ = SERVICE_CONFIG_INCARNATION.remove(&labels);
+    let _ = SERVICE_FILE_INCARNATION.remove(&labels);
+    let hooks = SERVICE_HOOKS
+        .lock()
+        .expect("Service hooks lock is poisoned")
+        .remove(service_group.as_ref());
+    for hook in hooks.into_iter().flat_map(|hooks| hooks.into_iter()) {
+        let mut labels = service_labels(service_group);
+        labels.insert("hook", &hook);
+        let _ = SERVICE_HOOK_RUNS.remove(&labels);
+        let _ = SERVICE_HOOK_EXIT_CODE.remove(&labels);
+    }
+    let package = SERVICE_PACKAGES
+        .lock()
+        .expect("Service packages lock is poisoned")
+        .remove(service_group.as_ref());
+    if let Some(ident) = package {
+        let _ = SERVICE_PACKAGE_INFO.remove(&package_labels(service_group, &ident));
+    }
+}
+
+fn service_labels(service_group: &ServiceGroup) -> HashMap<&str, &str> {
+    let mut labels = HashMap::new();
+    labels.insert("service_group", service_group.as_ref());
+    labels
+}
+
+fn package_labels<'a>(service_group: &'a ServiceGroup,
+                      ident: &'a PackageIdent)
+                      -> HashMap<&'a str, &'a str> {
+    let mut labels = service_labels(service_group);
+    labels.insert("origin", &ident.origin);
+    labels.insert("name", &ident.name);
+    labels.insert("version", ident.version.as_ref().map_or("", |v| v.as_str()));
+    labels.insert("release", ident.release.as_ref().map_or("", |r| r.as_str()));
+    labels
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+    use hcore::service::ServiceGroup;
+    use prometheus;
+
+    use super::*;
+
+    /// Returns the labels and value of every series of the given metric for a service group.
+    fn series(name: &str, service_group: &str) -> Vec<(HashMap<String, String>, f64)> {
+        let mut series = Vec::new();
+        for family in prometheus::gather() {
+            if family.get_name() != name {
+                continue;
+            }
+            for metric in family.get_metric() {
+                let labels: HashMap<String, String> = metric
+                    .get_label()
+                    .iter()
+                    .map(|l| (l.get_name().to_string(), l.get_value().to_string()))
+                    .collect();
+                if labels.get("service_group").map(|s| s.as_str()) == Some(service_group) {
+                    series.push((labels, metric.get_gauge().get_value()));
+                }
+            }
+        }
+        series
+    }
+
+    #[test]
+    fn package_info_is_labelled_with_the_package() {
+        let sg = ServiceGroup::from_str("redis.metrics-info").unwrap();
+        let ident = PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap();
+        package(&sg, &ident);
+
+        let series = series("hab_service_package_info", "redis.metrics-info");
+        assert_eq!(series.len(), 1);
+        let (ref labels, value) = series[0];
+        assert_eq!(value, 1.0);
+        assert_eq!(labels["origin"], "core");
+        assert_eq!(labels["name"], "redis");
+        assert_eq!(labels["version"], "3.2.4");
+        assert_eq!(labels["release"], "20170514150022");
+    }
+
+    #[test]
+    fn package_info_replaces_the_previous_package() {
+        let sg = ServiceGroup::from_str("redis.metrics-update").unwrap();
+        package(&sg,
+                &PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap());
+        package(&sg,
+                &PackageIdent::from_str("core/redis/3.2.5/20170601120000").unwrap());
+
+        let series = series("hab_service_package_info", "redis.metrics-update");
+        assert_eq!(series.len(), 1);
+        assert_eq!(series[0].0["release"], "20170601120000");
+    }
+
+    #[test]
+    fn remove_drops_every_series_of_the_service_group() {
+        let sg = ServiceGroup::from_str("redis.metrics-remove").unwrap();
+        process_state_changed(&sg, &ProcessState::Up, Timespec::new(1, 0));
+        process_restarted(&sg);
+        health_check(&sg, HealthCheck::Ok);
+        hook_exited(&sg, "init", Some(0));
+        package(&sg,
+                &PackageIdent::from_str("core/redis/3.2.4/20170514150022").unwrap());
+        config_incarnation(&sg, 1);
+        file_incarnation(&sg, 1);
+        let names = ["hab_service_up",
+                     "hab_service_last_state_change_timestamp_seconds",
+                     "hab_service_restarts_total",
+                     "hab_service_health_check",
+                     "hab_service_hook_runs_total",
+                     "hab_service_hook_exit_code",
+                     "hab_service_package_info",
+                     "hab_service_config_incarnation",
+                     "hab_service_file_incarnation"];
+        for name in names.iter() {
+            assert_eq!(series(name, "redis.metrics-remove").len(), 1, "{}", name);
+        }
+
+        remove(&sg);
+        for name in names.iter() {
+            assert!(series(name, "redis.metrics-remove").is_empty(), "{}", name);
+        }
+    }
+}
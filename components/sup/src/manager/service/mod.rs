@@ -13,12 +13,20 @@
 // limitations under the License.
 
 pub mod hooks;
+mod cgroups;
 mod config;
+mod config_history;
+mod dependency;
 mod exec;
 mod health;
+pub mod metrics;
 mod package;
+mod probe;
 mod spec;
 mod supervisor;
+mod task;
+mod update_window;
+mod version_constraint;
 
 use std;
 use std::fmt;
@@ -28,7 +36,7 @@ use std::io::prelude::*;
 use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 
 use ansi_term::Colour::{Yellow, Red, Green};
@@ -38,30 +46,47 @@ use hcore::crypto::hash;
 use hcore::os::process;
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
-use hcore::util::deserialize_using_from_str;
+use hcore::util::{deserialize_using_from_str, serialize_using_to_string};
 use hcore::util::perm::{set_owner, set_permissions};
 use serde;
-use time::Timespec;
+use time::{self, Timespec};
 
 use super::Sys;
 use self::config::CfgRenderer;
-use self::hooks::{HOOK_PERMISSIONS, Hook, HookTable};
+use self::hooks::{ConfigValidation, HOOK_PERMISSIONS, Hook, HookTable};
 use self::supervisor::Supervisor;
 use error::{Error, Result, SupError};
 use fs;
 use manager;
-use census::{ServiceFile, CensusRing, ElectionStatus};
+use manager::events::{self, Event};
+use census::{ServiceFile, ServiceSecret, CensusRing, ElectionStatus};
 use templating::RenderContext;
 use util;
 
+pub use self::cgroups::ResourceLimits;
 pub use self::config::Cfg;
+pub use self::config_history::{ConfigHistory, ConfigHistoryEntry};
+pub use self::dependency::{stop_order, LocalServices};
 pub use self::health::{HealthCheck, SmokeCheck};
 pub use self::package::Pkg;
-pub use self::spec::{DesiredState, ServiceBind, ServiceSpec, StartStyle};
-pub use self::supervisor::ProcessState;
+pub use self::probe::{HealthProbe, ProbeKind};
+pub use self::spec::{CanarySize, DesiredState, RestartPolicy, ServiceBind, ServiceSpec,
+                     StartStyle, SPEC_FILE_EXT};
+pub use self::supervisor::{ProcessState, RestartConfig, StopConfig};
+pub use self::task::{TaskSchedule, TaskStatus};
+pub use self::update_window::{UpdateTimezone, UpdateWindow};
+pub use self::version_constraint::VersionConstraint;
 
 static LOGKEY: &'static str = "SR";
 
+/// A gossiped configuration the service's `validate-config` hook rejected, which is therefore not
+/// applied.
+#[derive(Clone, Debug, Serialize)]
+pub struct ConfigRejection {
+    pub incarnation: u64,
+    pub reason: String,
+}
+
 lazy_static! {
     static ref HEALTH_CHECK_INTERVAL: Duration = {
         Duration::from_millis(30_000)
@@ -77,13 +102,35 @@ pub struct Service {
     pub spec_ident: PackageIdent,
     pub start_style: StartStyle,
     pub topology: Topology,
+    /// Cron schedule of a task service, which runs once if not set.
+    task_schedule: Option<TaskSchedule>,
+    /// Whether a task service only runs on the leader of its service group.
+    task_leader_only: bool,
+    /// The runs of a task service, not set for other topologies.
+    task: Option<TaskStatus>,
     pub update_strategy: UpdateStrategy,
+    #[serde(serialize_with = "serialize_using_to_string")]
+    pub canary_size: CanarySize,
+    pub canary_soak_period: u64,
+    pub rollback_window: Option<u64>,
+    pub update_windows: Vec<UpdateWindow>,
+    pub update_timezone: UpdateTimezone,
+    /// Release found by the updater outside of the service's update windows, swapped in once the
+    /// next window opens.
+    pub pending_update: Option<PackageIdent>,
+    pub update_constraint: Option<VersionConstraint>,
+    /// Newest release in the service's channel which is excluded by its update constraint, if it
+    /// is newer than the running release. Set by the updater's worker thread.
+    pub excluded_update: Arc<RwLock<Option<PackageIdent>>>,
     pub cfg: Cfg,
     pub pkg: Pkg,
     pub sys: Arc<Sys>,
 
     #[serde(skip_serializing)]
     config_renderer: CfgRenderer,
+    #[serde(skip_serializing)]
+    config_history: ConfigHistory,
+    config_rejection: Option<ConfigRejection>,
     health_check: HealthCheck,
     initialized: bool,
     last_election_status: ElectionStatus,
@@ -91,8 +138,30 @@ pub struct Service {
     needs_reconfiguration: bool,
     smoke_check: SmokeCheck,
     binds: Vec<ServiceBind>,
+    requires: Vec<String>,
+    after: Vec<String>,
+    /// Local services the service waited for to start when last ticked.
+    #[serde(skip_serializing)]
+    waiting_for: Vec<String>,
     hooks: HookTable,
     config_from: Option<PathBuf>,
+    strict_templates: bool,
+    /// Whether the Supervisor renders the templates of all of its services strictly.
+    #[serde(skip_serializing)]
+    sup_strict_templates: bool,
+    health_probe: Option<HealthProbe>,
+    #[serde(skip_serializing)]
+    health_probe_failures: u32,
+    #[serde(serialize_with = "serialize_using_to_string")]
+    restart_policy: RestartPolicy,
+    restart_max_retries: Option<u32>,
+    restart_backoff_base: u64,
+    restart_backoff_ceiling: u64,
+    #[serde(serialize_with = "serialize_using_to_string")]
+    stop_signal: process::Signal,
+    stop_timeout: u64,
+    #[serde(skip_serializing)]
+    resource_limits: Option<ResourceLimits>,
     #[serde(skip_serializing)]
     last_health_check: Instant,
     manager_fs_cfg: Arc<manager::FsCfg>,
@@ -105,7 +174,8 @@ impl Service {
            package: PackageInstall,
            spec: ServiceSpec,
            manager_fs_cfg: Arc<manager::FsCfg>,
-           organization: Option<&str>)
+           organization: Option<&str>,
+           sup_strict_templates: bool)
            -> Result<Service> {
         spec.validate(&package)?;
         let pkg = Pkg::from_install(package)?;
@@ -113,10 +183,27 @@ impl Service {
         let service_group = ServiceGroup::new(&pkg.name, spec.group, organization)?;
         let config_root = Self::config_root(&pkg, spec.config_from.as_ref());
         let hooks_root = Self::hooks_root(&pkg, spec.config_from.as_ref());
+        let cfg = Cfg::new(&pkg, spec.config_from.as_ref())?;
+        let resource_limits = match spec.resource_limits {
+            Some(ref limits) => Some(limits.clone()),
+            None => Self::default_resource_limits(&service_group, &cfg),
+        };
+        let restart_config = RestartConfig {
+            policy: spec.restart_policy,
+            max_retries: spec.restart_max_retries,
+            backoff_base: Duration::from_secs(spec.restart_backoff_base),
+            backoff_ceiling: Duration::from_secs(spec.restart_backoff_ceiling),
+        };
+        let stop_config = StopConfig {
+            signal: spec.stop_signal,
+            timeout: Duration::from_secs(spec.stop_timeout),
+        };
         Ok(Service {
                sys: sys,
-               cfg: Cfg::new(&pkg, spec.config_from.as_ref())?,
+               cfg: cfg,
                config_renderer: CfgRenderer::new(&config_root)?,
+               config_history: ConfigHistory::load(manager_fs_cfg.config_history(&service_group)),
+               config_rejection: None,
                depot_url: spec.depot_url,
                channel: spec.channel,
                health_check: HealthCheck::default(),
@@ -126,21 +213,77 @@ impl Service {
                needs_reload: false,
                needs_reconfiguration: false,
                manager_fs_cfg: manager_fs_cfg,
-               supervisor: Supervisor::new(&service_group),
+               supervisor: Supervisor::new(&service_group,
+                                           restart_config,
+                                           stop_config,
+                                           resource_limits),
                pkg: pkg,
                service_group: service_group,
                smoke_check: SmokeCheck::default(),
                binds: spec.binds,
+               requires: spec.requires,
+               after: spec.after,
+               waiting_for: Vec::new(),
                spec_ident: spec.ident,
                spec_file: spec_file,
                start_style: spec.start_style,
                topology: spec.topology,
+               task_schedule: spec.task_schedule,
+               task_leader_only: spec.task_leader_only,
+               task: None,
                update_strategy: spec.update_strategy,
+               canary_size: spec.canary_size,
+               canary_soak_period: spec.canary_soak_period,
+               rollback_window: spec.rollback_window,
+               update_windows: spec.update_windows,
+               update_timezone: spec.update_timezone,
+               pending_update: None,
+               update_constraint: spec.update_constraint,
+               excluded_update: Arc::new(RwLock::new(None)),
                config_from: spec.config_from,
+               strict_templates: spec.strict_templates,
+               sup_strict_templates: sup_strict_templates,
+               health_probe: spec.health_probe,
+               health_probe_failures: 0,
+               restart_policy: spec.restart_policy,
+               restart_max_retries: spec.restart_max_retries,
+               restart_backoff_base: spec.restart_backoff_base,
+               restart_backoff_ceiling: spec.restart_backoff_ceiling,
+               stop_signal: spec.stop_signal,
+               stop_timeout: spec.stop_timeout,
+               resource_limits: spec.resource_limits,
                last_health_check: Instant::now() - *HEALTH_CHECK_INTERVAL,
            })
     }
 
+    /// Returns the resource limits declared under `[resource_limits]` in the package's
+    /// `default.toml`, if any.
+    fn default_resource_limits(service_group: &ServiceGroup, cfg: &Cfg) -> Option<ResourceLimits> {
+        let value = match cfg.default.as_ref().and_then(|d| d.get("resource_limits")) {
+            Some(value) => value.clone(),
+            None => return None,
+        };
+        match value.try_into::<ResourceLimits>() {
+            Ok(limits) => {
+                match limits.validate() {
+                    Ok(_) => Some(limits),
+                    Err(err) => {
+                        outputln!(preamble service_group,
+                                  "Ignoring resource limits from default.toml, {}",
+                                  err);
+                        None
+                    }
+                }
+            }
+            Err(err) => {
+                outputln!(preamble service_group,
+                          "Ignoring resource limits from default.toml, {}",
+                          err);
+                None
+            }
+        }
+    }
+
     /// Returns the config root given the package and optional config-from path.
     fn config_root(package: &Pkg, config_from: Option<&PathBuf>) -> PathBuf {
         config_from
@@ -160,10 +303,16 @@ impl Service {
     pub fn load(sys: Arc<Sys>,
                 spec: ServiceSpec,
                 manager_fs_cfg: Arc<manager::FsCfg>,
-                organization: Option<&str>)
+                organization: Option<&str>,
+                sup_strict_templates: bool)
                 -> Result<Service> {
         let package = util::pkg::install_from_spec(&mut UI::default(), &spec)?;
-        Ok(Self::new(sys, package, spec, manager_fs_cfg, organization)?)
+        Ok(Self::new(sys,
+                     package,
+                     spec,
+                     manager_fs_cfg,
+                     organization,
+                     sup_strict_templates)?)
     }
 
     /// Create the service path for this package.
@@ -194,6 +343,11 @@ impl Service {
                   &self.pkg.svc_user,
                   &self.pkg.svc_group)?;
         set_permissions(&self.pkg.svc_files_path, 0o700)?;
+        Self::create_dir_all(&self.pkg.svc_secrets_path)?;
+        set_owner(&self.pkg.svc_secrets_path,
+                  &self.pkg.svc_user,
+                  &self.pkg.svc_group)?;
+        set_permissions(&self.pkg.svc_secrets_path, 0o700)?;
         Self::create_dir_all(&self.pkg.svc_var_path)?;
         set_owner(&self.pkg.svc_var_path,
                   &self.pkg.svc_user,
@@ -218,14 +372,32 @@ impl Service {
     }
 
     pub fn stop(&mut self) {
+        self.pre_stop();
         if let Err(err) = self.supervisor.stop() {
             outputln!(preamble self.service_group, "Service stop failed: {}", err);
         }
     }
 
+    /// Stop and start the service's process, regardless of the presence of a reload hook. This
+    /// also clears the count of automatic restarts in a row.
+    pub fn restart(&mut self) {
+        self.supervisor.restart_count = 0;
+        self.pre_stop();
+        if let Some(err) = self.supervisor.restart(&self.pkg).err() {
+            outputln!(preamble self.service_group, "Service restart failed: {}", err);
+        }
+    }
+
     fn reload(&mut self) {
         self.needs_reload = false;
-        if self.is_down() || self.hooks.reload.is_none() {
+        if self.is_down() {
+            if self.supervisor.should_restart() {
+                if let Some(err) = self.supervisor.restart(&self.pkg).err() {
+                    outputln!(preamble self.service_group, "Service restart failed: {}", err);
+                }
+            }
+        } else if self.hooks.reload.is_none() {
+            self.pre_stop();
             if let Some(err) = self.supervisor.restart(&self.pkg).err() {
                 outputln!(preamble self.service_group, "Service restart failed: {}", err);
             }
@@ -236,6 +408,7 @@ impl Service {
     }
 
     pub fn down(&mut self) -> Result<()> {
+        self.pre_stop();
         self.supervisor.down()
     }
 
@@ -264,18 +437,42 @@ impl Service {
         self.supervisor.state_entered
     }
 
-    pub fn tick(&mut self, census_ring: &CensusRing) -> bool {
+    /// Returns the result of the service's last health check.
+    pub fn health_check(&self) -> HealthCheck {
+        self.health_check
+    }
+
+    /// Whether the running release looks broken: its process exited with a non-zero code, is
+    /// crash looping, or its health check is critical. A process which exited cleanly, such as a
+    /// task between runs, doesn't count.
+    pub fn release_failing(&self) -> bool {
+        self.supervisor.failing() || self.health_check == HealthCheck::Critical
+    }
+
+    /// Whether a new release may be swapped in now, according to the service's update windows.
+    pub fn in_update_window(&self) -> bool {
+        update_window::is_open(&self.update_windows, &self.update_timezone.now())
+    }
+
+    pub fn tick(&mut self, census_ring: &CensusRing, local_services: &LocalServices) -> bool {
         if !self.initialized {
             if !self.all_binds_satisfied(census_ring) {
                 outputln!(preamble self.service_group, "Waiting for service binds...");
                 return false;
             }
+            if !self.dependencies_satisfied(local_services) {
+                return false;
+            }
         }
 
+        let health_check = self.health_check;
         let svc_updated = self.update_templates(census_ring);
         if self.update_service_files(census_ring) {
             self.file_updated();
         }
+        if self.update_secrets(census_ring) {
+            self.file_updated();
+        }
 
         match self.topology {
             Topology::Standalone => {
@@ -326,8 +523,62 @@ impl Service {
                     }
                 }
             }
+            Topology::Task => {
+                let may_run = !self.task_leader_only || self.is_task_leader(census_ring);
+                self.execute_task(may_run);
+            }
         }
-        svc_updated
+        // The health check is gossiped, canary updates follow it.
+        svc_updated || self.health_check != health_check
+    }
+
+    /// Whether the Supervisor runs an election for the service group, which it does for the
+    /// leader topology and for tasks which only run on the leader.
+    pub fn elects_leader(&self) -> bool {
+        match self.topology {
+            Topology::Leader => true,
+            Topology::Task => self.task_leader_only,
+            Topology::Standalone => false,
+        }
+    }
+
+    /// Whether this member is the elected leader of the service group of a task which only runs
+    /// on the leader. Changes of the election status are logged.
+    fn is_task_leader(&mut self, census_ring: &CensusRing) -> bool {
+        let census_group = census_ring
+            .census_group_for(&self.service_group)
+            .expect("Service Group's census entry missing from list!");
+        let leader = match (census_group.me(), census_group.leader()) {
+            (Some(me), Some(leader)) => me.member_id == leader.member_id,
+            _ => false,
+        };
+        let finished = census_group.election_status == ElectionStatus::ElectionFinished;
+        if self.last_election_status != census_group.election_status {
+            if !finished {
+                outputln!(preamble self.service_group,
+                          "Waiting to run task; {}",
+                          Yellow.bold().paint("election hasn't finished"));
+            } else if leader {
+                outputln!(preamble self.service_group,
+                          "Running task; {}",
+                          Green.bold().paint("this member is the leader"));
+            } else {
+                outputln!(preamble self.service_group,
+                          "Not running task; {} is the leader",
+                          census_group.leader_id.as_ref().map_or("", |id| id.as_str()));
+            }
+            self.last_election_status = census_group.election_status;
+        }
+        finished && leader
+    }
+
+    /// Names of the local services the service requires or is started after.
+    pub fn dependencies(&self) -> Vec<String> {
+        self.requires
+            .iter()
+            .chain(self.after.iter())
+            .cloned()
+            .collect()
     }
 
     pub fn to_spec(&self) -> ServiceSpec {
@@ -336,10 +587,29 @@ impl Service {
         spec.depot_url = self.depot_url.clone();
         spec.channel = self.channel.clone();
         spec.topology = self.topology;
+        spec.task_schedule = self.task_schedule.clone();
+        spec.task_leader_only = self.task_leader_only;
         spec.update_strategy = self.update_strategy;
+        spec.canary_size = self.canary_size;
+        spec.canary_soak_period = self.canary_soak_period;
+        spec.rollback_window = self.rollback_window;
+        spec.update_windows = self.update_windows.clone();
+        spec.update_timezone = self.update_timezone;
+        spec.update_constraint = self.update_constraint.clone();
         spec.binds = self.binds.clone();
+        spec.requires = self.requires.clone();
+        spec.after = self.after.clone();
         spec.start_style = self.start_style;
         spec.config_from = self.config_from.clone();
+        spec.strict_templates = self.strict_templates;
+        spec.restart_policy = self.restart_policy;
+        spec.restart_max_retries = self.restart_max_retries;
+        spec.restart_backoff_base = self.restart_backoff_base;
+        spec.restart_backoff_ceiling = self.restart_backoff_ceiling;
+        spec.stop_signal = self.stop_signal;
+        spec.stop_timeout = self.stop_timeout;
+        spec.resource_limits = self.resource_limits.clone();
+        spec.health_probe = self.health_probe.clone();
         spec
     }
 
@@ -360,6 +630,19 @@ impl Service {
         ret
     }
 
+    /// Whether all local services the service requires are up and healthy and all loaded local
+    /// services it is started after are up.
+    fn dependencies_satisfied(&mut self, local_services: &LocalServices) -> bool {
+        let waiting_for = local_services.waiting_for(&self.requires, &self.after);
+        if !waiting_for.is_empty() && waiting_for != self.waiting_for {
+            outputln!(preamble self.service_group,
+                      "Waiting for local services: {}",
+                      waiting_for.join(", "));
+        }
+        self.waiting_for = waiting_for;
+        self.waiting_for.is_empty()
+    }
+
     /// Compares the current state of the service to the current state of the census ring and
     /// re-renders all templatable content to disk.
     ///
@@ -369,7 +652,22 @@ impl Service {
             .census_group_for(&self.service_group)
             .expect("Service update failed; unable to find own service group");
 
-        let cfg_updated = self.cfg.update(census_group);
+        let cfg_updated = if self.hooks.validate_config.is_some() {
+            self.update_cfg_if_valid(census_ring)
+        } else {
+            self.cfg.update(census_group)
+        };
+        if cfg_updated {
+            metrics::config_incarnation(&self.service_group, self.cfg.gossip_incarnation());
+            if let Some(ref gossip) = self.cfg.gossip {
+                if let Err(e) = self.config_history
+                       .record(self.cfg.gossip_incarnation(), gossip) {
+                    outputln!(preamble self.service_group,
+                              "Failed to record configuration history: {}",
+                              e);
+                }
+            }
+        }
         if cfg_updated || census_ring.changed {
             self.needs_reconfiguration = {
                 let ctx = self.render_context(census_ring);
@@ -380,6 +678,66 @@ impl Service {
         cfg_updated
     }
 
+    /// Applies the census group's service config only if the `validate-config` hook accepts the
+    /// configuration merged with it. A rejected incarnation isn't validated again.
+    ///
+    /// Returns true if the configuration was updated.
+    fn update_cfg_if_valid(&mut self, census_ring: &CensusRing) -> bool {
+        let census_group = census_ring
+            .census_group_for(&self.service_group)
+            .expect("Service update failed; unable to find own service group");
+        let incarnation = match census_group.service_config {
+            Some(ref config) => config.incarnation,
+            None => return false,
+        };
+        if incarnation <= self.cfg.gossip_incarnation() ||
+           self.config_rejection
+               .as_ref()
+               .map_or(false, |rejection| rejection.incarnation == incarnation) {
+            return false;
+        }
+        let mut cfg = self.cfg.clone();
+        cfg.update(census_group);
+        match self.validate_config(&cfg, census_ring) {
+            ConfigValidation::Valid => {
+                self.cfg = cfg;
+                self.config_rejection = None;
+                true
+            }
+            ConfigValidation::Rejected(reason) => {
+                outputln!(preamble self.service_group,
+                          "Configuration incarnation {} rejected, not applying it: {}",
+                          incarnation,
+                          reason);
+                self.config_rejection = Some(ConfigRejection {
+                                                 incarnation: incarnation,
+                                                 reason: reason,
+                                             });
+                false
+            }
+        }
+    }
+
+    /// Compiles the `validate-config` hook against the given configuration and runs it.
+    fn validate_config(&self, cfg: &Cfg, census_ring: &CensusRing) -> ConfigValidation {
+        let hook = match self.hooks.validate_config {
+            Some(ref hook) => hook,
+            None => return ConfigValidation::Valid,
+        };
+        let ctx = RenderContext::new(&self.service_group,
+                                     &self.sys,
+                                     &self.pkg,
+                                     cfg,
+                                     census_ring,
+                                     self.binds.iter());
+        if let Err(e) = hook.compile(&ctx, self.strict_templates()) {
+            return ConfigValidation::Rejected(format!("Failed to compile {} hook: {}",
+                                                      hooks::ValidateConfigHook::file_name(),
+                                                      e));
+        }
+        hook.run(&self.service_group, &self.pkg)
+    }
+
     /// Replace the package of the running service and restart it's system process.
     pub fn update_package(&mut self, package: PackageInstall) {
         match Pkg::from_install(package) {
@@ -394,6 +752,8 @@ impl Service {
                         return;
                     }
                 }
+                // The running release is drained with its own hook before the new one is loaded.
+                self.pre_stop();
                 self.hooks = HookTable::load(&self.service_group,
                                              &Self::hooks_root(&pkg, self.config_from.as_ref()));
                 self.pkg = pkg;
@@ -427,6 +787,7 @@ impl Service {
                                           &self.sys.as_sys_info(),
                                           exported.as_ref());
         rumor.set_incarnation(incarnation);
+        rumor.set_health_check(self.health_check.to_string());
         rumor
     }
 
@@ -457,6 +818,20 @@ impl Service {
         }
     }
 
+    /// Runs the `pre-stop` hook, if the service has one, before its running process is stopped so
+    /// it can drain connections. The hook is killed if it runs longer than the stop timeout. A
+    /// failing hook doesn't prevent the process from being stopped.
+    fn pre_stop(&self) {
+        if self.is_down() {
+            return;
+        }
+        if let Some(ref hook) = self.hooks.pre_stop {
+            hook.run_with_timeout(&self.service_group,
+                                  &self.pkg,
+                                  Some(Duration::from_secs(self.stop_timeout)));
+        }
+    }
+
     pub fn suitability(&self) -> Option<u64> {
         if !self.initialized {
             return None;
@@ -506,9 +881,16 @@ impl Service {
         }
     }
 
+    /// Whether templates referring to variables the render context doesn't define fail to
+    /// render, set for the service or for the whole Supervisor.
+    fn strict_templates(&self) -> bool {
+        self.strict_templates || self.sup_strict_templates
+    }
+
     /// Helper for compiling configuration templates into configuration files.
     fn compile_configuration(&self, ctx: &RenderContext) -> bool {
-        match self.config_renderer.compile(&self.pkg, ctx) {
+        match self.config_renderer
+                  .compile(&self.pkg, ctx, self.strict_templates()) {
             Ok(true) => {
                 outputln!(preamble self.service_group, "Configuration recompiled");
                 true
@@ -527,20 +909,23 @@ impl Service {
     ///
     /// This function will also perform any necessary post-compilation tasks.
     fn compile_hooks(&self, ctx: &RenderContext) {
-        self.hooks.compile(&self.service_group, ctx);
+        self.hooks
+            .compile(&self.service_group, ctx, self.strict_templates());
         outputln!(preamble self.service_group, "Hooks recompiled");
-        if let Some(err) = self.copy_run().err() {
+        if let Some(err) = self.copy_run(hooks::permissions(ctx)).err() {
             outputln!(preamble self.service_group, "Failed to copy run hook: {}", err);
         }
     }
 
-    // Copy the "run" file to the svc path.
-    fn copy_run(&self) -> Result<()> {
+    // Copy the "run" file to the svc path, with the permissions of the compiled hooks.
+    fn copy_run(&self, permissions: u32) -> Result<()> {
         let svc_run = self.pkg.svc_path.join(hooks::RunHook::file_name());
         match self.hooks.run {
             Some(ref hook) => {
-                try!(std::fs::copy(hook.path(), &svc_run));
-                try!(set_permissions(&svc_run.to_str().unwrap(), HOOK_PERMISSIONS));
+                let mut run = Vec::new();
+                try!(File::open(hook.path()).and_then(|mut file| file.read_to_end(&mut run)));
+                let owner = (self.pkg.svc_user.as_str(), self.pkg.svc_group.as_str());
+                try!(fs::write_private(&svc_run, &run, permissions, Some(owner)));
             }
             None => {
                 let run = self.pkg.path.join(hooks::RunHook::file_name());
@@ -567,7 +952,8 @@ impl Service {
             }
         } else {
             self.check_process();
-            if Instant::now().duration_since(self.last_health_check) >= *HEALTH_CHECK_INTERVAL {
+            if Instant::now().duration_since(self.last_health_check) >=
+               self.health_check_interval() {
                 self.run_health_check_hook();
             }
 
@@ -580,6 +966,57 @@ impl Service {
         }
     }
 
+    /// Starts the process of a task service when its next run is due and records how its runs
+    /// went. A run which is going is left to finish even if the task may no longer run, but no
+    /// new run is started.
+    fn execute_task(&mut self, may_run: bool) {
+        if !self.initialized {
+            self.initialize();
+            if !self.initialized {
+                return;
+            }
+            let next_run = match self.task_schedule {
+                Some(ref schedule) => schedule.next_after(time::get_time()).map(|t| t.sec),
+                None => Some(time::get_time().sec),
+            };
+            self.task = Some(TaskStatus {
+                                 next_run: next_run,
+                                 ..TaskStatus::default()
+                             });
+        }
+        self.check_process();
+        let mut status = self.task.take().unwrap_or_default();
+        if status.running && self.is_down() {
+            status.running = false;
+            status.last_exit_code = self.supervisor.last_exit_code();
+            match status.last_exit_code {
+                Some(code) => {
+                    outputln!(preamble self.service_group, "Task finished with exit code {}", code)
+                }
+                None => outputln!(preamble self.service_group, "Task was killed by a signal"),
+            }
+        }
+        let now = time::get_time();
+        if status.next_run.map_or(false, |next_run| next_run <= now.sec) {
+            if status.running {
+                outputln!(preamble self.service_group,
+                          "Skipping task run, the previous run is still going");
+            } else if may_run {
+                outputln!(preamble self.service_group, "Running task");
+                self.start();
+                status.running = !self.is_down();
+                status.last_run = Some(now.sec);
+            }
+            status.next_run = match self.task_schedule {
+                Some(ref schedule) => schedule.next_after(now).map(|t| t.sec),
+                // A task without a schedule waits until it may run, then runs once.
+                None if status.last_run.is_none() => status.next_run,
+                None => None,
+            };
+        }
+        self.task = Some(status);
+    }
+
     /// Run file_updated hook if present
     fn file_updated(&self) -> bool {
         if self.initialized {
@@ -606,6 +1043,32 @@ impl Service {
                 updated = true;
             }
         }
+        if let Some(incarnation) = census_group
+               .changed_service_files()
+               .iter()
+               .map(|f| f.incarnation)
+               .max() {
+            metrics::file_incarnation(&self.service_group, incarnation);
+        }
+        updated
+    }
+
+    /// Write secrets from gossip data to disk, each to a file of the secrets directory only the
+    /// service's user can read.
+    ///
+    /// Returns true if a secret was changed or added, and false if there were no updates.
+    fn update_secrets(&mut self, census_ring: &CensusRing) -> bool {
+        let census_group =
+            census_ring
+                .census_group_for(&self.service_group)
+                .expect("Service update secrets failed; unable to find own service group");
+        let mut updated = false;
+        for secret in census_group.changed_secrets() {
+            if self.cache_secret(&secret) {
+                outputln!(preamble self.service_group, "Secret updated, {}", secret.name);
+                updated = true;
+            }
+        }
         updated
     }
 
@@ -635,8 +1098,39 @@ impl Service {
                            self.binds.iter())
     }
 
+    /// Returns how often the service's health is checked, which is the interval of its health
+    /// probe if one is declared in its spec.
+    fn health_check_interval(&self) -> Duration {
+        match self.health_probe {
+            Some(ref probe) => probe.interval(),
+            None => *HEALTH_CHECK_INTERVAL,
+        }
+    }
+
+    /// Run the health probe declared in the service's spec, falling back to the `health_check`
+    /// hook and then to the state of the process. A failing probe only reports `Critical` once it
+    /// has failed `failure_threshold` times in a row; until then the last result is kept.
     fn run_health_check_hook(&mut self) {
-        let check_result = if let Some(ref hook) = self.hooks.health_check {
+        let check_result = if let Some(ref probe) = self.health_probe {
+            match probe.run(&self.service_group, &self.pkg) {
+                HealthCheck::Critical => {
+                    self.health_probe_failures += 1;
+                    if self.health_probe_failures >= probe.failure_threshold {
+                        HealthCheck::Critical
+                    } else {
+                        outputln!(preamble self.service_group,
+                                  "Health probe failed {} of {} times",
+                                  self.health_probe_failures,
+                                  probe.failure_threshold);
+                        self.health_check
+                    }
+                }
+                result => {
+                    self.health_probe_failures = 0;
+                    result
+                }
+            }
+        } else if let Some(ref hook) = self.hooks.health_check {
             hook.run(&self.service_group, &self.pkg)
         } else {
             match self.supervisor.status() {
@@ -645,15 +1139,33 @@ impl Service {
             }
         };
         self.last_health_check = Instant::now();
+        if check_result != self.health_check {
+            events::publish(Event::health_check_changed(&self.service_group, check_result));
+            self.health_check = check_result;
+        }
+        metrics::health_check(&self.service_group, check_result);
         self.cache_health_check(check_result);
     }
 
     fn cache_service_file(&mut self, service_file: &ServiceFile) -> bool {
         let file = self.pkg.svc_files_path.join(&service_file.filename);
-        self.write_cache_file(file, &service_file.body)
+        self.write_cache_file(file, &service_file.body, 0o640)
     }
 
-    fn write_cache_file<T>(&self, file: T, contents: &[u8]) -> bool
+    fn cache_secret(&mut self, secret: &ServiceSecret) -> bool {
+        // Secret names come from gossip; never let one point outside of the secrets directory.
+        if Path::new(&secret.name).file_name().and_then(|name| name.to_str()) !=
+           Some(secret.name.as_str()) {
+            outputln!(preamble self.service_group,
+                      "Ignoring secret with invalid name {}",
+                      Red.bold().paint(secret.name.as_str()));
+            return false;
+        }
+        let file = self.pkg.svc_secrets_path.join(&secret.name);
+        self.write_cache_file(file, &secret.value, 0o600)
+    }
+
+    fn write_cache_file<T>(&self, file: T, contents: &[u8], permissions: u32) -> bool
         where T: AsRef<Path>
     {
         let current_checksum = match hash::hash_file(&file) {
@@ -669,37 +1181,10 @@ impl Service {
         if new_checksum == current_checksum {
             return false;
         }
-        let new_filename = format!("{}.write", file.as_ref().to_string_lossy());
-        let mut new_file = match File::create(&new_filename) {
-            Ok(new_file) => new_file,
-            Err(e) => {
-                outputln!(preamble self.service_group,
-                          "Failed to create cache file {}",
-                          Red.bold().paint(format!("{}, {}", file.as_ref().display(), e)));
-                return false;
-            }
-        };
-        if let Err(e) = new_file.write_all(contents) {
-            outputln!(preamble self.service_group,
-                      "Failed to write to cache file {}",
-                      Red.bold().paint(format!("{}, {}", file.as_ref().display(), e)));
-            return false;
-        }
-        if let Err(e) = std::fs::rename(&new_filename, &file) {
+        let owner = (self.pkg.svc_user.as_str(), self.pkg.svc_group.as_str());
+        if let Err(e) = fs::write_private(&file, contents, permissions, Some(owner)) {
             outputln!(preamble self.service_group,
-                      "Failed to move cache file {}",
-                      Red.bold().paint(format!("{}, {}", file.as_ref().display(), e)));
-            return false;
-        }
-        if let Err(e) = set_owner(&file, &self.pkg.svc_user, &self.pkg.svc_group) {
-            outputln!(preamble self.service_group,
-                      "Failed to set ownership of cache file {}",
-                      Red.bold().paint(format!("{}, {}", file.as_ref().display(), e)));
-            return false;
-        }
-        if let Err(e) = set_permissions(&file, 0o640) {
-            outputln!(preamble self.service_group,
-                      "Failed to set permissions on cache file {}",
+                      "Failed to write cache file {}",
                       Red.bold().paint(format!("{}, {}", file.as_ref().display(), e)));
             return false;
         }
@@ -717,6 +1202,8 @@ impl fmt::Display for Service {
 pub enum Topology {
     Standalone,
     Leader,
+    /// Runs the service's process to completion, once or on a schedule.
+    Task,
 }
 
 impl Topology {
@@ -724,6 +1211,7 @@ impl Topology {
         match *self {
             Topology::Leader => "leader",
             Topology::Standalone => "standalone",
+            Topology::Task => "task",
         }
     }
 }
@@ -735,6 +1223,7 @@ impl FromStr for Topology {
         match topology {
             "leader" => Ok(Topology::Leader),
             "standalone" => Ok(Topology::Standalone),
+            "task" => Ok(Topology::Task),
             _ => Err(sup_error!(Error::InvalidTopology(String::from(topology)))),
         }
     }
@@ -773,6 +1262,7 @@ pub enum UpdateStrategy {
     None,
     AtOnce,
     Rolling,
+    Canary,
 }
 
 impl UpdateStrategy {
@@ -781,6 +1271,7 @@ impl UpdateStrategy {
             UpdateStrategy::None => "none",
             UpdateStrategy::AtOnce => "at-once",
             UpdateStrategy::Rolling => "rolling",
+            UpdateStrategy::Canary => "canary",
         }
     }
 }
@@ -793,6 +1284,7 @@ impl FromStr for UpdateStrategy {
             "none" => Ok(UpdateStrategy::None),
             "at-once" => Ok(UpdateStrategy::AtOnce),
             "rolling" => Ok(UpdateStrategy::Rolling),
+            "canary" => Ok(UpdateStrategy::Canary),
             _ => Err(sup_error!(Error::InvalidUpdateStrategy(String::from(strategy)))),
         }
     }
@@ -848,6 +1340,7 @@ mod test {
         let topology = Topology::from_str(topology_str).unwrap();
 
         assert_eq!(topology, Topology::Leader);
+        assert_eq!(Topology::from_str("task").unwrap(), Topology::Task);
     }
 
     #[test]
@@ -937,6 +1430,14 @@ mod test {
         assert_eq!("at-once", strategy.to_string())
     }
 
+    #[test]
+    fn update_strategy_canary_from_str() {
+        let strategy = UpdateStrategy::from_str("canary").unwrap();
+
+        assert_eq!(strategy, UpdateStrategy::Canary);
+        assert_eq!("canary", strategy.to_string())
+    }
+
     #[test]
     fn update_strategy_toml_deserialize() {
         #[derive(Deserialize)]
@@ -95,6 +95,7 @@ pub struct Pkg {
     pub svc_config_path: PathBuf,
     pub svc_data_path: PathBuf,
     pub svc_files_path: PathBuf,
+    pub svc_secrets_path: PathBuf,
     pub svc_static_path: PathBuf,
     pub svc_var_path: PathBuf,
     pub svc_pid_file: PathBuf,
@@ -111,6 +112,7 @@ impl Pkg {
             svc_config_path: fs::svc_config_path(&package.ident.name),
             svc_data_path: fs::svc_data_path(&package.ident.name),
             svc_files_path: fs::svc_files_path(&package.ident.name),
+            svc_secrets_path: fs::svc_secrets_path(&package.ident.name),
             svc_run: fs::svc_path(&package.ident.name).join("run"),
             svc_static_path: fs::svc_static_path(&package.ident.name),
             svc_var_path: fs::svc_var_path(&package.ident.name),
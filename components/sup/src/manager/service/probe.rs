@@ -0,0 +1,466 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Declarative health probes.
+//!
+//! A health probe is declared in a service's spec and is an alternative to writing a
+//! `health_check` hook for the common cases of checking an HTTP endpoint, checking that a TCP port
+//! accepts connections, or running a command. Probes produce the same `HealthCheck` values as the
+//! hook does.
+
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::process::Stdio;
+use std::result;
+use std::str::FromStr;
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::Duration;
+
+use hcore::os::process::{self, Pid, Signal};
+use hcore::service::ServiceGroup;
+use hcore::util::deserialize_using_from_str;
+use serde;
+use url::Url;
+
+use super::{exec, HealthCheck, Pkg};
+use error::{Error, Result, SupError};
+
+static LOGKEY: &'static str = "HP";
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ProbeKind {
+    Http,
+    Tcp,
+    Exec,
+}
+
+impl ProbeKind {
+    fn as_str(&self) -> &str {
+        match *self {
+            ProbeKind::Http => "http",
+            ProbeKind::Tcp => "tcp",
+            ProbeKind::Exec => "exec",
+        }
+    }
+}
+
+impl FromStr for ProbeKind {
+    type Err = SupError;
+
+    fn from_str(kind: &str) -> result::Result<Self, Self::Err> {
+        match kind {
+            "http" => Ok(ProbeKind::Http),
+            "tcp" => Ok(ProbeKind::Tcp),
+            "exec" => Ok(ProbeKind::Exec),
+            _ => Err(sup_error!(Error::InvalidProbeKind(String::from(kind)))),
+        }
+    }
+}
+
+impl fmt::Display for ProbeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Default for ProbeKind {
+    fn default() -> ProbeKind {
+        ProbeKind::Http
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ProbeKind {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer<'de>
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for ProbeKind {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// A health probe as declared in a service spec, for example:
+///
+/// ```toml
+/// [health_probe]
+/// kind = "http"
+/// target = "http://localhost:8080/status"
+/// expected_status = 200
+/// interval = 10
+/// timeout = 2
+/// failure_threshold = 3
+/// ```
+///
+/// The `target` is a URL for `http` probes, a `host:port` address for `tcp` probes and a command
+/// followed by whitespace separated arguments for `exec` probes. The command is not run by a
+/// shell and its arguments are split on every whitespace, so quotes are passed on as they are and
+/// no argument can contain a space; a command needing one belongs in a script shipped with the
+/// package. `interval` and `timeout` are in seconds.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default)]
+pub struct HealthProbe {
+    pub kind: ProbeKind,
+    pub target: String,
+    pub expected_status: u16,
+    pub interval: u64,
+    pub timeout: u64,
+    pub failure_threshold: u32,
+}
+
+impl HealthProbe {
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval)
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout)
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.target.trim().is_empty() {
+            return Err(sup_error!(Error::InvalidHealthProbe(format!("{} probe is missing a \
+                                                                     target",
+                                                                    self.kind))));
+        }
+        if self.interval == 0 || self.timeout == 0 || self.failure_threshold == 0 {
+            return Err(sup_error!(Error::InvalidHealthProbe(String::from("interval, timeout \
+                                                                          and failure_threshold \
+                                                                          must be greater than \
+                                                                          zero"))));
+        }
+        if self.kind == ProbeKind::Http {
+            Url::parse(&self.target)
+                .map_err(|e| {
+                             sup_error!(Error::InvalidHealthProbe(format!("{}, {}",
+                                                                          self.target,
+                                                                          e)))
+                         })?;
+        }
+        Ok(())
+    }
+
+    /// Run the probe once. A probe which fails or does not finish within its timeout is
+    /// `Critical`.
+    pub fn run(&self, service_group: &ServiceGroup, pkg: &Pkg) -> HealthCheck {
+        let result = match self.kind {
+            ProbeKind::Http => {
+                let target = self.target.clone();
+                let timeout = self.timeout();
+                let expected_status = self.expected_status;
+                with_timeout(timeout, move || http_probe(&target, expected_status, timeout))
+            }
+            ProbeKind::Tcp => {
+                let target = self.target.clone();
+                with_timeout(self.timeout(), move || tcp_probe(&target))
+            }
+            ProbeKind::Exec => exec_probe(&self.target, pkg, self.timeout()),
+        };
+        match result {
+            Some(Ok(health)) => health,
+            Some(Err(err)) => {
+                outputln!(preamble service_group,
+                          "Health probe {} {} failed, {}",
+                          self.kind,
+                          self.target,
+                          err);
+                HealthCheck::Critical
+            }
+            None => {
+                outputln!(preamble service_group,
+                          "Health probe {} {} timed out after {}s",
+                          self.kind,
+                          self.target,
+                          self.timeout);
+                HealthCheck::Critical
+            }
+        }
+    }
+}
+
+impl Default for HealthProbe {
+    fn default() -> Self {
+        HealthProbe {
+            kind: ProbeKind::default(),
+            target: String::new(),
+            expected_status: 200,
+            interval: 30,
+            timeout: 5,
+            failure_threshold: 3,
+        }
+    }
+}
+
+/// Runs the given function on its own thread, returning `None` if it did not complete in time.
+fn with_timeout<F, T>(timeout: Duration, f: F) -> Option<T>
+    where F: FnOnce() -> T + Send + 'static,
+          T: Send + 'static
+{
+    let (tx, rx) = channel();
+    let spawned = thread::Builder::new()
+        .name(String::from("health-probe"))
+        .spawn(move || { let _ = tx.send(f()); });
+    if spawned.is_err() {
+        return None;
+    }
+    rx.recv_timeout(timeout).ok()
+}
+
+fn http_probe(target: &str, expected_status: u16, timeout: Duration) -> io::Result<HealthCheck> {
+    let url = Url::parse(target)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    if url.scheme() != "http" {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput,
+                                  format!("unsupported scheme {}", url.scheme())));
+    }
+    // The host of an IPv6 URL is bracketed, which is right for the `Host` header but not to
+    // connect to, so the address is resolved from the parsed host.
+    let host = url.host_str()
+        .ok_or(io::Error::new(io::ErrorKind::InvalidInput, "missing host"))?;
+    let mut stream = TcpStream::connect(url.with_default_port(|_| Ok(80))?)?;
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+    let path = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+    write!(stream,
+           "GET {} HTTP/1.0\r\nHost: {}\r\nConnection: close\r\n\r\n",
+           path,
+           host)?;
+    let mut status_line = String::new();
+    BufReader::new(stream).read_line(&mut status_line)?;
+    match parse_status_line(&status_line) {
+        Some(status) if status == expected_status => Ok(HealthCheck::Ok),
+        Some(status) => {
+            Err(io::Error::new(io::ErrorKind::Other,
+                               format!("expected status {}, got {}", expected_status, status)))
+        }
+        None => {
+            Err(io::Error::new(io::ErrorKind::InvalidData,
+                               format!("invalid HTTP status line, {}", status_line.trim())))
+        }
+    }
+}
+
+fn parse_status_line(line: &str) -> Option<u16> {
+    let mut parts = line.split_whitespace();
+    match parts.next() {
+        Some(version) if version.starts_with("HTTP/") => {
+            parts.next().and_then(|s| u16::from_str(s).ok())
+        }
+        _ => None,
+    }
+}
+
+fn tcp_probe(target: &str) -> io::Result<HealthCheck> {
+    let addr = target
+        .to_socket_addrs()?
+        .next()
+        .ok_or(io::Error::new(io::ErrorKind::InvalidInput, "address did not resolve"))?;
+    TcpStream::connect(addr)?;
+    Ok(HealthCheck::Ok)
+}
+
+/// Runs the probe's command as the service user. Exit codes follow the same convention as the
+/// `health_check` hook. A command still running after the timeout is killed.
+fn exec_probe(target: &str, pkg: &Pkg, timeout: Duration) -> Option<io::Result<HealthCheck>> {
+    let mut args = target.split_whitespace();
+    let command = match args.next() {
+        Some(command) => command,
+        None => {
+            return Some(Err(io::Error::new(io::ErrorKind::InvalidInput, "missing command")));
+        }
+    };
+    let mut cmd = match exec::run_cmd(command, pkg) {
+        Ok(cmd) => cmd,
+        Err(err) => return Some(Err(io::Error::new(io::ErrorKind::Other, err.to_string()))),
+    };
+    let mut child = match cmd.args(args)
+              .stdout(Stdio::null())
+              .stderr(Stdio::null())
+              .spawn() {
+        Ok(child) => child,
+        Err(err) => return Some(Err(err)),
+    };
+    let pid = child.id() as Pid;
+    let result = with_timeout(timeout, move || child.wait());
+    if result.is_none() {
+        let _ = process::signal(pid, Signal::KILL);
+    }
+    result.map(|status| {
+                   status.map(|status| match status.code() {
+                                  Some(0) => HealthCheck::Ok,
+                                  Some(1) => HealthCheck::Warning,
+                                  Some(3) => HealthCheck::Unknown,
+                                  _ => HealthCheck::Critical,
+                              })
+               })
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::str::FromStr;
+    use std::thread;
+    use std::time::Duration;
+
+    use toml;
+
+    use super::*;
+    use error::Error::*;
+
+    #[test]
+    fn probe_kind_default() {
+        assert_eq!(ProbeKind::default(), ProbeKind::Http);
+    }
+
+    #[test]
+    fn probe_kind_from_str() {
+        assert_eq!(ProbeKind::from_str("tcp").unwrap(), ProbeKind::Tcp);
+    }
+
+    #[test]
+    fn probe_kind_from_str_invalid() {
+        match ProbeKind::from_str("udp") {
+            Err(e) => {
+                match e.err {
+                    InvalidProbeKind(s) => assert_eq!("udp", s),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("String should fail to parse"),
+        }
+    }
+
+    #[test]
+    fn probe_kind_to_string() {
+        assert_eq!("exec", ProbeKind::Exec.to_string());
+    }
+
+    #[test]
+    fn health_probe_toml_deserialize_defaults() {
+        let probe: HealthProbe = toml::from_str(r#"
+            kind = "tcp"
+            target = "localhost:6379"
+            "#)
+                .unwrap();
+
+        assert_eq!(probe.kind, ProbeKind::Tcp);
+        assert_eq!(probe.target, "localhost:6379");
+        assert_eq!(probe.expected_status, 200);
+        assert_eq!(probe.interval(), Duration::from_secs(30));
+        assert_eq!(probe.timeout(), Duration::from_secs(5));
+        assert_eq!(probe.failure_threshold, 3);
+    }
+
+    #[test]
+    fn health_probe_validate() {
+        let mut probe = HealthProbe::default();
+        assert!(probe.validate().is_err());
+        probe.target = String::from("not a url");
+        assert!(probe.validate().is_err());
+        probe.target = String::from("http://localhost:8080/status");
+        assert!(probe.validate().is_ok());
+        probe.failure_threshold = 0;
+        assert!(probe.validate().is_err());
+    }
+
+    #[test]
+    fn parse_http_status_line() {
+        assert_eq!(parse_status_line("HTTP/1.1 200 OK\r\n"), Some(200));
+        assert_eq!(parse_status_line("HTTP/1.0 503 Service Unavailable"),
+                   Some(503));
+        assert_eq!(parse_status_line("SSH-2.0-OpenSSH_7.4"), None);
+    }
+
+    #[test]
+    fn tcp_probe_ok() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        assert_eq!(tcp_probe(&addr.to_string()).unwrap(), HealthCheck::Ok);
+    }
+
+    #[test]
+    fn tcp_probe_refused() {
+        let addr = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap()
+        };
+
+        assert!(tcp_probe(&addr.to_string()).is_err());
+    }
+
+    /// Serves a single HTTP request on the given listener with the given status.
+    fn serve_status(listener: TcpListener, status: u16) {
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut request_line = String::new();
+            BufReader::new(&stream)
+                .read_line(&mut request_line)
+                .unwrap();
+            write!(&stream, "HTTP/1.0 {} Status\r\n\r\n", status).unwrap();
+        });
+    }
+
+    #[test]
+    fn http_probe_ok() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/status", listener.local_addr().unwrap());
+        serve_status(listener, 200);
+
+        assert_eq!(http_probe(&url, 200, Duration::from_secs(5)).unwrap(),
+                   HealthCheck::Ok);
+    }
+
+    #[test]
+    fn http_probe_unexpected_status() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/status", listener.local_addr().unwrap());
+        serve_status(listener, 503);
+
+        assert!(http_probe(&url, 200, Duration::from_secs(5)).is_err());
+    }
+
+    #[test]
+    fn http_probe_ipv6() {
+        // Hosts without IPv6 have nothing to probe.
+        let listener = match TcpListener::bind("[::1]:0") {
+            Ok(listener) => listener,
+            Err(_) => return,
+        };
+        let url = format!("http://[::1]:{}/status", listener.local_addr().unwrap().port());
+        serve_status(listener, 200);
+
+        assert_eq!(http_probe(&url, 200, Duration::from_secs(5)).unwrap(),
+                   HealthCheck::Ok);
+    }
+
+    #[test]
+    fn with_timeout_expires() {
+        let result = with_timeout(Duration::from_millis(10),
+                                  || thread::sleep(Duration::from_millis(500)));
+
+        assert!(result.is_none());
+    }
+}
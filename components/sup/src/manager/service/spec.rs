@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashSet;
+use std::cmp;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fs::{self, File};
 use std::io::{BufReader, Read, Write};
@@ -21,6 +22,7 @@ use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
 
+use hcore::os::process::Signal;
 use hcore::package::{PackageIdent, PackageInstall};
 use hcore::service::ServiceGroup;
 use hcore::url::DEFAULT_DEPOT_URL;
@@ -29,12 +31,17 @@ use rand::{Rng, thread_rng};
 use serde;
 use toml;
 
-use super::{Topology, UpdateStrategy};
+use super::{HealthProbe, ResourceLimits, TaskSchedule, Topology, UpdateStrategy, UpdateTimezone,
+            UpdateWindow, VersionConstraint};
 use error::{Error, Result, SupError};
 
 static LOGKEY: &'static str = "SS";
 static DEFAULT_GROUP: &'static str = "default";
-const SPEC_FILE_EXT: &'static str = "spec";
+pub const SPEC_FILE_EXT: &'static str = "spec";
+const DEFAULT_RESTART_BACKOFF_BASE: u64 = 1;
+const DEFAULT_RESTART_BACKOFF_CEILING: u64 = 60;
+const DEFAULT_STOP_TIMEOUT: u64 = 8;
+const DEFAULT_CANARY_SOAK_PERIOD: u64 = 300;
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum DesiredState {
@@ -82,8 +89,19 @@ pub struct ServiceSpec {
     pub depot_url: String,
     pub channel: Option<String>,
     pub topology: Topology,
+    /// Cron expression, evaluated in UTC, on which a service with the task topology is run. The
+    /// task runs once if not set.
+    pub task_schedule: Option<TaskSchedule>,
+    /// Whether a service with the task topology only runs on the leader of its service group.
+    pub task_leader_only: bool,
     pub update_strategy: UpdateStrategy,
     pub binds: Vec<ServiceBind>,
+    /// Names of services loaded on the same Supervisor which must be up and healthy before the
+    /// service is started.
+    pub requires: Vec<String>,
+    /// Names of services which are started before the service if they are loaded on the same
+    /// Supervisor.
+    pub after: Vec<String>,
     pub config_from: Option<PathBuf>,
     #[serde(
         deserialize_with = "deserialize_using_from_str",
@@ -95,6 +113,49 @@ pub struct ServiceSpec {
         serialize_with = "serialize_using_to_string"
     )]
     pub start_style: StartStyle,
+    #[serde(
+        deserialize_with = "deserialize_using_from_str",
+        serialize_with = "serialize_using_to_string"
+    )]
+    pub restart_policy: RestartPolicy,
+    /// Number of restarts in a row after which the Supervisor gives up, unlimited if not set.
+    pub restart_max_retries: Option<u32>,
+    /// Seconds to wait before the first restart, doubled for each following restart in a row.
+    pub restart_backoff_base: u64,
+    /// Maximum seconds to wait between restarts.
+    pub restart_backoff_ceiling: u64,
+    /// Signal sent to the service's process to stop it, after running its `pre-stop` hook.
+    #[serde(
+        deserialize_with = "deserialize_using_from_str",
+        serialize_with = "serialize_using_to_string"
+    )]
+    pub stop_signal: Signal,
+    /// Seconds to wait for the process to exit after the stop signal before it is killed.
+    pub stop_timeout: u64,
+    #[serde(
+        deserialize_with = "deserialize_using_from_str",
+        serialize_with = "serialize_using_to_string"
+    )]
+    pub canary_size: CanarySize,
+    /// Seconds the canaries of a canary update must stay healthy before the rest of the service
+    /// group is updated.
+    pub canary_soak_period: u64,
+    /// Seconds a newly updated release may keep failing before the Supervisor rolls back to the
+    /// last known good release, which is also the time a release has to run without failing to
+    /// become known good. Rollbacks are disabled if not set.
+    pub rollback_window: Option<u64>,
+    /// Periods during which a new release may be swapped in. Updates found outside of them are
+    /// held until the next one opens. Updates are applied as soon as they are found if empty.
+    pub update_windows: Vec<UpdateWindow>,
+    pub update_timezone: UpdateTimezone,
+    /// Requirements on the version of the releases the service is updated to, such as `1.4.x`.
+    /// Releases which don't meet them are never updated to.
+    pub update_constraint: Option<VersionConstraint>,
+    /// Whether configuration and hook templates referring to variables the render context
+    /// doesn't define fail to render, leaving the previously rendered files in place.
+    pub strict_templates: bool,
+    pub resource_limits: Option<ResourceLimits>,
+    pub health_probe: Option<HealthProbe>,
 }
 
 impl ServiceSpec {
@@ -161,9 +222,55 @@ impl ServiceSpec {
 
     pub fn validate(&self, package: &PackageInstall) -> Result<()> {
         self.validate_binds(package)?;
+        if let Some(ref limits) = self.resource_limits {
+            limits.validate()?;
+        }
+        if let Some(ref probe) = self.health_probe {
+            probe.validate()?;
+        }
         Ok(())
     }
 
+    /// Validates that the service isn't ordered after itself, either directly or through the
+    /// `requires` and `after` lists of the other loaded services. A loaded spec for the same
+    /// service is ignored as this spec replaces it.
+    ///
+    /// # Errors
+    ///
+    /// * If the service requires or is started after itself
+    /// * If the service is part of a cycle of services requiring or started after each other
+    pub fn validate_dependencies<'a, T>(&'a self, loaded: T) -> Result<()>
+        where T: IntoIterator<Item = &'a ServiceSpec>
+    {
+        if self.dependencies().any(|name| name == self.ident.name) {
+            return Err(sup_error!(Error::InvalidDependency(self.ident.name.clone())));
+        }
+        let mut dependencies: HashMap<&str, Vec<&str>> = HashMap::new();
+        for spec in loaded {
+            if spec.ident.name != self.ident.name {
+                dependencies.insert(spec.ident.name.as_str(), spec.dependencies().collect());
+            }
+        }
+        dependencies.insert(self.ident.name.as_str(), self.dependencies().collect());
+        let mut cycle = vec![self.ident.name.as_str()];
+        let mut visited = HashSet::new();
+        if find_cycle(&dependencies, &mut cycle, &mut visited) {
+            return Err(sup_error!(Error::DependencyCycle(cycle
+                                                             .into_iter()
+                                                             .map(String::from)
+                                                             .collect())));
+        }
+        Ok(())
+    }
+
+    /// Names of the services this service requires or is started after.
+    fn dependencies<'a>(&'a self) -> Box<Iterator<Item = &'a str> + 'a> {
+        Box::new(self.requires
+                     .iter()
+                     .chain(self.after.iter())
+                     .map(String::as_str))
+    }
+
     /// Validates that all required package binds are present in service binds and all remaining
     /// service binds are optional package binds.
     ///
@@ -206,6 +313,32 @@ impl ServiceSpec {
     }
 }
 
+/// Walks the dependencies of the last service of `path` depth first, looking for a way back to
+/// the first one. Returns `true` when found, with `path` holding the services of the cycle from
+/// the first service back to itself.
+fn find_cycle<'a>(dependencies: &HashMap<&'a str, Vec<&'a str>>,
+                  path: &mut Vec<&'a str>,
+                  visited: &mut HashSet<&'a str>)
+                  -> bool {
+    let current = path[path.len() - 1];
+    if let Some(names) = dependencies.get(current) {
+        for &name in names {
+            if name == path[0] {
+                path.push(name);
+                return true;
+            }
+            if visited.insert(name) {
+                path.push(name);
+                if find_cycle(dependencies, path, visited) {
+                    return true;
+                }
+                path.pop();
+            }
+        }
+    }
+    false
+}
+
 impl Default for ServiceSpec {
     fn default() -> Self {
         ServiceSpec {
@@ -214,11 +347,30 @@ impl Default for ServiceSpec {
             depot_url: DEFAULT_DEPOT_URL.to_string(),
             channel: None,
             topology: Topology::default(),
+            task_schedule: None,
+            task_leader_only: false,
             update_strategy: UpdateStrategy::default(),
             binds: Vec::default(),
+            requires: Vec::new(),
+            after: Vec::new(),
             config_from: None,
             desired_state: DesiredState::default(),
             start_style: StartStyle::default(),
+            restart_policy: RestartPolicy::default(),
+            restart_max_retries: None,
+            restart_backoff_base: DEFAULT_RESTART_BACKOFF_BASE,
+            restart_backoff_ceiling: DEFAULT_RESTART_BACKOFF_CEILING,
+            stop_signal: Signal::TERM,
+            stop_timeout: DEFAULT_STOP_TIMEOUT,
+            canary_size: CanarySize::default(),
+            canary_soak_period: DEFAULT_CANARY_SOAK_PERIOD,
+            rollback_window: None,
+            update_windows: Vec::new(),
+            update_timezone: UpdateTimezone::default(),
+            update_constraint: None,
+            strict_templates: false,
+            resource_limits: None,
+            health_probe: None,
         }
     }
 }
@@ -314,6 +466,99 @@ impl FromStr for StartStyle {
     }
 }
 
+/// Whether a service's process is started again after it exits on its own.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RestartPolicy {
+    Always,
+    OnFailure,
+    Never,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> RestartPolicy {
+        RestartPolicy::Always
+    }
+}
+
+impl fmt::Display for RestartPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            RestartPolicy::Always => "always",
+            RestartPolicy::OnFailure => "on-failure",
+            RestartPolicy::Never => "never",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl FromStr for RestartPolicy {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "always" => Ok(RestartPolicy::Always),
+            "on-failure" => Ok(RestartPolicy::OnFailure),
+            "never" => Ok(RestartPolicy::Never),
+            _ => Err(sup_error!(Error::BadRestartPolicy(value.to_string()))),
+        }
+    }
+}
+
+/// How many members of a service group are updated first with the canary update strategy, either
+/// a number of members (`2`) or a percentage of the alive members (`25%`).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CanarySize {
+    Members(u32),
+    Percent(u32),
+}
+
+impl CanarySize {
+    /// Returns the number of canaries in a service group with the given number of alive members.
+    /// There is always at least one canary.
+    pub fn count(&self, population: usize) -> usize {
+        let count = match *self {
+            CanarySize::Members(members) => members as usize,
+            CanarySize::Percent(percent) => (population * percent as usize + 99) / 100,
+        };
+        cmp::max(cmp::min(count, population), 1)
+    }
+}
+
+impl Default for CanarySize {
+    fn default() -> CanarySize {
+        CanarySize::Members(1)
+    }
+}
+
+impl fmt::Display for CanarySize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CanarySize::Members(members) => write!(f, "{}", members),
+            CanarySize::Percent(percent) => write!(f, "{}%", percent),
+        }
+    }
+}
+
+impl FromStr for CanarySize {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let value = value.trim();
+        let size = if value.ends_with('%') {
+            match u32::from_str(&value[..value.len() - 1]) {
+                Ok(percent) if percent > 0 && percent <= 100 => Some(CanarySize::Percent(percent)),
+                _ => None,
+            }
+        } else {
+            match u32::from_str(value) {
+                Ok(members) if members > 0 => Some(CanarySize::Members(members)),
+                _ => None,
+            }
+        };
+        size.ok_or_else(|| sup_error!(Error::BadCanarySize(value.to_string())))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::fs::{self, File};
@@ -329,6 +574,7 @@ mod test {
 
     use super::*;
     use error::Error::*;
+    use manager::service::ProbeKind;
 
     fn file_from_str<P: AsRef<Path>>(path: P, content: &str) {
         fs::create_dir_all(path.as_ref()
@@ -356,12 +602,31 @@ mod test {
             group = "jobs"
             depot_url = "http://example.com/depot"
             topology = "leader"
+            task_schedule = "0 3 * * *"
+            task_leader_only = true
             update_strategy = "rolling"
             binds = ["cache:redis.cache@acmecorp", "db:postgres.app@acmecorp"]
+            requires = ["postgresql"]
+            after = ["redis", "memcached"]
             start_style = "persistent"
             config_from = "/only/for/development"
+            restart_policy = "never"
+            restart_max_retries = 3
+            stop_signal = "INT"
+            stop_timeout = 30
+            canary_size = "2"
+            rollback_window = 120
+            update_windows = ["Sat-Sun 02:00-06:00", "0-30 3 * * *"]
+            update_timezone = "-08:00"
+            update_constraint = "~1.4.2"
+            strict_templates = true
 
             extra_stuff = "should be ignored"
+
+            [health_probe]
+            kind = "http"
+            target = "http://localhost:8080/status"
+            timeout = 2
             "#;
         let spec = ServiceSpec::from_str(toml).unwrap();
 
@@ -370,13 +635,40 @@ mod test {
         assert_eq!(spec.group, String::from("jobs"));
         assert_eq!(spec.depot_url, String::from("http://example.com/depot"));
         assert_eq!(spec.topology, Topology::Leader);
+        assert_eq!(spec.task_schedule,
+                   Some(TaskSchedule::from_str("0 3 * * *").unwrap()));
+        assert!(spec.task_leader_only);
         assert_eq!(spec.update_strategy, UpdateStrategy::Rolling);
         assert_eq!(spec.binds,
                    vec![ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                         ServiceBind::from_str("db:postgres.app@acmecorp").unwrap()]);
+        assert_eq!(spec.requires, vec![String::from("postgresql")]);
+        assert_eq!(spec.after,
+                   vec![String::from("redis"), String::from("memcached")]);
         assert_eq!(spec.config_from,
                    Some(PathBuf::from("/only/for/development")));
         assert_eq!(spec.start_style, StartStyle::Persistent);
+        assert_eq!(spec.restart_policy, RestartPolicy::Never);
+        assert_eq!(spec.restart_max_retries, Some(3));
+        assert_eq!(spec.restart_backoff_base, 1);
+        assert_eq!(spec.restart_backoff_ceiling, 60);
+        assert_eq!(spec.stop_signal, Signal::INT);
+        assert_eq!(spec.stop_timeout, 30);
+        assert_eq!(spec.canary_size, CanarySize::Members(2));
+        assert_eq!(spec.canary_soak_period, 300);
+        assert_eq!(spec.rollback_window, Some(120));
+        assert_eq!(spec.update_windows,
+                   vec![UpdateWindow::from_str("Sat-Sun 02:00-06:00").unwrap(),
+                        UpdateWindow::from_str("0-30 3 * * *").unwrap()]);
+        assert_eq!(spec.update_timezone, UpdateTimezone::Offset(-480));
+        assert_eq!(spec.update_constraint,
+                   Some(VersionConstraint::from_str("~1.4.2").unwrap()));
+        assert!(spec.strict_templates);
+        let probe = spec.health_probe.unwrap();
+        assert_eq!(probe.kind, ProbeKind::Http);
+        assert_eq!(probe.target, "http://localhost:8080/status");
+        assert_eq!(probe.timeout, 2);
+        assert_eq!(probe.failure_threshold, 3);
     }
 
     #[test]
@@ -439,12 +731,38 @@ mod test {
             depot_url: String::from("http://example.com/depot"),
             channel: Some(String::from("stable")),
             topology: Topology::Leader,
+            task_schedule: Some(TaskSchedule::from_str("*/15 * * * *").unwrap()),
+            task_leader_only: true,
             update_strategy: UpdateStrategy::AtOnce,
             binds: vec![ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                         ServiceBind::from_str("db:postgres.app@acmecorp").unwrap()],
+            requires: vec![String::from("postgresql")],
+            after: vec![String::from("redis")],
             config_from: Some(PathBuf::from("/only/for/development")),
             desired_state: DesiredState::Down,
             start_style: StartStyle::Persistent,
+            restart_policy: RestartPolicy::OnFailure,
+            restart_max_retries: Some(5),
+            restart_backoff_base: 2,
+            restart_backoff_ceiling: 30,
+            stop_signal: Signal::QUIT,
+            stop_timeout: 20,
+            canary_size: CanarySize::Percent(25),
+            canary_soak_period: 600,
+            rollback_window: Some(90),
+            update_windows: vec![UpdateWindow::from_str("Sat 02:00-06:00").unwrap()],
+            update_timezone: UpdateTimezone::Local,
+            update_constraint: Some(VersionConstraint::from_str(">=1.2, <2.0").unwrap()),
+            strict_templates: true,
+            resource_limits: Some(ResourceLimits {
+                                      memory_max: Some(1048576),
+                                      ..ResourceLimits::default()
+                                  }),
+            health_probe: Some(HealthProbe {
+                                   kind: ProbeKind::Tcp,
+                                   target: String::from("localhost:6379"),
+                                   ..HealthProbe::default()
+                               }),
         };
         let toml = spec.to_toml_string().unwrap();
 
@@ -453,12 +771,34 @@ mod test {
         assert!(toml.contains(r#"depot_url = "http://example.com/depot""#));
         assert!(toml.contains(r#"channel = "stable""#));
         assert!(toml.contains(r#"topology = "leader""#));
+        assert!(toml.contains(r#"task_schedule = "*/15 * * * *""#));
+        assert!(toml.contains("task_leader_only = true"));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
+        assert!(toml.contains(r#"requires = ["postgresql"]"#));
+        assert!(toml.contains(r#"after = ["redis"]"#));
         assert!(toml.contains(r#"desired_state = "down""#));
         assert!(toml.contains(r#"start_style = "persistent""#));
         assert!(toml.contains(r#"config_from = "/only/for/development""#));
+        assert!(toml.contains(r#"restart_policy = "on-failure""#));
+        assert!(toml.contains("restart_max_retries = 5"));
+        assert!(toml.contains("restart_backoff_base = 2"));
+        assert!(toml.contains("restart_backoff_ceiling = 30"));
+        assert!(toml.contains(r#"stop_signal = "QUIT""#));
+        assert!(toml.contains("stop_timeout = 20"));
+        assert!(toml.contains(r#"canary_size = "25%""#));
+        assert!(toml.contains("canary_soak_period = 600"));
+        assert!(toml.contains("rollback_window = 90"));
+        assert!(toml.contains(r#"update_windows = ["Sat 02:00-06:00"]"#));
+        assert!(toml.contains(r#"update_timezone = "local""#));
+        assert!(toml.contains(r#"update_constraint = ">=1.2, <2.0""#));
+        assert!(toml.contains("strict_templates = true"));
+        assert!(toml.contains("[resource_limits]"));
+        assert!(toml.contains("memory_max = 1048576"));
+        assert!(toml.contains("[health_probe]"));
+        assert!(toml.contains(r#"kind = "tcp""#));
+        assert!(toml.contains(r#"target = "localhost:6379""#));
     }
 
     #[test]
@@ -569,12 +909,38 @@ mod test {
             depot_url: String::from("http://example.com/depot"),
             channel: Some(String::from("stable")),
             topology: Topology::Leader,
+            task_schedule: Some(TaskSchedule::from_str("*/15 * * * *").unwrap()),
+            task_leader_only: true,
             update_strategy: UpdateStrategy::AtOnce,
             binds: vec![ServiceBind::from_str("cache:redis.cache@acmecorp").unwrap(),
                         ServiceBind::from_str("db:postgres.app@acmecorp").unwrap()],
+            requires: vec![String::from("postgresql")],
+            after: vec![String::from("redis")],
             config_from: Some(PathBuf::from("/only/for/development")),
             desired_state: DesiredState::Down,
             start_style: StartStyle::Persistent,
+            restart_policy: RestartPolicy::OnFailure,
+            restart_max_retries: Some(5),
+            restart_backoff_base: 2,
+            restart_backoff_ceiling: 30,
+            stop_signal: Signal::QUIT,
+            stop_timeout: 20,
+            canary_size: CanarySize::Percent(25),
+            canary_soak_period: 600,
+            rollback_window: Some(90),
+            update_windows: vec![UpdateWindow::from_str("Sat 02:00-06:00").unwrap()],
+            update_timezone: UpdateTimezone::Local,
+            update_constraint: Some(VersionConstraint::from_str(">=1.2, <2.0").unwrap()),
+            strict_templates: true,
+            resource_limits: Some(ResourceLimits {
+                                      memory_max: Some(1048576),
+                                      ..ResourceLimits::default()
+                                  }),
+            health_probe: Some(HealthProbe {
+                                   kind: ProbeKind::Tcp,
+                                   target: String::from("localhost:6379"),
+                                   ..HealthProbe::default()
+                               }),
         };
         spec.to_file(&path).unwrap();
         let toml = string_from_file(path);
@@ -584,12 +950,34 @@ mod test {
         assert!(toml.contains(r#"depot_url = "http://example.com/depot""#));
         assert!(toml.contains(r#"channel = "stable""#));
         assert!(toml.contains(r#"topology = "leader""#));
+        assert!(toml.contains(r#"task_schedule = "*/15 * * * *""#));
+        assert!(toml.contains("task_leader_only = true"));
         assert!(toml.contains(r#"update_strategy = "at-once""#));
         assert!(toml.contains(r#""cache:redis.cache@acmecorp""#));
         assert!(toml.contains(r#""db:postgres.app@acmecorp""#));
+        assert!(toml.contains(r#"requires = ["postgresql"]"#));
+        assert!(toml.contains(r#"after = ["redis"]"#));
         assert!(toml.contains(r#"desired_state = "down""#));
         assert!(toml.contains(r#"start_style = "persistent""#));
         assert!(toml.contains(r#"config_from = "/only/for/development""#));
+        assert!(toml.contains(r#"restart_policy = "on-failure""#));
+        assert!(toml.contains("restart_max_retries = 5"));
+        assert!(toml.contains("restart_backoff_base = 2"));
+        assert!(toml.contains("restart_backoff_ceiling = 30"));
+        assert!(toml.contains(r#"stop_signal = "QUIT""#));
+        assert!(toml.contains("stop_timeout = 20"));
+        assert!(toml.contains(r#"canary_size = "25%""#));
+        assert!(toml.contains("canary_soak_period = 600"));
+        assert!(toml.contains("rollback_window = 90"));
+        assert!(toml.contains(r#"update_windows = ["Sat 02:00-06:00"]"#));
+        assert!(toml.contains(r#"update_timezone = "local""#));
+        assert!(toml.contains(r#"update_constraint = ">=1.2, <2.0""#));
+        assert!(toml.contains("strict_templates = true"));
+        assert!(toml.contains("[resource_limits]"));
+        assert!(toml.contains("memory_max = 1048576"));
+        assert!(toml.contains("[health_probe]"));
+        assert!(toml.contains(r#"kind = "tcp""#));
+        assert!(toml.contains(r#"target = "localhost:6379""#));
     }
 
     #[test]
@@ -726,4 +1114,138 @@ mod test {
 
         assert!(toml.starts_with(r#"key = "name:service.group""#));
     }
+
+    #[test]
+    fn restart_policy_default() {
+        assert_eq!(RestartPolicy::default(), RestartPolicy::Always);
+    }
+
+    #[test]
+    fn restart_policy_from_str() {
+        assert_eq!(RestartPolicy::from_str("on-failure").unwrap(),
+                   RestartPolicy::OnFailure);
+        assert_eq!(RestartPolicy::from_str("NEVER").unwrap(),
+                   RestartPolicy::Never);
+    }
+
+    #[test]
+    fn restart_policy_from_str_invalid() {
+        match RestartPolicy::from_str("sometimes") {
+            Err(e) => {
+                match e.err {
+                    BadRestartPolicy(s) => assert_eq!("sometimes", s),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("String should fail to parse"),
+        }
+    }
+
+    #[test]
+    fn restart_policy_to_string() {
+        assert_eq!("on-failure", RestartPolicy::OnFailure.to_string());
+    }
+
+    #[test]
+    fn canary_size_default() {
+        assert_eq!(CanarySize::default(), CanarySize::Members(1));
+    }
+
+    #[test]
+    fn canary_size_from_str() {
+        assert_eq!(CanarySize::from_str("3").unwrap(), CanarySize::Members(3));
+        assert_eq!(CanarySize::from_str("25%").unwrap(),
+                   CanarySize::Percent(25));
+    }
+
+    #[test]
+    fn canary_size_from_str_invalid() {
+        for value in vec!["0", "0%", "101%", "some"] {
+            match CanarySize::from_str(value) {
+                Err(e) => {
+                    match e.err {
+                        BadCanarySize(s) => assert_eq!(value, s),
+                        wrong => panic!("Unexpected error returned: {:?}", wrong),
+                    }
+                }
+                Ok(_) => panic!("String should fail to parse"),
+            }
+        }
+    }
+
+    #[test]
+    fn canary_size_to_string() {
+        assert_eq!("2", CanarySize::Members(2).to_string());
+        assert_eq!("10%", CanarySize::Percent(10).to_string());
+    }
+
+    #[test]
+    fn canary_size_count() {
+        assert_eq!(CanarySize::Members(2).count(5), 2);
+        assert_eq!(CanarySize::Members(8).count(5), 5);
+        assert_eq!(CanarySize::Percent(25).count(10), 3);
+        assert_eq!(CanarySize::Percent(10).count(3), 1);
+        assert_eq!(CanarySize::Members(1).count(0), 1);
+    }
+
+    fn dependent_spec(ident: &str, requires: &[&str], after: &[&str]) -> ServiceSpec {
+        let mut spec = ServiceSpec::default_for(PackageIdent::from_str(ident).unwrap());
+        spec.requires = requires.iter().map(|name| name.to_string()).collect();
+        spec.after = after.iter().map(|name| name.to_string()).collect();
+        spec
+    }
+
+    #[test]
+    fn validate_dependencies_on_itself() {
+        let spec = dependent_spec("core/redis", &[], &["redis"]);
+
+        match spec.validate_dependencies(&[]) {
+            Err(e) => {
+                match e.err {
+                    InvalidDependency(name) => assert_eq!(name, "redis"),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("Spec should fail to validate"),
+        }
+    }
+
+    #[test]
+    fn validate_dependencies_without_cycle() {
+        let loaded = vec![dependent_spec("core/postgresql", &[], &[]),
+                          dependent_spec("core/migrator", &["postgresql"], &[]),
+                          dependent_spec("core/app", &["postgresql"], &["redis"])];
+        let spec = dependent_spec("core/redis", &[], &["postgresql"]);
+
+        assert!(spec.validate_dependencies(&loaded).is_ok());
+    }
+
+    #[test]
+    fn validate_dependencies_with_cycle() {
+        let loaded = vec![dependent_spec("core/postgresql", &[], &[]),
+                          dependent_spec("core/migrator", &["postgresql"], &["app"]),
+                          dependent_spec("core/app", &["postgresql"], &["redis"])];
+        let spec = dependent_spec("core/redis", &[], &["migrator"]);
+
+        match spec.validate_dependencies(&loaded) {
+            Err(e) => {
+                match e.err {
+                    DependencyCycle(names) => {
+                        assert_eq!(names, vec!["redis", "migrator", "app", "redis"])
+                    }
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("Spec should fail to validate"),
+        }
+    }
+
+    #[test]
+    fn validate_dependencies_replaces_loaded_spec() {
+        let loaded = vec![dependent_spec("core/app", &[], &["redis"]),
+                          dependent_spec("core/redis", &[], &["app"])];
+        let spec = dependent_spec("core/redis", &[], &[]);
+
+        assert!(spec.validate_dependencies(&loaded).is_ok());
+    }
 }
@@ -24,29 +24,40 @@ use std::fs::File;
 use std::io::BufReader;
 use std::io::prelude::*;
 use std::path::PathBuf;
-use std::process::Child;
+use std::process::Command;
+use std::cmp;
 use std::result;
 use std::thread;
+use std::time::{Duration, Instant};
 
-use hcore::os::process::{HabChild, ExitStatusExt};
+use hcore::os::process::{HabChild, ExitStatusExt, Signal};
 use hcore::util::perm::set_owner;
 use hcore::service::ServiceGroup;
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 use time::{self, Timespec};
 
+use super::cgroups::{Cgroup, ResourceLimits};
 use super::exec;
+use super::metrics;
 use error::{Result, Error};
-use manager::service::Pkg;
+use manager::events::{self, Event};
+use manager::service::{Pkg, RestartPolicy};
+use output::{Stream, StructuredOutput};
 
 static LOGKEY: &'static str = "SV";
 
+/// Number of restarts in a row, without the process staying up for the backoff ceiling, after
+/// which a process is considered to be crash looping.
+const CRASH_LOOP_THRESHOLD: u32 = 3;
+
 #[derive(Debug, PartialEq, Deserialize, Serialize)]
 pub enum ProcessState {
     Down,
     Up,
     Start,
     Restart,
+    CrashLooping,
 }
 
 impl fmt::Display for ProcessState {
@@ -56,11 +67,39 @@ impl fmt::Display for ProcessState {
             &ProcessState::Up => "up",
             &ProcessState::Start => "start",
             &ProcessState::Restart => "restart",
+            &ProcessState::CrashLooping => "crash-looping",
         };
         write!(f, "{}", state)
     }
 }
 
+/// How a process which exited on its own is restarted.
+#[derive(Clone, Debug)]
+pub struct RestartConfig {
+    pub policy: RestartPolicy,
+    pub max_retries: Option<u32>,
+    pub backoff_base: Duration,
+    pub backoff_ceiling: Duration,
+}
+
+impl RestartConfig {
+    /// Returns how long to wait before the next restart after `restarts` restarts in a row.
+    fn backoff(&self, restarts: u32) -> Duration {
+        let factor = 1u32.checked_shl(restarts).unwrap_or(u32::max_value());
+        let backoff = self.backoff_base
+            .checked_mul(factor)
+            .unwrap_or(self.backoff_ceiling);
+        cmp::min(backoff, self.backoff_ceiling)
+    }
+}
+
+/// How the process is stopped when the Supervisor stops or restarts it.
+#[derive(Clone, Debug)]
+pub struct StopConfig {
+    pub signal: Signal,
+    pub timeout: Duration,
+}
+
 #[derive(Debug)]
 pub struct Supervisor {
     pub child: Option<HabChild>,
@@ -68,24 +107,55 @@ pub struct Supervisor {
     pub state: ProcessState,
     pub state_entered: Timespec,
     pub has_started: bool,
+    pub restart_count: u32,
     pid: Option<PathBuf>,
+    service_group: ServiceGroup,
+    restart_config: RestartConfig,
+    stop_config: StopConfig,
+    resource_limits: Option<ResourceLimits>,
+    cgroup: Option<Cgroup>,
+    started_at: Option<Instant>,
+    exited_at: Option<Instant>,
+    last_exit_success: bool,
+    last_exit_code: Option<u32>,
 }
 
 impl Supervisor {
-    pub fn new(service_group: &ServiceGroup) -> Supervisor {
+    pub fn new(service_group: &ServiceGroup,
+               restart_config: RestartConfig,
+               stop_config: StopConfig,
+               resource_limits: Option<ResourceLimits>)
+               -> Supervisor {
         Supervisor {
             child: None,
             preamble: format!("{}", service_group),
             state: ProcessState::Down,
             state_entered: time::get_time(),
             has_started: false,
+            restart_count: 0,
             pid: None,
+            service_group: service_group.clone(),
+            restart_config: restart_config,
+            stop_config: stop_config,
+            resource_limits: resource_limits,
+            cgroup: None,
+            started_at: None,
+            exited_at: None,
+            last_exit_success: false,
+            last_exit_code: None,
         }
     }
 
     fn enter_state(&mut self, state: ProcessState) {
+        // Staying in the same state, like a stopped process being reaped while down, is not a
+        // transition; it is neither published nor resets when the state was entered.
+        if self.state == state {
+            return;
+        }
+        events::publish(Event::process_state_changed(&self.service_group, &state));
         self.state = state;
         self.state_entered = time::get_time();
+        metrics::process_state_changed(&self.service_group, &self.state, self.state_entered);
     }
 
     pub fn status(&self) -> (bool, String) {
@@ -95,7 +165,7 @@ impl Supervisor {
                              time::get_time() - self.state_entered);
         let healthy = match self.state {
             ProcessState::Up | ProcessState::Start | ProcessState::Restart => true,
-            ProcessState::Down => false,
+            ProcessState::Down | ProcessState::CrashLooping => false,
         };
         (healthy, status)
     }
@@ -116,24 +186,37 @@ impl Supervisor {
                   &pkg.svc_user,
                   &pkg.svc_group);
         self.enter_state(ProcessState::Start);
-        let mut child = exec::run_cmd(&pkg.svc_run, &pkg)?.spawn()?;
+        let mut cmd = exec::run_cmd(&pkg.svc_run, &pkg)?;
+        let cgroup_procs = self.apply_resource_limits(&mut cmd);
+        let mut child = cmd.spawn()?;
+        drop(cgroup_procs);
         self.child = Some(HabChild::from(&mut child)?);
         self.create_pidfile(pkg)?;
+        let stdout = child.stdout.take();
+        let stderr = child.stderr.take();
         let package_name = self.preamble.clone();
         thread::Builder::new()
             .name(String::from("sup-service-read"))
-            .spawn(move || -> Result<()> { child_reader(&mut child, package_name) })?;
+            .spawn(move || -> Result<()> { child_reader(stdout, package_name, Stream::Stdout) })?;
+        let package_name = self.preamble.clone();
+        thread::Builder::new()
+            .name(String::from("sup-service-read-err"))
+            .spawn(move || -> Result<()> { child_reader(stderr, package_name, Stream::Stderr) })?;
         self.enter_state(ProcessState::Up);
         self.has_started = true;
+        self.started_at = Some(Instant::now());
+        metrics::package(&self.service_group, &pkg.ident);
         Ok(())
     }
 
-    /// Send a SIGTERM to a process, wait 8 seconds, then send SIGKILL
+    /// Send the stop signal to a process, wait for the stop timeout, then send SIGKILL
     pub fn stop(&mut self) -> Result<()> {
         match self.child {
             Some(ref mut child) => {
-                outputln!(preamble & self.preamble, "Stopping...");
-                let shutdown = try!(child.kill());
+                outputln!(preamble & self.preamble,
+                          "Stopping with SIG{}...",
+                          self.stop_config.signal);
+                let shutdown = try!(child.stop(self.stop_config.signal, self.stop_config.timeout));
                 outputln!("{} - Shutdown method: {}", self.preamble, shutdown);
             }
             None => {}
@@ -146,24 +229,121 @@ impl Supervisor {
         self.enter_state(ProcessState::Down);
         try!(self.stop());
         self.cleanup_pidfile();
+        if let Some(cgroup) = self.cgroup.take() {
+            cgroup.remove();
+        }
         Ok(())
     }
 
     pub fn restart(&mut self, pkg: &Pkg) -> Result<()> {
+        metrics::process_restarted(&self.service_group);
         self.enter_state(ProcessState::Restart);
         try!(self.stop());
         try!(self.start(pkg));
         Ok(())
     }
 
+    /// Makes the process spawned by `cmd` join the service's cgroup before it executes, creating
+    /// the cgroup first if needed. Returns the file to keep open until the process is spawned.
+    /// Failing to set up the cgroup is logged but doesn't prevent the process from running.
+    fn apply_resource_limits(&mut self, cmd: &mut Command) -> Option<File> {
+        let limits = match self.resource_limits {
+            Some(ref limits) => limits,
+            None => return None,
+        };
+        if self.cgroup.is_none() {
+            match Cgroup::create(&self.service_group, limits) {
+                Ok(cgroup) => self.cgroup = cgroup,
+                Err(err) => {
+                    outputln!(preamble self.preamble, "Failed to apply resource limits, {}", err);
+                    return None;
+                }
+            }
+        }
+        match self.cgroup {
+            Some(ref cgroup) => {
+                match cgroup.join_before_exec(cmd) {
+                    Ok(procs) => Some(procs),
+                    Err(err) => {
+                        outputln!(preamble self.preamble,
+                                  "Failed to apply resource limits, {}",
+                                  err);
+                        None
+                    }
+                }
+            }
+            None => None,
+        }
+    }
+
+    /// Decide whether a process which is no longer running should be started again, according to
+    /// the restart policy. Restarts in a row are delayed by an exponential backoff and a process
+    /// which keeps exiting is marked as crash looping.
+    pub fn should_restart(&mut self) -> bool {
+        match self.restart_config.policy {
+            RestartPolicy::Never => return false,
+            RestartPolicy::OnFailure if self.last_exit_success => return false,
+            _ => (),
+        }
+        if let Some(max_retries) = self.restart_config.max_retries {
+            if self.restart_count >= max_retries {
+                if self.state != ProcessState::CrashLooping {
+                    outputln!(preamble self.preamble,
+                              "Giving up after {} restarts in a row",
+                              self.restart_count);
+                    self.enter_state(ProcessState::CrashLooping);
+                }
+                return false;
+            }
+        }
+        let backoff = self.restart_config.backoff(self.restart_count);
+        if let Some(exited_at) = self.exited_at {
+            if exited_at.elapsed() < backoff {
+                if self.restart_count >= CRASH_LOOP_THRESHOLD &&
+                   self.state != ProcessState::CrashLooping {
+                    outputln!(preamble self.preamble,
+                              "Crash looping, restarted {} times in a row",
+                              self.restart_count);
+                    self.enter_state(ProcessState::CrashLooping);
+                }
+                return false;
+            }
+        }
+        self.restart_count += 1;
+        // Start the backoff from now in case the process fails to start at all
+        self.exited_at = Some(Instant::now());
+        true
+    }
+
+    /// Exit code of the last process which exited, not set if it was killed by a signal.
+    pub fn last_exit_code(&self) -> Option<u32> {
+        self.last_exit_code
+    }
+
+    /// Whether the process is failing: it is crash looping, or it last exited with a non-zero code
+    /// and is either down or hasn't recovered from its restarts yet. A process which exited with
+    /// a zero code isn't failing, whatever the restart policy.
+    pub fn failing(&self) -> bool {
+        if self.state == ProcessState::CrashLooping {
+            return true;
+        }
+        let failed = self.last_exit_code.map_or(false, |code| code != 0);
+        failed && (self.state == ProcessState::Down || self.restart_count > 0)
+    }
+
     /// if the child process exists, check it's status via waitpid().
     pub fn check_process(&mut self) {
+        if let Some(ref cgroup) = self.cgroup {
+            cgroup.update_metrics();
+        }
         let changed = match self.child {
             None => false,
             Some(ref mut child) => {
                 match child.status() {
                     Ok(ref status) if status.no_status() => false,
                     Ok(ref status) => {
+                        self.last_exit_success = status.code() == Some(0);
+                        self.last_exit_code = status.code();
                         if status.code().is_some() {
                             outputln!("{} - process {} died with exit code {}",
                                       self.preamble,
@@ -185,16 +365,27 @@ impl Supervisor {
             }
         };
         if changed {
+            self.exited_at = Some(Instant::now());
             match self.state {
-                ProcessState::Up | ProcessState::Start | ProcessState::Restart => {
+                ProcessState::Up | ProcessState::Start | ProcessState::Restart |
+                ProcessState::CrashLooping => {
                     outputln!("{} - Service exited", self.preamble);
                     self.child = None;
+                    self.enter_state(ProcessState::Down);
                 }
                 ProcessState::Down => {
                     self.enter_state(ProcessState::Down);
                     self.child = None;
                 }
             }
+        } else if self.restart_count > 0 && self.child.is_some() {
+            // The process has stayed up long enough, consider it recovered
+            let recovered = self.started_at
+                .map(|t| t.elapsed() >= self.restart_config.backoff_ceiling)
+                .unwrap_or(false);
+            if recovered {
+                self.restart_count = 0;
+            }
         }
     }
 
@@ -240,12 +431,16 @@ impl Serialize for Supervisor {
             Some(ref child) => Some(child.id()),
             None => None,
         };
-        let mut strukt = try!(serializer.serialize_struct("supervisor", 5));
+        let resource_usage = self.cgroup.as_ref().map(|cgroup| cgroup.usage());
+        let mut strukt = try!(serializer.serialize_struct("supervisor", 8));
         try!(strukt.serialize_field("pid", &pid));
         try!(strukt.serialize_field("preamble", &self.preamble));
         try!(strukt.serialize_field("state", &self.state));
         try!(strukt.serialize_field("state_entered", &self.state_entered.sec));
         try!(strukt.serialize_field("started", &self.has_started));
+        try!(strukt.serialize_field("restart_count", &self.restart_count));
+        try!(strukt.serialize_field("resource_limits", &self.resource_limits));
+        try!(strukt.serialize_field("resource_usage", &resource_usage));
         strukt.end()
     }
 }
@@ -256,22 +451,215 @@ impl Drop for Supervisor {
     }
 }
 
-/// Consume output from a child process until EOF, then finish
-fn child_reader(child: &mut Child, package_name: String) -> Result<()> {
-    let c_stdout = match child.stdout {
-        Some(ref mut s) => s,
+/// Consume one output stream of a child process until EOF, then finish
+fn child_reader<R: Read>(output: Option<R>, package_name: String, stream: Stream) -> Result<()> {
+    let output = match output {
+        Some(output) => output,
         None => return Err(sup_error!(Error::UnpackFailed)),
     };
+    let logkey = match stream {
+        Stream::Stdout => "O",
+        Stream::Stderr => "E",
+    };
 
-    let mut reader = BufReader::new(c_stdout);
+    let mut reader = BufReader::new(output);
     let mut buffer = String::new();
 
     while reader.read_line(&mut buffer).unwrap() > 0 {
-        let mut line = output_format!(preamble &package_name, logkey "O");
-        line.push_str(&buffer);
-        print!("{}", line);
+        {
+            let mut so = StructuredOutput::new(&package_name,
+                                               logkey,
+                                               line!(),
+                                               file!(),
+                                               column!(),
+                                               buffer.trim_right_matches('\n'));
+            so.stream = Some(stream);
+            println!("{}", so);
+        }
         buffer.clear();
     }
     debug!("child_reader exiting");
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+    use std::time::{Duration, Instant};
+
+    use hcore::os::process::Signal;
+    use hcore::service::ServiceGroup;
+
+    use super::{ProcessState, RestartConfig, StopConfig, Supervisor};
+    use manager::events::{self, Event};
+    use manager::service::RestartPolicy;
+
+    fn supervisor(service_group: &ServiceGroup, policy: RestartPolicy) -> Supervisor {
+        let restart_config = RestartConfig {
+            policy: policy,
+            max_retries: None,
+            backoff_base: Duration::from_secs(1),
+            backoff_ceiling: Duration::from_secs(10),
+        };
+        let stop_config = StopConfig {
+            signal: Signal::TERM,
+            timeout: Duration::from_secs(8),
+        };
+        Supervisor::new(service_group, restart_config, stop_config, None)
+    }
+
+    fn state_changes(rx: &::std::sync::mpsc::Receiver<Event>, sg: &ServiceGroup) -> Vec<String> {
+        rx.try_iter()
+            .filter_map(|e| match e {
+                            Event::ProcessStateChanged { service_group, state, .. } => {
+                                if service_group == *sg { Some(state) } else { None }
+                            }
+                            _ => None,
+                        })
+            .collect()
+    }
+
+    /// A supervisor whose process last exited `ago` ago, with the given exit status, after
+    /// `restarts` restarts in a row.
+    fn exited(policy: RestartPolicy, success: bool, restarts: u32, ago: Duration) -> Supervisor {
+        let sg = ServiceGroup::from_str("redis.supervisor-restart").unwrap();
+        let mut supervisor = supervisor(&sg, policy);
+        supervisor.last_exit_success = success;
+        supervisor.last_exit_code = Some(if success { 0 } else { 1 });
+        supervisor.restart_count = restarts;
+        supervisor.exited_at = Some(Instant::now() - ago);
+        supervisor
+    }
+
+    #[test]
+    fn never_restarts() {
+        for success in vec![true, false] {
+            let mut supervisor = exited(RestartPolicy::Never, success, 0, Duration::from_secs(60));
+            assert!(!supervisor.should_restart());
+            assert_eq!(supervisor.restart_count, 0);
+        }
+    }
+
+    #[test]
+    fn on_failure_restarts_only_failed_processes() {
+        let mut supervisor =
+            exited(RestartPolicy::OnFailure, true, 0, Duration::from_secs(60));
+        assert!(!supervisor.should_restart());
+
+        let mut supervisor =
+            exited(RestartPolicy::OnFailure, false, 0, Duration::from_secs(60));
+        assert!(supervisor.should_restart());
+        assert_eq!(supervisor.restart_count, 1);
+    }
+
+    #[test]
+    fn always_restarts() {
+        for success in vec![true, false] {
+            let mut supervisor =
+                exited(RestartPolicy::Always, success, 0, Duration::from_secs(60));
+            assert!(supervisor.should_restart());
+            assert_eq!(supervisor.restart_count, 1);
+        }
+    }
+
+    #[test]
+    fn restarts_wait_for_the_backoff() {
+        // One restart in a row waits for twice the 1 second backoff base.
+        let mut supervisor =
+            exited(RestartPolicy::Always, false, 1, Duration::from_millis(1500));
+        assert!(!supervisor.should_restart());
+        assert_eq!(supervisor.restart_count, 1);
+        assert_eq!(supervisor.state, ProcessState::Down);
+
+        let mut supervisor = exited(RestartPolicy::Always, false, 1, Duration::from_secs(3));
+        assert!(supervisor.should_restart());
+        assert_eq!(supervisor.restart_count, 2);
+    }
+
+    #[test]
+    fn restarting_within_the_backoff_is_a_crash_loop() {
+        let mut supervisor = exited(RestartPolicy::OnFailure, false, 3, Duration::from_secs(1));
+        assert!(!supervisor.should_restart());
+        assert_eq!(supervisor.state, ProcessState::CrashLooping);
+
+        // A crash looping process is still restarted once its backoff has passed.
+        supervisor.exited_at = Some(Instant::now() - Duration::from_secs(60));
+        assert!(supervisor.should_restart());
+        assert_eq!(supervisor.restart_count, 4);
+    }
+
+    #[test]
+    fn gives_up_after_max_retries() {
+        let mut supervisor = exited(RestartPolicy::Always, false, 2, Duration::from_secs(60));
+        supervisor.restart_config.max_retries = Some(2);
+        assert!(!supervisor.should_restart());
+        assert_eq!(supervisor.state, ProcessState::CrashLooping);
+        assert_eq!(supervisor.restart_count, 2);
+
+        let mut supervisor = exited(RestartPolicy::Always, false, 1, Duration::from_secs(60));
+        supervisor.restart_config.max_retries = Some(2);
+        assert!(supervisor.should_restart());
+    }
+
+    #[test]
+    fn clean_exit_is_not_failing() {
+        for policy in vec![RestartPolicy::Never, RestartPolicy::OnFailure, RestartPolicy::Always] {
+            let supervisor = exited(policy, true, 0, Duration::from_secs(60));
+            assert!(!supervisor.failing());
+        }
+        let supervisor = exited(RestartPolicy::Always, true, 2, Duration::from_secs(60));
+        assert!(!supervisor.failing());
+    }
+
+    #[test]
+    fn non_zero_exit_is_failing() {
+        let supervisor = exited(RestartPolicy::Never, false, 0, Duration::from_secs(60));
+        assert!(supervisor.failing());
+
+        // Restarted after a failure, until it has stayed up long enough to recover.
+        let mut supervisor = exited(RestartPolicy::OnFailure, false, 1, Duration::from_secs(60));
+        supervisor.state = ProcessState::Up;
+        assert!(supervisor.failing());
+        supervisor.restart_count = 0;
+        assert!(!supervisor.failing());
+    }
+
+    #[test]
+    fn crash_loop_is_failing() {
+        let mut supervisor = exited(RestartPolicy::Always, true, 3, Duration::from_secs(1));
+        supervisor.state = ProcessState::CrashLooping;
+        assert!(supervisor.failing());
+    }
+
+    #[test]
+    fn reentering_a_state_is_not_a_transition() {
+        let sg = ServiceGroup::from_str("redis.supervisor-reenter").unwrap();
+        let mut supervisor = supervisor(&sg, RestartPolicy::Always);
+        let rx = events::subscribe();
+        let entered = supervisor.state_entered;
+
+        supervisor.enter_state(ProcessState::Down);
+        assert!(state_changes(&rx, &sg).is_empty());
+        assert_eq!(supervisor.state_entered, entered);
+
+        supervisor.enter_state(ProcessState::Restart);
+        supervisor.enter_state(ProcessState::Down);
+        assert_eq!(state_changes(&rx, &sg), vec!["restart", "down"]);
+    }
+
+    #[test]
+    fn restart_backoff_doubles_up_to_ceiling() {
+        let config = RestartConfig {
+            policy: RestartPolicy::Always,
+            max_retries: None,
+            backoff_base: Duration::from_secs(1),
+            backoff_ceiling: Duration::from_secs(10),
+        };
+
+        assert_eq!(config.backoff(0), Duration::from_secs(1));
+        assert_eq!(config.backoff(1), Duration::from_secs(2));
+        assert_eq!(config.backoff(3), Duration::from_secs(8));
+        assert_eq!(config.backoff(4), Duration::from_secs(10));
+        assert_eq!(config.backoff(64), Duration::from_secs(10));
+    }
+}
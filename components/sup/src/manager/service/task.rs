@@ -0,0 +1,197 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Task services.
+//!
+//! A service with the `task` topology runs its process to completion instead of keeping it up. It
+//! runs once after being loaded or updated, or whenever its schedule comes due. Schedules are cron
+//! expressions such as `0 3 * * *`, evaluated in UTC. A run which comes due while the previous one
+//! is still going is skipped.
+
+use std::fmt;
+use std::result;
+use std::str::FromStr;
+
+use hcore::util::deserialize_using_from_str;
+use serde;
+use time::{self, Timespec};
+
+use super::UpdateWindow;
+use error::{Error, SupError};
+
+static LOGKEY: &'static str = "TS";
+/// How far ahead the next run of a schedule is looked for, in minutes.
+const MAX_SCHEDULE_LOOKAHEAD: i64 = 366 * 24 * 60;
+
+/// The minutes during which a task service is started.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TaskSchedule(UpdateWindow);
+
+impl TaskSchedule {
+    /// Returns the start of the first minute after the given time which the schedule matches, if
+    /// it matches one within the next year.
+    pub fn next_after(&self, after: Timespec) -> Option<Timespec> {
+        let first = after.sec - after.sec % 60 + 60;
+        (0..MAX_SCHEDULE_LOOKAHEAD)
+            .map(|minute| Timespec::new(first + minute * 60, 0))
+            .find(|minute| self.0.contains(&time::at_utc(*minute)))
+    }
+}
+
+impl fmt::Display for TaskSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for TaskSchedule {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        // Only cron expressions are accepted, day and time ranges don't say when to start.
+        if value.split_whitespace().count() != 5 {
+            return Err(sup_error!(Error::BadTaskSchedule(value.to_string())));
+        }
+        match UpdateWindow::from_str(value) {
+            Ok(window) => Ok(TaskSchedule(window)),
+            Err(_) => Err(sup_error!(Error::BadTaskSchedule(value.to_string()))),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TaskSchedule {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer<'de>
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for TaskSchedule {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// The runs of a task service, as reported by the Supervisor. Times are in seconds since the
+/// epoch.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct TaskStatus {
+    /// Whether a run is going.
+    pub running: bool,
+    /// When the last run was started.
+    pub last_run: Option<i64>,
+    /// Exit code of the last finished run, not set if it was killed by a signal.
+    pub last_exit_code: Option<u32>,
+    /// When the next run is due, not set once a task without a schedule has run.
+    pub next_run: Option<i64>,
+}
+
+impl TaskStatus {
+    /// Whether the last run of the task exited successfully, and no new run is going.
+    pub fn succeeded(&self) -> bool {
+        !self.running && self.last_exit_code == Some(0)
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.running {
+            write!(f, "running")?;
+        } else {
+            write!(f, "idle")?;
+        }
+        if let Some(last_run) = self.last_run {
+            write!(f, ", last run:{}", rfc3339(last_run))?;
+        }
+        if let Some(code) = self.last_exit_code {
+            write!(f, ", exit code:{}", code)?;
+        }
+        if let Some(next_run) = self.next_run {
+            write!(f, ", next run:{}", rfc3339(next_run))?;
+        }
+        Ok(())
+    }
+}
+
+fn rfc3339(secs: i64) -> String {
+    time::at_utc(Timespec::new(secs, 0)).rfc3339().to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use time::Timespec;
+
+    use super::*;
+    use error::Error::*;
+
+    // Saturday 2017-06-03 03:30:20 UTC.
+    const SATURDAY_EARLY: i64 = 1496460620;
+
+    #[test]
+    fn task_schedule_next_after() {
+        let schedule = TaskSchedule::from_str("*/15 * * * *").unwrap();
+        assert_eq!(schedule.next_after(Timespec::new(SATURDAY_EARLY, 0)),
+                   Some(Timespec::new(1496461500, 0)));
+        let schedule = TaskSchedule::from_str("0 3 * * *").unwrap();
+        assert_eq!(schedule.next_after(Timespec::new(SATURDAY_EARLY, 0)),
+                   Some(Timespec::new(1496545200, 0)));
+    }
+
+    #[test]
+    fn task_schedule_next_after_starts_at_the_next_minute() {
+        let schedule = TaskSchedule::from_str("* * * * *").unwrap();
+        assert_eq!(schedule.next_after(Timespec::new(1496460600, 0)),
+                   Some(Timespec::new(1496460660, 0)));
+    }
+
+    #[test]
+    fn task_schedule_never_due() {
+        let schedule = TaskSchedule::from_str("0 0 31 feb *").unwrap();
+        assert_eq!(schedule.next_after(Timespec::new(SATURDAY_EARLY, 0)), None);
+    }
+
+    #[test]
+    fn task_status_succeeded() {
+        let mut status = TaskStatus::default();
+        assert!(!status.succeeded());
+        status.running = true;
+        assert!(!status.succeeded());
+        status.running = false;
+        status.last_exit_code = Some(1);
+        assert!(!status.succeeded());
+        status.last_exit_code = Some(0);
+        assert!(status.succeeded());
+        // A successful task which runs again is waited for until the new run is done too.
+        status.running = true;
+        assert!(!status.succeeded());
+    }
+
+    #[test]
+    fn task_schedule_rejects_ranges() {
+        match TaskSchedule::from_str("Mon-Fri 22:00-02:00") {
+            Err(e) => {
+                match e.err {
+                    BadTaskSchedule(ref schedule) => assert_eq!(schedule, "Mon-Fri 22:00-02:00"),
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("Day and time ranges should not be accepted as a schedule"),
+        }
+    }
+}
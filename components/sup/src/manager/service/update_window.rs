@@ -0,0 +1,541 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Update windows.
+//!
+//! An update window restricts when the service updater may swap in a new release of a service. A
+//! window is either a cron expression such as `* 2-5 * * sat,sun`, which is open during every
+//! minute it matches, or a day and time range such as `Mon-Fri 22:00-02:00`, where a range ending
+//! before it starts runs past midnight into the next day. Windows are evaluated in the service's
+//! update timezone.
+
+use std::fmt;
+use std::result;
+use std::str::FromStr;
+
+use hcore::util::deserialize_using_from_str;
+use serde;
+use time::{self, Duration, Tm};
+
+use error::{Error, SupError};
+
+static LOGKEY: &'static str = "UW";
+const DAY_NAMES: [&'static str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+const MONTH_NAMES: [&'static str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug",
+                                         "sep", "oct", "nov", "dec"];
+const ALL_DAYS: u64 = 0b111_1111;
+/// Largest offset from UTC accepted for an update timezone, in minutes.
+const MAX_OFFSET: i32 = 14 * 60;
+
+/// Timezone in which a service's update windows are evaluated.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum UpdateTimezone {
+    Utc,
+    Local,
+    /// Fixed offset from UTC, in minutes.
+    Offset(i32),
+}
+
+impl UpdateTimezone {
+    /// Returns the current time in this timezone.
+    pub fn now(&self) -> Tm {
+        match *self {
+            UpdateTimezone::Utc => time::now_utc(),
+            UpdateTimezone::Local => time::now(),
+            UpdateTimezone::Offset(minutes) => {
+                time::at_utc(time::get_time() + Duration::minutes(minutes as i64))
+            }
+        }
+    }
+}
+
+impl Default for UpdateTimezone {
+    fn default() -> UpdateTimezone {
+        UpdateTimezone::Utc
+    }
+}
+
+impl fmt::Display for UpdateTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            UpdateTimezone::Utc => write!(f, "UTC"),
+            UpdateTimezone::Local => write!(f, "local"),
+            UpdateTimezone::Offset(minutes) => {
+                let sign = if minutes < 0 { '-' } else { '+' };
+                write!(f,
+                       "{}{:02}:{:02}",
+                       sign,
+                       minutes.abs() / 60,
+                       minutes.abs() % 60)
+            }
+        }
+    }
+}
+
+impl FromStr for UpdateTimezone {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let lower = value.trim().to_lowercase();
+        match lower.as_ref() {
+            "utc" | "z" => return Ok(UpdateTimezone::Utc),
+            "local" => return Ok(UpdateTimezone::Local),
+            _ => (),
+        }
+        let offset = if lower.starts_with("utc") {
+            &lower[3..]
+        } else {
+            &lower[..]
+        };
+        parse_offset(offset)
+            .map(UpdateTimezone::Offset)
+            .map_err(|_| sup_error!(Error::BadUpdateTimezone(value.to_string())))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for UpdateTimezone {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer<'de>
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for UpdateTimezone {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// A period of time during which a service may be updated.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct UpdateWindow {
+    expr: String,
+    schedule: Schedule,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Schedule {
+    /// Values matched by each field of a cron expression, as bit sets.
+    Cron {
+        minutes: u64,
+        hours: u64,
+        days_of_month: u64,
+        months: u64,
+        days_of_week: u64,
+        any_day_of_month: bool,
+        any_day_of_week: bool,
+    },
+    /// Days of the week the range starts on, as a bit set, and the start and end of the range in
+    /// minutes since midnight.
+    Range { days: u64, start: u32, end: u32 },
+}
+
+impl UpdateWindow {
+    /// Whether the window is open at the given time.
+    pub fn contains(&self, tm: &Tm) -> bool {
+        let weekday = tm.tm_wday as u32;
+        match self.schedule {
+            Schedule::Cron { minutes,
+                             hours,
+                             days_of_month,
+                             months,
+                             days_of_week,
+                             any_day_of_month,
+                             any_day_of_week } => {
+                let day_of_month = is_set(days_of_month, tm.tm_mday as u32);
+                let day_of_week = is_set(days_of_week, weekday);
+                // Like cron, a day matches either day field when both are restricted.
+                let day = if any_day_of_month || any_day_of_week {
+                    day_of_month && day_of_week
+                } else {
+                    day_of_month || day_of_week
+                };
+                day && is_set(minutes, tm.tm_min as u32) && is_set(hours, tm.tm_hour as u32) &&
+                is_set(months, tm.tm_mon as u32 + 1)
+            }
+            Schedule::Range { days, start, end } => {
+                let minute = (tm.tm_hour * 60 + tm.tm_min) as u32;
+                if start < end {
+                    is_set(days, weekday) && minute >= start && minute < end
+                } else {
+                    (is_set(days, weekday) && minute >= start) ||
+                    (is_set(days, (weekday + 6) % 7) && minute < end)
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for UpdateWindow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expr)
+    }
+}
+
+impl FromStr for UpdateWindow {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let fields: Vec<&str> = value.split_whitespace().collect();
+        let schedule = if fields.len() == 5 {
+            parse_cron(&fields)
+        } else {
+            parse_range(&fields)
+        };
+        match schedule {
+            Ok(schedule) => {
+                Ok(UpdateWindow {
+                       expr: fields.join(" "),
+                       schedule: schedule,
+                   })
+            }
+            Err(_) => Err(sup_error!(Error::BadUpdateWindow(value.to_string()))),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for UpdateWindow {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer<'de>
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for UpdateWindow {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Whether a service with the given update windows may be updated at the given time. A service
+/// without update windows may always be updated.
+pub fn is_open(windows: &[UpdateWindow], tm: &Tm) -> bool {
+    windows.is_empty() || windows.iter().any(|window| window.contains(tm))
+}
+
+fn is_set(bits: u64, value: u32) -> bool {
+    bits & (1 << value) != 0
+}
+
+fn parse_cron(fields: &[&str]) -> result::Result<Schedule, ()> {
+    let mut days_of_week = parse_cron_field(fields[4], 0, 7, &DAY_NAMES)?;
+    // Both 0 and 7 are Sunday.
+    if is_set(days_of_week, 7) {
+        days_of_week = (days_of_week | 1) & ALL_DAYS;
+    }
+    Ok(Schedule::Cron {
+           minutes: parse_cron_field(fields[0], 0, 59, &[])?,
+           hours: parse_cron_field(fields[1], 0, 23, &[])?,
+           days_of_month: parse_cron_field(fields[2], 1, 31, &[])?,
+           months: parse_cron_field(fields[3], 1, 12, &MONTH_NAMES)?,
+           days_of_week: days_of_week,
+           any_day_of_month: fields[2] == "*",
+           any_day_of_week: fields[4] == "*",
+       })
+}
+
+/// Parses a comma separated list of values, ranges and steps such as `1,3-5,*/15` into a bit set.
+/// Values may be given by name, the first name being the minimum value.
+fn parse_cron_field(field: &str, min: u32, max: u32, names: &[&str]) -> result::Result<u64, ()> {
+    let mut bits = 0;
+    for part in field.split(',') {
+        let mut split = part.splitn(2, '/');
+        let range = split.next().unwrap();
+        let step = match split.next() {
+            Some(step) => u32::from_str(step).map_err(|_| ())?,
+            None => 1,
+        };
+        if step == 0 {
+            return Err(());
+        }
+        let (first, last) = if range == "*" {
+            (min, max)
+        } else {
+            let mut bounds = range.splitn(2, '-');
+            let first = parse_cron_value(bounds.next().unwrap(), min, names)?;
+            let last = match bounds.next() {
+                Some(last) => parse_cron_value(last, min, names)?,
+                None if step > 1 => max,
+                None => first,
+            };
+            (first, last)
+        };
+        if first < min || last > max || first > last {
+            return Err(());
+        }
+        let mut value = first;
+        while value <= last {
+            bits |= 1 << value;
+            value += step;
+        }
+    }
+    Ok(bits)
+}
+
+fn parse_cron_value(value: &str, min: u32, names: &[&str]) -> result::Result<u32, ()> {
+    let lower = value.to_lowercase();
+    match names.iter().position(|name| *name == lower) {
+        Some(index) => Ok(index as u32 + min),
+        None => u32::from_str(value).map_err(|_| ()),
+    }
+}
+
+fn parse_range(fields: &[&str]) -> result::Result<Schedule, ()> {
+    let (days, times) = match fields.len() {
+        1 => (ALL_DAYS, fields[0]),
+        2 => (parse_days(fields[0])?, fields[1]),
+        _ => return Err(()),
+    };
+    let mut bounds = times.splitn(2, '-');
+    let start = parse_time(bounds.next().unwrap())?;
+    let end = parse_time(bounds.next().unwrap_or(""))?;
+    if start == end || start >= 24 * 60 {
+        return Err(());
+    }
+    Ok(Schedule::Range {
+           days: days,
+           start: start,
+           end: end,
+       })
+}
+
+/// Parses a comma separated list of days and day ranges such as `mon-fri,sun` into a bit set.
+/// Ranges may wrap around the end of the week, as in `fri-mon`.
+fn parse_days(days: &str) -> result::Result<u64, ()> {
+    let mut bits = 0;
+    for part in days.split(',') {
+        let mut bounds = part.splitn(2, '-');
+        let first = parse_day(bounds.next().unwrap())?;
+        let last = match bounds.next() {
+            Some(last) => parse_day(last)?,
+            None => first,
+        };
+        let mut day = first;
+        loop {
+            bits |= 1 << day;
+            if day == last {
+                break;
+            }
+            day = (day + 1) % 7;
+        }
+    }
+    Ok(bits)
+}
+
+fn parse_day(day: &str) -> result::Result<u32, ()> {
+    let lower = day.to_lowercase();
+    DAY_NAMES
+        .iter()
+        .position(|name| lower.starts_with(name))
+        .map(|index| index as u32)
+        .ok_or(())
+}
+
+/// Parses a time of day such as `22:00` into minutes since midnight. `24:00` is the end of the
+/// day.
+fn parse_time(value: &str) -> result::Result<u32, ()> {
+    let mut parts = value.splitn(2, ':');
+    let hour = u32::from_str(parts.next().unwrap()).map_err(|_| ())?;
+    let minute = u32::from_str(parts.next().unwrap_or("")).map_err(|_| ())?;
+    if minute > 59 || hour > 24 || (hour == 24 && minute != 0) {
+        return Err(());
+    }
+    Ok(hour * 60 + minute)
+}
+
+/// Parses an offset from UTC such as `+02:00`, `-0530` or `+1` into minutes.
+fn parse_offset(value: &str) -> result::Result<i32, ()> {
+    let sign = match value.chars().next() {
+        Some('+') => 1,
+        Some('-') => -1,
+        _ => return Err(()),
+    };
+    let digits: String = value[1..].chars().filter(|c| *c != ':').collect();
+    let (hours, minutes) = match digits.len() {
+        1 | 2 => (&digits[..], "0"),
+        4 => (&digits[..2], &digits[2..]),
+        _ => return Err(()),
+    };
+    let hours = i32::from_str(hours).map_err(|_| ())?;
+    let minutes = i32::from_str(minutes).map_err(|_| ())?;
+    let offset = hours * 60 + minutes;
+    if minutes > 59 || offset > MAX_OFFSET {
+        return Err(());
+    }
+    Ok(sign * offset)
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use time::{self, Timespec, Tm};
+    use toml;
+
+    use super::*;
+    use error::Error::*;
+
+    // Saturday 2017-06-03 03:30 UTC.
+    const SATURDAY_EARLY: i64 = 1496460600;
+    // Saturday 2017-06-03 12:00 UTC.
+    const SATURDAY_NOON: i64 = 1496491200;
+    // Monday 2017-06-05 23:30 UTC.
+    const MONDAY_LATE: i64 = 1496705400;
+    // Tuesday 2017-06-06 01:30 UTC.
+    const TUESDAY_EARLY: i64 = 1496712600;
+
+    fn at(secs: i64) -> Tm {
+        time::at_utc(Timespec::new(secs, 0))
+    }
+
+    #[test]
+    fn cron_window() {
+        let window = UpdateWindow::from_str("* 2-5 * * sat,sun").unwrap();
+        assert!(window.contains(&at(SATURDAY_EARLY)));
+        assert!(!window.contains(&at(SATURDAY_NOON)));
+        assert!(!window.contains(&at(TUESDAY_EARLY)));
+    }
+
+    #[test]
+    fn cron_window_steps_and_sunday_as_seven() {
+        let window = UpdateWindow::from_str("*/30 3 * jun 7,6").unwrap();
+        assert!(window.contains(&at(SATURDAY_EARLY)));
+        let window = UpdateWindow::from_str("0-29/15 3 * * 6").unwrap();
+        assert!(!window.contains(&at(SATURDAY_EARLY)));
+    }
+
+    #[test]
+    fn cron_window_matches_either_restricted_day() {
+        let window = UpdateWindow::from_str("* * 3 * mon").unwrap();
+        assert!(window.contains(&at(SATURDAY_NOON)));
+        assert!(window.contains(&at(MONDAY_LATE)));
+        assert!(!window.contains(&at(TUESDAY_EARLY)));
+    }
+
+    #[test]
+    fn range_window() {
+        let window = UpdateWindow::from_str("Sat 02:00-06:00").unwrap();
+        assert!(window.contains(&at(SATURDAY_EARLY)));
+        assert!(!window.contains(&at(SATURDAY_NOON)));
+    }
+
+    #[test]
+    fn range_window_past_midnight() {
+        let window = UpdateWindow::from_str("Mon-Fri 22:00-02:00").unwrap();
+        assert!(window.contains(&at(MONDAY_LATE)));
+        assert!(window.contains(&at(TUESDAY_EARLY)));
+        // The range starting on Friday night is over by 03:30 on Saturday.
+        assert!(!window.contains(&at(SATURDAY_EARLY)));
+        let window = UpdateWindow::from_str("fri 22:00-04:00").unwrap();
+        assert!(window.contains(&at(SATURDAY_EARLY)));
+    }
+
+    #[test]
+    fn range_window_every_day() {
+        let window = UpdateWindow::from_str("11:00-24:00").unwrap();
+        assert!(window.contains(&at(SATURDAY_NOON)));
+        assert!(window.contains(&at(MONDAY_LATE)));
+        assert!(!window.contains(&at(TUESDAY_EARLY)));
+    }
+
+    #[test]
+    fn update_window_from_str_invalid() {
+        for value in &["", "Sat", "Sat 06:00-06:00", "Sat 25:00-26:00", "Someday 02:00-03:00",
+                       "60 * * * *", "* * * * 8", "*/0 * * * *", "5-1 * * * *"] {
+            match UpdateWindow::from_str(value) {
+                Err(e) => {
+                    match e.err {
+                        BadUpdateWindow(s) => assert_eq!(*value, s),
+                        wrong => panic!("Unexpected error returned: {:?}", wrong),
+                    }
+                }
+                Ok(_) => panic!("'{}' should fail to parse", value),
+            }
+        }
+    }
+
+    #[test]
+    fn update_window_to_string() {
+        let window = UpdateWindow::from_str("  Sat   02:00-06:00 ").unwrap();
+        assert_eq!("Sat 02:00-06:00", window.to_string());
+    }
+
+    #[test]
+    fn is_open_without_windows() {
+        assert!(is_open(&[], &at(SATURDAY_NOON)));
+        let windows = vec![UpdateWindow::from_str("Sat 02:00-06:00").unwrap(),
+                           UpdateWindow::from_str("* 23 * * mon").unwrap()];
+        assert!(is_open(&windows, &at(MONDAY_LATE)));
+        assert!(!is_open(&windows, &at(SATURDAY_NOON)));
+    }
+
+    #[test]
+    fn update_timezone_from_str() {
+        assert_eq!(UpdateTimezone::from_str("UTC").unwrap(), UpdateTimezone::Utc);
+        assert_eq!(UpdateTimezone::from_str("local").unwrap(),
+                   UpdateTimezone::Local);
+        assert_eq!(UpdateTimezone::from_str("+02:00").unwrap(),
+                   UpdateTimezone::Offset(120));
+        assert_eq!(UpdateTimezone::from_str("UTC-05:30").unwrap(),
+                   UpdateTimezone::Offset(-330));
+        assert_eq!(UpdateTimezone::from_str("+1").unwrap(),
+                   UpdateTimezone::Offset(60));
+    }
+
+    #[test]
+    fn update_timezone_from_str_invalid() {
+        for value in &["Europe/Paris", "+15:00", "+02:75", "02:00"] {
+            match UpdateTimezone::from_str(value) {
+                Err(e) => {
+                    match e.err {
+                        BadUpdateTimezone(s) => assert_eq!(*value, s),
+                        wrong => panic!("Unexpected error returned: {:?}", wrong),
+                    }
+                }
+                Ok(_) => panic!("'{}' should fail to parse", value),
+            }
+        }
+    }
+
+    #[test]
+    fn update_timezone_to_string() {
+        assert_eq!("UTC", UpdateTimezone::Utc.to_string());
+        assert_eq!("-05:30", UpdateTimezone::Offset(-330).to_string());
+        assert_eq!("+02:00", UpdateTimezone::Offset(120).to_string());
+    }
+
+    #[derive(Deserialize)]
+    struct Windows {
+        update_windows: Vec<UpdateWindow>,
+        update_timezone: UpdateTimezone,
+    }
+
+    #[test]
+    fn update_windows_toml_deserialize() {
+        let windows: Windows = toml::from_str(r#"
+            update_windows = ["Sat 02:00-06:00", "0 3 * * *"]
+            update_timezone = "+01:00"
+            "#)
+                .unwrap();
+        assert_eq!(windows.update_windows.len(), 2);
+        assert_eq!(windows.update_windows[1].to_string(), "0 3 * * *");
+        assert_eq!(windows.update_timezone, UpdateTimezone::Offset(60));
+    }
+}
@@ -0,0 +1,300 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Version constraints on service updates.
+//!
+//! A version constraint is a comma separated list of requirements which a release's version must
+//! all meet for the service updater to update to it:
+//!
+//! * `1.4.x` or `1.4.*`: the version starts with `1.4`
+//! * `~1.4.2`: at least `1.4.2` but still starting with `1.4`
+//! * `^1.4.2`: at least `1.4.2` but still starting with `1`, or with `0.y` for a `0.y.z` version
+//! * `>=1.2`, `>1.2`, `<=2.0`, `<2.0`, `=1.4.2` or `1.4.2`: compared to the given version
+//!
+//! Versions are compared like package identifiers are, with `version_sort`.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::result;
+use std::str::FromStr;
+
+use hcore::package::PackageIdent;
+use hcore::package::ident::version_sort;
+use hcore::util::deserialize_using_from_str;
+use serde;
+
+use error::{Error, SupError};
+
+static LOGKEY: &'static str = "VC";
+
+/// Requirements on the version of the releases a service may be updated to.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct VersionConstraint {
+    expr: String,
+    requirements: Vec<Requirement>,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Requirement {
+    /// The version compares to the given version with one of the orderings.
+    Compare(Vec<Ordering>, String),
+    /// The version's leading numbers are the given ones.
+    Prefix(Vec<u64>),
+}
+
+impl Requirement {
+    fn matches(&self, version: &str) -> bool {
+        match *self {
+            Requirement::Compare(ref orderings, ref other) => {
+                match version_sort(version, other) {
+                    Ok(ordering) => orderings.contains(&ordering),
+                    Err(_) => false,
+                }
+            }
+            Requirement::Prefix(ref prefix) => {
+                match version_numbers(version) {
+                    Some(numbers) => numbers.starts_with(prefix),
+                    None => false,
+                }
+            }
+        }
+    }
+}
+
+impl VersionConstraint {
+    /// Whether the given version meets the constraint.
+    pub fn matches(&self, version: &str) -> bool {
+        self.requirements
+            .iter()
+            .all(|requirement| requirement.matches(version))
+    }
+
+    /// Whether the version of the given package meets the constraint. A package without a
+    /// version never does.
+    pub fn matches_ident(&self, ident: &PackageIdent) -> bool {
+        match ident.version {
+            Some(ref version) => self.matches(version),
+            None => false,
+        }
+    }
+}
+
+impl fmt::Display for VersionConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expr)
+    }
+}
+
+impl FromStr for VersionConstraint {
+    type Err = SupError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        let mut requirements = Vec::new();
+        let mut exprs = Vec::new();
+        for expr in value.split(',').map(|expr| expr.trim()) {
+            match parse_requirement(expr, &mut requirements) {
+                Ok(()) => exprs.push(expr),
+                Err(_) => return Err(sup_error!(Error::BadVersionConstraint(value.to_string()))),
+            }
+        }
+        Ok(VersionConstraint {
+               expr: exprs.join(", "),
+               requirements: requirements,
+           })
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for VersionConstraint {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+        where D: serde::Deserializer<'de>
+    {
+        deserialize_using_from_str(deserializer)
+    }
+}
+
+impl serde::Serialize for VersionConstraint {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+fn parse_requirement(expr: &str, requirements: &mut Vec<Requirement>) -> result::Result<(), ()> {
+    if expr == "*" {
+        return Ok(());
+    }
+    let operators: [(&str, &[Ordering]); 5] = [(">=", &[Ordering::Greater, Ordering::Equal]),
+                                                ("<=", &[Ordering::Less, Ordering::Equal]),
+                                                (">", &[Ordering::Greater]),
+                                                ("<", &[Ordering::Less]),
+                                                ("=", &[Ordering::Equal])];
+    for &(operator, orderings) in operators.iter() {
+        if expr.starts_with(operator) {
+            let version = parse_version(&expr[operator.len()..])?;
+            requirements.push(Requirement::Compare(orderings.to_vec(), version));
+            return Ok(());
+        }
+    }
+    if expr.starts_with('~') || expr.starts_with('^') {
+        let version = parse_version(&expr[1..])?;
+        let numbers = version_numbers(&version).ok_or(())?;
+        let len = if expr.starts_with('~') || numbers[0] == 0 {
+            2
+        } else {
+            1
+        };
+        let prefix = numbers.into_iter().take(len).collect();
+        requirements.push(Requirement::Compare(vec![Ordering::Greater, Ordering::Equal], version));
+        requirements.push(Requirement::Prefix(prefix));
+        return Ok(());
+    }
+    let mut parts: Vec<&str> = expr.split('.').collect();
+    match parts.last() {
+        Some(&"x") | Some(&"X") | Some(&"*") => {
+            parts.pop();
+            let mut prefix = Vec::new();
+            for part in parts {
+                prefix.push(u64::from_str(part).map_err(|_| ())?);
+            }
+            if prefix.is_empty() {
+                return Err(());
+            }
+            requirements.push(Requirement::Prefix(prefix));
+            Ok(())
+        }
+        _ => {
+            let version = parse_version(expr)?;
+            requirements.push(Requirement::Compare(vec![Ordering::Equal], version));
+            Ok(())
+        }
+    }
+}
+
+fn parse_version(version: &str) -> result::Result<String, ()> {
+    let version = version.trim();
+    if version.is_empty() || version_sort(version, version).is_err() {
+        return Err(());
+    }
+    Ok(version.to_string())
+}
+
+/// Returns the numbers a version starts with, `[1, 4, 2]` for `1.4.2-rc1`.
+fn version_numbers(version: &str) -> Option<Vec<u64>> {
+    let end = version
+        .find(|c: char| !c.is_digit(10) && c != '.')
+        .unwrap_or(version.len());
+    let mut numbers = Vec::new();
+    for part in version[..end].split('.') {
+        match u64::from_str(part) {
+            Ok(number) => numbers.push(number),
+            Err(_) => return if numbers.is_empty() { None } else { Some(numbers) },
+        }
+    }
+    Some(numbers)
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+
+    use super::*;
+    use error::Error::*;
+
+    fn parse(value: &str) -> VersionConstraint {
+        VersionConstraint::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn wildcard() {
+        let constraint = parse("1.4.x");
+        assert!(constraint.matches("1.4.0"));
+        assert!(constraint.matches("1.4.12"));
+        assert!(constraint.matches("1.4"));
+        assert!(!constraint.matches("1.5.0"));
+        assert!(!constraint.matches("1.5.0-rc1"));
+        assert!(!constraint.matches("1.40.0"));
+        assert!(!constraint.matches("2.4.0"));
+    }
+
+    #[test]
+    fn tilde() {
+        let constraint = parse("~1.4.2");
+        assert!(constraint.matches("1.4.2"));
+        assert!(constraint.matches("1.4.9"));
+        assert!(!constraint.matches("1.4.1"));
+        assert!(!constraint.matches("1.5.0"));
+    }
+
+    #[test]
+    fn caret() {
+        let constraint = parse("^1.4.2");
+        assert!(constraint.matches("1.4.2"));
+        assert!(constraint.matches("1.9.0"));
+        assert!(!constraint.matches("1.4.1"));
+        assert!(!constraint.matches("2.0.0"));
+
+        let constraint = parse("^0.3.1");
+        assert!(constraint.matches("0.3.7"));
+        assert!(!constraint.matches("0.4.0"));
+    }
+
+    #[test]
+    fn comparisons() {
+        let constraint = parse(">=1.2, <2.0");
+        assert!(constraint.matches("1.2.0"));
+        assert!(constraint.matches("1.10.3"));
+        assert!(!constraint.matches("1.1.9"));
+        assert!(!constraint.matches("2.0.0"));
+        assert!(!constraint.matches("master"));
+    }
+
+    #[test]
+    fn exact() {
+        assert!(parse("1.4.2").matches("1.4.2"));
+        assert!(!parse("=1.4.2").matches("1.4.3"));
+        assert!(parse("*").matches("3.0.0"));
+    }
+
+    #[test]
+    fn matches_ident() {
+        let constraint = parse("1.4.x");
+        let ident = PackageIdent::from_str("core/redis/1.4.3/20170101000000").unwrap();
+        assert!(constraint.matches_ident(&ident));
+        let ident = PackageIdent::from_str("core/redis").unwrap();
+        assert!(!constraint.matches_ident(&ident));
+    }
+
+    #[test]
+    fn version_constraint_from_str_invalid() {
+        for value in &["", "x", "1.y.x", ">=", "~", ">=1.2,", "<master"] {
+            match VersionConstraint::from_str(value) {
+                Err(e) => {
+                    match e.err {
+                        BadVersionConstraint(s) => assert_eq!(*value, s),
+                        wrong => panic!("Unexpected error returned: {:?}", wrong),
+                    }
+                }
+                Ok(_) => panic!("'{}' should fail to parse", value),
+            }
+        }
+    }
+
+    #[test]
+    fn version_constraint_to_string() {
+        assert_eq!(">=1.2, <2.0", parse(" >=1.2,<2.0 ").to_string());
+    }
+}
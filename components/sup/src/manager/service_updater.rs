@@ -12,17 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
-use std::path::Path;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
 use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TryRecvError};
 use std::thread;
 use std::time::Duration;
 
 use butterfly;
+use butterfly::rumor::release_blacklist::ReleaseBlacklist;
 use common::ui::UI;
 use depot_client;
 use env;
 use hcore::package::{PackageIdent, PackageInstall};
+use hcore::package::ident::version_sort;
 use hcore::service::ServiceGroup;
 use hcore::crypto::default_cache_key_path;
 use hcore::fs::{CACHE_ARTIFACT_PATH, FS_ROOT_PATH};
@@ -30,8 +37,9 @@ use time::{SteadyTime, Duration as TimeDuration};
 
 use {PRODUCT, VERSION};
 use error::Result;
-use census::CensusRing;
-use manager::service::{Service, Topology, UpdateStrategy};
+use census::{CensusGroup, CensusMember, CensusRing};
+use manager::FsCfg;
+use manager::service::{HealthCheck, Service, Topology, UpdateStrategy, VersionConstraint};
 
 static LOGKEY: &'static str = "SU";
 const FREQUENCY_ENVVAR: &'static str = "HAB_UPDATE_STRATEGY_FREQUENCY_MS";
@@ -61,27 +69,353 @@ enum FollowerState {
     Updating(Receiver<PackageInstall>),
 }
 
+/// Progress of a canary update of a service group, as seen by this Supervisor.
+///
+/// A canary update is a rolling update where the update leader and the next alive members after
+/// it, the canaries, update as soon as the leader runs a new release. The rest of the service
+/// group only continues, one member at a time, once every canary has been running the release
+/// with a healthy health check for the soak period. The release is rejected if a canary which
+/// installed it goes back from it or its health check goes critical during the soak period, and
+/// every member running a rejected release rolls back to its previous release.
+#[derive(Default)]
+struct CanaryRollout {
+    /// Release being rolled out, pinned when the update leader first runs it.
+    candidate: Option<PackageIdent>,
+    /// Release this Supervisor ran before its last update.
+    previous: Option<PackageIdent>,
+    /// Canaries which have been seen running the candidate.
+    updated_canaries: HashSet<String>,
+    /// When all canaries were first seen running the candidate with a healthy health check.
+    soak_started: Option<SteadyTime>,
+    /// Whether the canaries have been healthy on the candidate for the whole soak period.
+    soaked: bool,
+    /// Releases which failed on a canary and are never updated to again.
+    rejected: HashSet<PackageIdent>,
+    rollback: Option<Receiver<PackageInstall>>,
+}
+
+/// A canary of a canary update, as gossiped to the census.
+struct Canary<'a> {
+    member_id: &'a str,
+    pkg: Option<&'a PackageIdent>,
+    health_check: HealthCheck,
+}
+
+impl<'a> From<&'a CensusMember> for Canary<'a> {
+    fn from(member: &'a CensusMember) -> Canary<'a> {
+        Canary {
+            member_id: &member.member_id,
+            pkg: member.pkg.as_ref(),
+            health_check: member.health_check,
+        }
+    }
+}
+
+impl CanaryRollout {
+    /// Follows the canaries of the release being rolled out and rolls the service back if it runs
+    /// a rejected release. Returns whether the service's package was replaced if the rollout
+    /// has taken over the updater, or `None` if the regular rolling update should proceed.
+    fn check(&mut self, service: &mut Service, census_group: &CensusGroup) -> Option<bool> {
+        if self.rollback.is_some() {
+            match self.rollback.as_ref().unwrap().try_recv() {
+                Ok(package) => {
+                    self.rollback = None;
+                    service.update_package(package);
+                    return Some(true);
+                }
+                Err(TryRecvError::Empty) => return Some(false),
+                Err(TryRecvError::Disconnected) => {
+                    debug!("Service Updater worker has died; restarting...");
+                    self.rollback = None;
+                }
+            }
+        }
+        let canaries: Vec<Canary> = census_group
+            .canaries(&service.canary_size)
+            .into_iter()
+            .map(Canary::from)
+            .collect();
+        self.observe(&service.service_group,
+                     census_group
+                         .update_leader()
+                         .and_then(|leader| leader.pkg.as_ref()),
+                     &canaries,
+                     TimeDuration::seconds(service.canary_soak_period as i64),
+                     SteadyTime::now());
+        if self.rejected.contains(&service.pkg.ident) {
+            if let Some(previous) = self.previous.clone() {
+                if previous != service.pkg.ident {
+                    outputln!(preamble service.service_group,
+                              "Rolling back from {} to {}",
+                              service.pkg.ident,
+                              previous);
+                    self.rollback = Some(Worker::new(service)
+                                             .start(&service.service_group, Some(previous)));
+                    return Some(false);
+                }
+            }
+        }
+        None
+    }
+
+    /// Records what the canaries run. A rollout starts when the update leader runs a release
+    /// other than the candidate once the previous rollout has soaked or was rejected, so a
+    /// canary going back from the candidate, the update leader included, rejects it rather than
+    /// starting over.
+    fn observe(&mut self,
+               service_group: &ServiceGroup,
+               leader_pkg: Option<&PackageIdent>,
+               canaries: &[Canary],
+               soak_period: TimeDuration,
+               now: SteadyTime) {
+        let finished = match self.candidate {
+            Some(ref candidate) => self.soaked || self.rejected.contains(candidate),
+            None => true,
+        };
+        if let Some(pkg) = leader_pkg {
+            if finished && self.candidate.as_ref() != Some(pkg) && !self.rejected.contains(pkg) {
+                self.candidate = Some(pkg.clone());
+                self.updated_canaries.clear();
+                self.soak_started = None;
+                self.soaked = false;
+            }
+        }
+        let candidate = match self.candidate {
+            Some(ref candidate) => candidate.clone(),
+            None => return,
+        };
+        if self.soaked || self.rejected.contains(&candidate) {
+            return;
+        }
+        let mut failed = false;
+        let mut healthy = !canaries.is_empty();
+        for canary in canaries.iter() {
+            if canary.pkg == Some(&candidate) {
+                self.updated_canaries.insert(canary.member_id.to_string());
+                if canary.health_check == HealthCheck::Critical {
+                    outputln!(preamble service_group,
+                              "Canary {} health check is critical while running {}",
+                              canary.member_id,
+                              candidate);
+                    failed = true;
+                }
+                if canary.health_check != HealthCheck::Ok {
+                    healthy = false;
+                }
+            } else {
+                healthy = false;
+                if self.updated_canaries.contains(canary.member_id) {
+                    outputln!(preamble service_group,
+                              "Canary {} went back from {}",
+                              canary.member_id,
+                              candidate);
+                    failed = true;
+                }
+            }
+        }
+        if failed {
+            outputln!(preamble service_group,
+                      "Canary update failed, rejecting {}",
+                      candidate);
+            self.rejected.insert(candidate);
+            self.soak_started = None;
+            return;
+        }
+        if !healthy {
+            self.soak_started = None;
+            return;
+        }
+        if self.soak_started.is_none() {
+            self.soak_started = Some(now);
+        }
+        if now - self.soak_started.unwrap() >= soak_period {
+            outputln!(preamble service_group,
+                      "Canaries have run {} for the soak period",
+                      candidate);
+            self.soaked = true;
+        }
+    }
+}
+
+/// Watches the release a service was updated to and rolls the service back to the last release
+/// known to be good if the new one keeps failing.
+///
+/// A release becomes known good once it has run without failing for the rollback window. A
+/// release which fails for the whole rollback window is blacklisted on disk and gossiped to the
+/// service group, so that no updater installs it again.
+struct ReleaseWatch {
+    window: TimeDuration,
+    known_good_file: PathBuf,
+    blacklist_file: PathBuf,
+    /// Last release which ran for the whole rollback window without failing.
+    known_good: Option<PackageIdent>,
+    /// Release being watched and when it was first seen running.
+    evaluating: Option<(PackageIdent, SteadyTime)>,
+    /// When the watched release was first seen failing, if it still is.
+    failing_since: Option<SteadyTime>,
+    /// Releases this Supervisor rolled back from.
+    blacklist: HashSet<PackageIdent>,
+    rollback: Option<Receiver<PackageInstall>>,
+}
+
+impl ReleaseWatch {
+    fn new(service_group: &ServiceGroup, window: u64, fs_cfg: &FsCfg) -> Self {
+        let known_good_file = fs_cfg.known_good_release(service_group);
+        let blacklist_file = fs_cfg.release_blacklist(service_group);
+        ReleaseWatch {
+            window: TimeDuration::seconds(window as i64),
+            known_good: read_releases(&known_good_file).into_iter().next(),
+            blacklist: read_releases(&blacklist_file).into_iter().collect(),
+            known_good_file: known_good_file,
+            blacklist_file: blacklist_file,
+            evaluating: None,
+            failing_since: None,
+            rollback: None,
+        }
+    }
+
+    /// Records how the running release is doing and rolls the service back if it has been failing
+    /// for the rollback window, or was blacklisted here or by another member of the service group.
+    /// Returns whether the service's package was replaced if the watch has taken over the updater,
+    /// or `None` if the regular update strategy should proceed.
+    fn check(&mut self,
+             service: &mut Service,
+             butterfly: &butterfly::Server,
+             census_group: Option<&CensusGroup>)
+             -> Option<bool> {
+        if self.rollback.is_some() {
+            match self.rollback.as_ref().unwrap().try_recv() {
+                Ok(package) => {
+                    self.rollback = None;
+                    service.update_package(package);
+                    return Some(true);
+                }
+                Err(TryRecvError::Empty) => return Some(false),
+                Err(TryRecvError::Disconnected) => {
+                    debug!("Service Updater worker has died; restarting...");
+                    self.rollback = None;
+                }
+            }
+        }
+        let current = service.pkg.ident.clone();
+        let gossiped = census_group.map_or(false, |cg| cg.release_blacklist.contains(&current));
+        if self.blacklist.contains(&current) || gossiped {
+            return self.roll_back(service);
+        }
+        if self.known_good.as_ref() == Some(&current) {
+            self.evaluating = None;
+            self.failing_since = None;
+            return None;
+        }
+        let now = SteadyTime::now();
+        if self.evaluating.as_ref().map(|&(ref ident, _)| ident) != Some(&current) {
+            self.evaluating = Some((current.clone(), now));
+            self.failing_since = None;
+        }
+        let started = self.evaluating.as_ref().unwrap().1;
+        if service.release_failing() {
+            if self.failing_since.is_none() {
+                self.failing_since = Some(now);
+            }
+            if now - self.failing_since.unwrap() < self.window {
+                return None;
+            }
+            outputln!(preamble service.service_group,
+                      "{} has been failing for {}s, blacklisting it",
+                      current,
+                      self.window.num_seconds());
+            self.blacklist.insert(current.clone());
+            write_releases(&self.blacklist_file, &self.blacklist);
+            butterfly.insert_release_blacklist(ReleaseBlacklist::new(service.sys
+                                                                         .member_id
+                                                                         .clone(),
+                                                                     service.service_group
+                                                                         .clone(),
+                                                                     current.to_string()));
+            if self.known_good.is_none() {
+                outputln!(preamble service.service_group,
+                          "No known good release of {} to roll back to",
+                          service.service_group.service());
+            }
+            return self.roll_back(service);
+        }
+        self.failing_since = None;
+        if now - started >= self.window {
+            outputln!(preamble service.service_group, "{} is known good", current);
+            write_releases(&self.known_good_file, Some(&current));
+            self.known_good = Some(current);
+            self.evaluating = None;
+        }
+        None
+    }
+
+    fn roll_back(&mut self, service: &Service) -> Option<bool> {
+        match self.known_good.clone() {
+            Some(known_good) if known_good != service.pkg.ident => {
+                outputln!(preamble service.service_group,
+                          "Rolling back from {} to {}",
+                          service.pkg.ident,
+                          known_good);
+                self.rollback = Some(Worker::new(service)
+                                         .start(&service.service_group, Some(known_good)));
+                Some(false)
+            }
+            _ => None,
+        }
+    }
+}
+
 pub struct ServiceUpdater {
     states: UpdaterStateList,
+    canaries: HashMap<ServiceGroup, CanaryRollout>,
+    watches: HashMap<ServiceGroup, ReleaseWatch>,
+    /// Releases found outside of their service's update windows.
+    pending: HashMap<ServiceGroup, PackageInstall>,
+    /// Releases each service must never be updated to, shared with its update worker.
+    rejected: HashMap<ServiceGroup, Arc<RwLock<HashSet<PackageIdent>>>>,
     butterfly: butterfly::Server,
+    fs_cfg: Arc<FsCfg>,
 }
 
 impl ServiceUpdater {
-    pub fn new(butterfly: butterfly::Server) -> Self {
+    pub fn new(butterfly: butterfly::Server, fs_cfg: Arc<FsCfg>) -> Self {
         ServiceUpdater {
             states: UpdaterStateList::default(),
+            canaries: HashMap::new(),
+            watches: HashMap::new(),
+            pending: HashMap::new(),
+            rejected: HashMap::new(),
             butterfly: butterfly,
+            fs_cfg: fs_cfg,
         }
     }
 
     pub fn add(&mut self, service: &Service) -> bool {
+        if let Some(window) = service.rollback_window {
+            if service.update_strategy != UpdateStrategy::None &&
+               !self.watches.contains_key(&service.service_group) {
+                let watch = ReleaseWatch::new(&service.service_group, window, &self.fs_cfg);
+                for release in watch.blacklist.iter() {
+                    self.butterfly
+                        .insert_release_blacklist(ReleaseBlacklist::new(service.sys
+                                                                            .member_id
+                                                                            .clone(),
+                                                                        service.service_group
+                                                                            .clone(),
+                                                                        release.to_string()));
+                }
+                self.watches.insert(service.service_group.clone(), watch);
+            }
+        }
         match service.update_strategy {
             UpdateStrategy::None => false,
             UpdateStrategy::AtOnce => {
+                let rejected = self.refresh_rejected(service, None);
                 self.states
                     .entry(service.service_group.clone())
                     .or_insert_with(|| {
                                         let rx = Worker::new(service)
+                                            .rejecting(rejected)
                                             .start(&service.service_group, None);
                                         UpdaterState::AtOnce(rx)
                                     });
@@ -93,26 +427,76 @@ impl ServiceUpdater {
                     .or_insert(UpdaterState::Rolling(RollingState::AwaitingElection));
                 true
             }
+            UpdateStrategy::Canary => {
+                self.states
+                    .entry(service.service_group.clone())
+                    .or_insert(UpdaterState::Rolling(RollingState::AwaitingElection));
+                self.canaries
+                    .entry(service.service_group.clone())
+                    .or_insert_with(CanaryRollout::default);
+                true
+            }
         }
     }
 
+    /// Refreshes the releases the service must never be updated to. The returned set is shared
+    /// with the service's update workers, which so skip releases rejected after they started.
+    fn refresh_rejected(&mut self,
+                        service: &Service,
+                        census_group: Option<&CensusGroup>)
+                        -> Arc<RwLock<HashSet<PackageIdent>>> {
+        let releases = rejected_releases(&self.canaries, &self.watches, census_group, service);
+        let rejected = self.rejected
+            .entry(service.service_group.clone())
+            .or_insert_with(Default::default);
+        *rejected.write().expect("Rejected releases lock is poisoned") = releases;
+        rejected.clone()
+    }
+
     pub fn check_for_updated_package(&mut self,
                                      service: &mut Service,
                                      census_ring: &CensusRing)
                                      -> bool {
         let mut updated = false;
+        let mut received = false;
+        let rejected =
+            self.refresh_rejected(service, census_ring.census_group_for(&service.service_group));
+        if let Some(watch) = self.watches.get_mut(&service.service_group) {
+            let census_group = census_ring.census_group_for(&service.service_group);
+            if let Some(updated) = watch.check(service, &self.butterfly, census_group) {
+                return updated;
+            }
+        }
+        if let Some(package) = self.pending.remove(&service.service_group) {
+            if !service.in_update_window() {
+                self.pending.insert(service.service_group.clone(), package);
+                return false;
+            }
+            outputln!(preamble service.service_group,
+                      "Update window is open, applying {}",
+                      package.ident);
+            service.pending_update = None;
+            service.update_package(package);
+            return true;
+        }
+        if let Some(rollout) = self.canaries.get_mut(&service.service_group) {
+            if let Some(census_group) = census_ring.census_group_for(&service.service_group) {
+                if let Some(updated) = rollout.check(service, census_group) {
+                    return updated;
+                }
+            }
+        }
         match self.states.get_mut(&service.service_group) {
             Some(&mut UpdaterState::AtOnce(ref mut rx)) => {
                 match rx.try_recv() {
-                    Ok(package) => {
-                        service.update_package(package);
-                        return true;
-                    }
+                    Ok(package) => return apply_update(&mut self.pending, service, package),
                     Err(TryRecvError::Empty) => return false,
                     Err(TryRecvError::Disconnected) => {}
                 }
                 debug!("Service Updater worker has died; restarting...");
-                *rx = Worker::new(service).start(&service.service_group, None);
+                *rx = Worker::new(service)
+                    .rejecting(rejected.clone())
+                    .start(&service.service_group, None);
             }
             Some(&mut UpdaterState::Rolling(ref mut st @ RollingState::AwaitingElection)) => {
                 if let Some(census_group) = census_ring.census_group_for(&service.service_group) {
@@ -167,13 +551,19 @@ impl ServiceUpdater {
                         match rx.try_recv() {
                             Ok(package) => {
                                 debug!("Rolling Update, polling found a new package");
-                                service.update_package(package);
-                                updated = true;
+                                if let Some(rollout) =
+                                    self.canaries.get_mut(&service.service_group) {
+                                    rollout.previous = Some(service.pkg.ident.clone());
+                                }
+                                updated = apply_update(&mut self.pending, service, package);
+                                received = true;
                             }
                             Err(TryRecvError::Empty) => return false,
                             Err(TryRecvError::Disconnected) => {
                                 debug!("Service Updater worker has died; restarting...");
-                                *rx = Worker::new(service).start(&service.service_group, None);
+                                *rx = Worker::new(service)
+                                    .rejecting(rejected.clone())
+                                    .start(&service.service_group, None);
                             }
                         }
                     }
@@ -190,7 +580,9 @@ impl ServiceUpdater {
                                     debug!("Update leader still waiting for followers...");
                                     return false;
                                 }
-                                let rx = Worker::new(service).start(&service.service_group, None);
+                                let rx = Worker::new(service)
+                                    .rejecting(rejected.clone())
+                                    .start(&service.service_group, None);
                                 *state = LeaderState::Polling(rx);
                             }
                             None => {
@@ -200,7 +592,7 @@ impl ServiceUpdater {
                         }
                     }
                 }
-                if updated {
+                if received {
                     *state = LeaderState::Waiting;
                 }
             }
@@ -217,10 +609,38 @@ impl ServiceUpdater {
                                             debug!("We're not in an update");
                                             return false;
                                         }
-                                        if leader.pkg != peer.pkg {
-                                            debug!("We're in an update but it's not our turn");
+                                        if rejected
+                                               .read()
+                                               .expect("Rejected releases lock is poisoned")
+                                               .contains(leader.pkg.as_ref().unwrap()) {
+                                            debug!("We're in an update to a rejected release");
                                             return false;
                                         }
+                                        match self.canaries.get(&service.service_group) {
+                                            Some(rollout) => {
+                                                if census_group
+                                                       .canaries(&service.canary_size)
+                                                       .iter()
+                                                       .any(|cm| cm.member_id == me.member_id) {
+                                                    debug!("We're in an update and a canary");
+                                                } else if !rollout.soaked {
+                                                    debug!("We're in an update but the canaries \
+                                                            are still soaking");
+                                                    return false;
+                                                } else if leader.pkg != peer.pkg {
+                                                    debug!("We're in an update but it's not our \
+                                                            turn");
+                                                    return false;
+                                                }
+                                            }
+                                            None => {
+                                                if leader.pkg != peer.pkg {
+                                                    debug!("We're in an update but it's not our \
+                                                            turn");
+                                                    return false;
+                                                }
+                                            }
+                                        }
                                         debug!("We're in an update and it's our turn");
                                         let rx =
                                             Worker::new(service).start(&service.service_group,
@@ -241,8 +661,13 @@ impl ServiceUpdater {
                             Some(census_group) => {
                                 match rx.try_recv() {
                                     Ok(package) => {
-                                        service.update_package(package);
-                                        updated = true
+                                        if let Some(rollout) =
+                                            self.canaries.get_mut(&service.service_group) {
+                                            rollout.previous = Some(service.pkg.ident.clone());
+                                        }
+                                        updated =
+                                            apply_update(&mut self.pending, service, package);
+                                        received = true;
                                     }
                                     Err(TryRecvError::Empty) => return false,
                                     Err(TryRecvError::Disconnected) => {
@@ -261,7 +686,7 @@ impl ServiceUpdater {
                         }
                     }
                 }
-                if updated {
+                if received {
                     *state = FollowerState::Waiting;
                 }
             }
@@ -271,12 +696,103 @@ impl ServiceUpdater {
     }
 }
 
+/// Swaps in the package if the service is within one of its update windows, or holds on to it until
+/// the next window opens otherwise. Returns whether the package was swapped in.
+fn apply_update(pending: &mut HashMap<ServiceGroup, PackageInstall>,
+                service: &mut Service,
+                package: PackageInstall)
+                -> bool {
+    if service.in_update_window() {
+        service.pending_update = None;
+        service.update_package(package);
+        return true;
+    }
+    outputln!(preamble service.service_group,
+              "Holding update to {} until the next update window",
+              package.ident);
+    service.pending_update = Some(package.ident.clone());
+    pending.insert(service.service_group.clone(), package);
+    false
+}
+
+/// Releases of the service which must never be updated to: those rejected by a canary update and
+/// those this Supervisor or another member of its service group rolled back from.
+fn rejected_releases(canaries: &HashMap<ServiceGroup, CanaryRollout>,
+                     watches: &HashMap<ServiceGroup, ReleaseWatch>,
+                     census_group: Option<&CensusGroup>,
+                     service: &Service)
+                     -> HashSet<PackageIdent> {
+    let mut rejected = canaries
+        .get(&service.service_group)
+        .map(|rollout| rollout.rejected.clone())
+        .unwrap_or_default();
+    if let Some(watch) = watches.get(&service.service_group) {
+        rejected.extend(watch.blacklist.iter().cloned());
+    }
+    if let Some(census_group) = census_group {
+        rejected.extend(census_group.release_blacklist.iter().cloned());
+    }
+    rejected
+}
+
+/// Reads the package identifiers listed one per line in the given file, if it exists.
+fn read_releases(path: &Path) -> Vec<PackageIdent> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(_) => return Vec::new(),
+    };
+    BufReader::new(file)
+        .lines()
+        .filter_map(|line| line.ok())
+        .filter_map(|line| PackageIdent::from_str(line.trim()).ok())
+        .collect()
+}
+
+/// Replaces the given file with the package identifiers, one per line.
+fn write_releases<'a, T>(path: &Path, releases: T)
+    where T: IntoIterator<Item = &'a PackageIdent>
+{
+    let mut tmp_file = path.as_os_str().to_owned();
+    tmp_file.push(".tmp");
+    let tmp_file = PathBuf::from(tmp_file);
+    let file = match File::create(&tmp_file) {
+        Ok(file) => file,
+        Err(err) => {
+            warn!("Couldn't open temporary release file, {}, {}",
+                  tmp_file.display(),
+                  err);
+            return;
+        }
+    };
+    let mut writer = BufWriter::new(file);
+    for release in releases {
+        if let Err(err) = writeln!(writer, "{}", release) {
+            warn!("Couldn't write to temporary release file, {}, {}",
+                  tmp_file.display(),
+                  err);
+            return;
+        }
+    }
+    if let Err(err) = writer.flush() {
+        warn!("Couldn't write to temporary release file, {}, {}",
+              tmp_file.display(),
+              err);
+        return;
+    }
+    if let Err(err) = fs::rename(&tmp_file, path) {
+        warn!("Couldn't finalize release file, {}, {}", path.display(), err);
+    }
+}
+
 struct Worker {
     current: PackageIdent,
     spec_ident: PackageIdent,
     depot: depot_client::Client,
     channel: Option<String>,
     update_strategy: UpdateStrategy,
+    update_constraint: Option<VersionConstraint>,
+    excluded_update: Arc<RwLock<Option<PackageIdent>>>,
+    rejected: Arc<RwLock<HashSet<PackageIdent>>>,
     ui: UI,
 }
 
@@ -288,10 +804,26 @@ impl Worker {
             depot: depot_client::Client::new(&service.depot_url, PRODUCT, VERSION, None).unwrap(),
             channel: service.channel.clone(),
             update_strategy: service.update_strategy.clone(),
+            update_constraint: service.update_constraint.clone(),
+            excluded_update: service.excluded_update.clone(),
+            rejected: Arc::default(),
             ui: UI::default(),
         }
     }
 
+    /// Never update to one of the given releases while polling. The set is read on every poll.
+    fn rejecting(mut self, releases: Arc<RwLock<HashSet<PackageIdent>>>) -> Self {
+        self.rejected = releases;
+        self
+    }
+
+    fn is_rejected(&self, ident: &PackageIdent) -> bool {
+        self.rejected
+            .read()
+            .expect("Rejected releases lock is poisoned")
+            .contains(ident)
+    }
+
     /// Start a new update worker.
     ///
     /// Passing an optional package identifier will make the worker perform a run-once update to
@@ -334,12 +866,11 @@ impl Worker {
             let next_check = SteadyTime::now() +
                              TimeDuration::milliseconds(self.update_frequency());
             let mut package: Option<PackageInstall> = None;
-            match self.depot
-                      .show_package(&self.spec_ident,
-                                    self.channel.as_ref().map(String::as_ref)) {
-                Ok(remote) => {
-                    let latest: PackageIdent = remote.get_ident().clone().into();
-                    if latest > self.current {
+            match self.latest_release() {
+                Ok(latest) => {
+                    if self.is_rejected(&latest) {
+                        info!("Package found has been rejected");
+                    } else if latest > self.current {
                         outputln!("Updating from {} to {}", self.current, latest);
                         match self.install(&latest, true) {
                             Ok(pkg) => package = Some(pkg),
@@ -359,7 +890,10 @@ impl Worker {
                         None => self.current.clone(),
                     };
 
-                    if cached.ident > compare {
+                    if cached.ident > compare && !self.is_rejected(&cached.ident) &&
+                       self.update_constraint
+                           .as_ref()
+                           .map_or(true, |c| c.matches_ident(&cached.ident)) {
                         package = Some(cached);
                     }
                 }
@@ -377,6 +911,55 @@ impl Worker {
         }
     }
 
+    /// Returns the latest release of the package in the channel which meets the update constraint,
+    /// recording the latest release of the channel as excluded if it doesn't.
+    fn latest_release(&self) -> Result<PackageIdent> {
+        let channel = self.channel.as_ref().map(String::as_ref);
+        let remote = self.depot.show_package(&self.spec_ident, channel)?;
+        let latest: PackageIdent = remote.get_ident().clone().into();
+        let constraint = match self.update_constraint {
+            Some(ref constraint) => constraint,
+            None => return Ok(latest),
+        };
+        {
+            let mut excluded = self.excluded_update
+                .write()
+                .expect("Excluded update lock is poisoned");
+            if constraint.matches_ident(&latest) {
+                *excluded = None;
+                return Ok(latest);
+            }
+            if latest > self.current {
+                if excluded.as_ref() != Some(&latest) {
+                    outputln!("Newer release {} is excluded by the update constraint {}",
+                              latest,
+                              constraint);
+                }
+                *excluded = Some(latest.clone());
+            } else {
+                *excluded = None;
+            }
+        }
+        let mut versions: Vec<String> = self.depot
+            .show_package_versions(&self.spec_ident)?
+            .into_iter()
+            .map(|v| v.version)
+            .filter(|version| constraint.matches(version))
+            .collect();
+        versions.sort_by(|a, b| version_sort(b, a).unwrap_or(Ordering::Equal));
+        for version in versions {
+            let ident = PackageIdent::new(latest.origin.clone(),
+                                          latest.name.clone(),
+                                          Some(version),
+                                          None);
+            match self.depot.show_package(&ident, channel) {
+                Ok(remote) => return Ok(remote.get_ident().clone().into()),
+                Err(e) => debug!("No release of {} found in the channel: {:?}", ident, e),
+            }
+        }
+        Ok(self.current.clone())
+    }
+
     fn install(&mut self, package: &PackageIdent, recurse: bool) -> Result<PackageInstall> {
         let package = match PackageInstall::load(package, Some(&*FS_ROOT_PATH)) {
             Ok(pkg) => pkg,
@@ -423,3 +1006,168 @@ impl Worker {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Write;
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+    use hcore::service::ServiceGroup;
+    use tempdir::TempDir;
+    use time::{Duration, SteadyTime};
+
+    use manager::service::HealthCheck;
+    use super::{read_releases, write_releases, Canary, CanaryRollout};
+
+    fn canary<'a>(member_id: &'a str,
+                  pkg: &'a PackageIdent,
+                  health_check: HealthCheck)
+                  -> Canary<'a> {
+        Canary {
+            member_id: member_id,
+            pkg: Some(pkg),
+            health_check: health_check,
+        }
+    }
+
+    /// Has each member's rollout observe the same canaries, with the first canary leading.
+    fn observe_all(rollouts: &mut [CanaryRollout], canaries: &[Canary], now: SteadyTime) {
+        let sg = ServiceGroup::from_str("redis.default").unwrap();
+        for rollout in rollouts.iter_mut() {
+            rollout.observe(&sg, canaries[0].pkg, canaries, Duration::seconds(60), now);
+        }
+    }
+
+    #[test]
+    fn releases_round_trip() {
+        let tmpdir = TempDir::new("releases").unwrap();
+        let path = tmpdir.path().join("redis.blacklist");
+        let releases = vec![PackageIdent::from_str("core/redis/3.2.4/20170101000000").unwrap(),
+                            PackageIdent::from_str("core/redis/3.2.5/20170201000000").unwrap()];
+        write_releases(&path, &releases);
+        assert_eq!(read_releases(&path), releases);
+    }
+
+    #[test]
+    fn read_releases_skips_invalid_lines() {
+        let tmpdir = TempDir::new("releases").unwrap();
+        let path = tmpdir.path().join("redis.known-good");
+        let mut file = File::create(&path).unwrap();
+        write!(file, "core/redis/3.2.4/20170101000000\n\nnot-an-ident-\n").unwrap();
+        assert_eq!(read_releases(&path),
+                   vec![PackageIdent::from_str("core/redis/3.2.4/20170101000000").unwrap()]);
+    }
+
+    #[test]
+    fn read_releases_of_missing_file() {
+        let tmpdir = TempDir::new("releases").unwrap();
+        assert!(read_releases(&tmpdir.path().join("redis.blacklist")).is_empty());
+    }
+
+    #[test]
+    fn canary_going_back_rejects_the_candidate_on_every_canary() {
+        let v1 = PackageIdent::from_str("core/redis/3.2.4/20170101000000").unwrap();
+        let v2 = PackageIdent::from_str("core/redis/3.2.5/20170201000000").unwrap();
+        let now = SteadyTime::now();
+        let mut rollouts = vec![CanaryRollout::default(),
+                                CanaryRollout::default(),
+                                CanaryRollout::default()];
+        observe_all(&mut rollouts,
+                    &[canary("a", &v2, HealthCheck::Ok),
+                      canary("b", &v1, HealthCheck::Ok),
+                      canary("c", &v1, HealthCheck::Ok)],
+                    now);
+        observe_all(&mut rollouts,
+                    &[canary("a", &v2, HealthCheck::Ok),
+                      canary("b", &v2, HealthCheck::Ok),
+                      canary("c", &v2, HealthCheck::Unknown)],
+                    now);
+        // The update leader rolls back on its own; the candidate stays pinned and is rejected.
+        observe_all(&mut rollouts,
+                    &[canary("a", &v1, HealthCheck::Ok),
+                      canary("b", &v2, HealthCheck::Ok),
+                      canary("c", &v2, HealthCheck::Ok)],
+                    now + Duration::seconds(10));
+        for rollout in rollouts.iter() {
+            assert!(rollout.rejected.contains(&v2));
+            assert!(!rollout.rejected.contains(&v1));
+            assert!(!rollout.soaked);
+        }
+    }
+
+    #[test]
+    fn critical_canary_rejects_the_candidate() {
+        let v1 = PackageIdent::from_str("core/redis/3.2.4/20170101000000").unwrap();
+        let v2 = PackageIdent::from_str("core/redis/3.2.5/20170201000000").unwrap();
+        let now = SteadyTime::now();
+        let mut rollouts = vec![CanaryRollout::default(), CanaryRollout::default()];
+        observe_all(&mut rollouts,
+                    &[canary("a", &v2, HealthCheck::Ok),
+                      canary("b", &v1, HealthCheck::Ok)],
+                    now);
+        observe_all(&mut rollouts,
+                    &[canary("a", &v2, HealthCheck::Ok),
+                      canary("b", &v2, HealthCheck::Critical)],
+                    now);
+        for rollout in rollouts.iter() {
+            assert!(rollout.rejected.contains(&v2));
+        }
+    }
+
+    #[test]
+    fn soak_requires_every_canary_to_be_healthy() {
+        let v1 = PackageIdent::from_str("core/redis/3.2.4/20170101000000").unwrap();
+        let v2 = PackageIdent::from_str("core/redis/3.2.5/20170201000000").unwrap();
+        let now = SteadyTime::now();
+        let mut rollouts = vec![CanaryRollout::default()];
+        observe_all(&mut rollouts,
+                    &[canary("a", &v2, HealthCheck::Ok),
+                      canary("b", &v1, HealthCheck::Ok)],
+                    now);
+        observe_all(&mut rollouts,
+                    &[canary("a", &v2, HealthCheck::Ok),
+                      canary("b", &v2, HealthCheck::Warning)],
+                    now + Duration::seconds(10));
+        observe_all(&mut rollouts,
+                    &[canary("a", &v2, HealthCheck::Ok),
+                      canary("b", &v2, HealthCheck::Warning)],
+                    now + Duration::seconds(100));
+        assert!(!rollouts[0].soaked);
+        observe_all(&mut rollouts,
+                    &[canary("a", &v2, HealthCheck::Ok),
+                      canary("b", &v2, HealthCheck::Ok)],
+                    now + Duration::seconds(110));
+        observe_all(&mut rollouts,
+                    &[canary("a", &v2, HealthCheck::Ok),
+                      canary("b", &v2, HealthCheck::Ok)],
+                    now + Duration::seconds(150));
+        assert!(!rollouts[0].soaked);
+        observe_all(&mut rollouts,
+                    &[canary("a", &v2, HealthCheck::Ok),
+                      canary("b", &v2, HealthCheck::Ok)],
+                    now + Duration::seconds(170));
+        assert!(rollouts[0].soaked);
+        assert!(rollouts[0].rejected.is_empty());
+    }
+
+    #[test]
+    fn soaked_candidate_is_replaced_by_the_next_release() {
+        let v2 = PackageIdent::from_str("core/redis/3.2.5/20170201000000").unwrap();
+        let v3 = PackageIdent::from_str("core/redis/3.2.6/20170301000000").unwrap();
+        let now = SteadyTime::now();
+        let mut rollouts = vec![CanaryRollout::default()];
+        observe_all(&mut rollouts, &[canary("a", &v2, HealthCheck::Ok)], now);
+        observe_all(&mut rollouts,
+                    &[canary("a", &v2, HealthCheck::Ok)],
+                    now + Duration::seconds(60));
+        assert!(rollouts[0].soaked);
+        observe_all(&mut rollouts,
+                    &[canary("a", &v3, HealthCheck::Ok)],
+                    now + Duration::seconds(70));
+        assert_eq!(rollouts[0].candidate, Some(v3));
+        assert!(!rollouts[0].soaked);
+        assert!(rollouts[0].rejected.is_empty());
+    }
+}
@@ -15,7 +15,7 @@
 use std::collections::{HashMap, HashSet};
 use std::error::Error as StdErr;
 use std::ffi::OsStr;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::channel;
@@ -189,7 +189,15 @@ impl SpecWatcher {
     }
 
     pub fn specs_from_watch_path<'a>(&self) -> Result<HashMap<String, ServiceSpec>> {
-        let spec_files: Vec<PathBuf> = glob(&self.watch_path
+        Self::specs_from_path(&self.watch_path)
+    }
+
+    /// Loads the service specs in the given directory, keyed by service name, skipping the
+    /// spec files which can't be loaded.
+    pub fn specs_from_path<P>(path: P) -> Result<HashMap<String, ServiceSpec>>
+        where P: AsRef<Path>
+    {
+        let spec_files: Vec<PathBuf> = glob(&path.as_ref()
                                                  .join(SPEC_FILE_GLOB)
                                                  .display()
                                                  .to_string())?
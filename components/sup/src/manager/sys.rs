@@ -19,6 +19,7 @@ use butterfly::rumor::service::SysInfo;
 use hcore;
 #[cfg(any(target_os="linux", target_os="macos"))]
 use libc;
+use num_cpus;
 
 use VERSION;
 use config::GossipListenAddr;
@@ -38,6 +39,7 @@ pub struct Sys {
     pub http_gateway_ip: IpAddr,
     pub http_gateway_port: u16,
     pub permanent: bool,
+    pub cpus: usize,
 }
 
 impl Sys {
@@ -68,6 +70,7 @@ impl Sys {
             http_gateway_ip: http.ip(),
             http_gateway_port: http.port(),
             permanent: permanent,
+            cpus: num_cpus::get(),
         }
     }
 
@@ -20,15 +20,23 @@
 //! where the output was generated within the Supervisor. Also supported is a `preamble`, which is
 //! used to denote when output comes from a running service rather than the Supervisor itself.
 //!
-//! The `StructuredOutput` struct supports two global options - verbosity and coloring. If verbose
-//! is turned on, then every line printed is annotated with its preamble, logkey, and precise
-//! location. Without verbose, it prints simply the preamble and logkey. Coloring does what it says
-//! on the tin :)
+//! The `StructuredOutput` struct supports three global options - verbosity, coloring and JSON. If
+//! verbose is turned on, then every line printed is annotated with its preamble, logkey, and
+//! precise location. Without verbose, it prints simply the preamble and logkey. Coloring does what
+//! it says on the tin :)
+//!
+//! With JSON turned on, every line is instead printed as a JSON object for log shippers, with the
+//! time, a level, the service group, hook and stream the line came from, if any, and the message
+//! stripped of colors. Lines a service printed to stderr have the `error` level, all others have
+//! the `info` level.
 
+use std::borrow::Cow;
 use std::sync::atomic::{AtomicBool, Ordering, ATOMIC_BOOL_INIT};
 use std::fmt;
 
 use ansi_term::Colour::{White, Cyan, Green};
+use serde_json;
+use time;
 
 use PROGRAM_NAME;
 
@@ -36,6 +44,7 @@ static mut VERBOSE: AtomicBool = ATOMIC_BOOL_INIT;
 // I am sorry this isn't named the other way; I can't get an atomic initializer that defaults to
 // true. Them's the breaks.
 static mut NO_COLOR: AtomicBool = ATOMIC_BOOL_INIT;
+static mut JSON: AtomicBool = ATOMIC_BOOL_INIT;
 
 /// True if verbose output is on.
 pub fn is_verbose() -> bool {
@@ -67,6 +76,46 @@ pub fn set_no_color(booly: bool) {
     }
 }
 
+/// True if output is printed as JSON objects.
+pub fn is_json() -> bool {
+    unsafe { JSON.load(Ordering::Relaxed) }
+}
+
+/// Turn JSON output on or off.
+pub fn set_json(booly: bool) {
+    unsafe {
+        JSON.store(booly, Ordering::Relaxed);
+    }
+}
+
+/// The output stream of a service or hook process a line was read from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+impl Stream {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            Stream::Stdout => "stdout",
+            Stream::Stderr => "stderr",
+        }
+    }
+}
+
+/// A line of output as printed in JSON.
+#[derive(Serialize)]
+struct JsonOutput<'a> {
+    timestamp: String,
+    level: &'static str,
+    service_group: Option<&'a str>,
+    hook: Option<&'a str>,
+    stream: Option<&'static str>,
+    logkey: &'static str,
+    message: String,
+}
+
 /// Adds structure to printed output. Stores a preamble, a logkey, line, file, column, and content
 /// to print.
 pub struct StructuredOutput<'a> {
@@ -76,8 +125,13 @@ pub struct StructuredOutput<'a> {
     file: &'static str,
     column: u32,
     content: &'a str,
+    /// Name of the hook whose process printed the content.
+    pub hook: Option<&'a str>,
+    /// Stream of the service or hook process the content was read from.
+    pub stream: Option<Stream>,
     pub verbose: Option<bool>,
     pub color: Option<bool>,
+    pub json: Option<bool>,
 }
 
 impl<'a> StructuredOutput<'a> {
@@ -96,10 +150,36 @@ impl<'a> StructuredOutput<'a> {
             file: file,
             column: column,
             content: content,
+            hook: None,
+            stream: None,
             verbose: None,
             color: None,
+            json: None,
         }
     }
+
+    fn write_json(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let service_group = if self.preamble == PROGRAM_NAME.as_str() {
+            None
+        } else {
+            Some(self.preamble)
+        };
+        let level = match self.stream {
+            Some(Stream::Stderr) => "error",
+            _ => "info",
+        };
+        let output = JsonOutput {
+            timestamp: time::now_utc().rfc3339().to_string(),
+            level: level,
+            service_group: service_group,
+            hook: self.hook,
+            stream: self.stream.map(|stream| stream.as_str()),
+            logkey: self.logkey,
+            message: strip_ansi(self.content),
+        };
+        let json = serde_json::to_string(&output).map_err(|_| fmt::Error)?;
+        write!(f, "{}", json)
+    }
 }
 
 // If we ever want to create multiple output formats in the future, we would do it here -
@@ -107,6 +187,9 @@ impl<'a> StructuredOutput<'a> {
 // function. Viola!
 impl<'a> fmt::Display for StructuredOutput<'a> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.json.unwrap_or(is_json()) {
+            return self.write_json(f);
+        }
         let verbose = self.verbose.unwrap_or(is_verbose());
         let color = self.color.unwrap_or(is_color());
         let preamble_color = if self.preamble == PROGRAM_NAME.as_str() {
@@ -114,11 +197,15 @@ impl<'a> fmt::Display for StructuredOutput<'a> {
         } else {
             Green
         };
+        let preamble = match self.hook {
+            Some(hook) => Cow::Owned(format!("{} hook[{}]:", self.preamble, hook)),
+            None => Cow::Borrowed(self.preamble),
+        };
         if verbose {
             if color {
                 write!(f,
                        "{}({})[{}]: {}",
-                       preamble_color.paint(self.preamble),
+                       preamble_color.paint(&*preamble),
                        White.bold().paint(self.logkey),
                        White
                            .underline()
@@ -127,7 +214,7 @@ impl<'a> fmt::Display for StructuredOutput<'a> {
             } else {
                 write!(f,
                        "{}({})[{}:{}:{}]: {}",
-                       self.preamble,
+                       preamble,
                        self.logkey,
                        self.file,
                        self.line,
@@ -138,20 +225,40 @@ impl<'a> fmt::Display for StructuredOutput<'a> {
             if color {
                 write!(f,
                        "{}({}): {}",
-                       preamble_color.paint(self.preamble),
+                       preamble_color.paint(&*preamble),
                        White.bold().paint(self.logkey),
                        self.content)
             } else {
-                write!(f, "{}({}): {}", self.preamble, self.logkey, self.content)
+                write!(f, "{}({}): {}", preamble, self.logkey, self.content)
             }
         }
     }
 }
 
+/// Removes the ANSI escape sequences coloring the content.
+fn strip_ansi(content: &str) -> String {
+    let mut stripped = String::with_capacity(content.len());
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip the sequence up to and including its final letter
+            for c in chars.by_ref() {
+                if c.is_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
 #[cfg(test)]
 mod tests {
-    use super::StructuredOutput;
-    use ansi_term::Colour::{White, Cyan};
+    use super::{Stream, StructuredOutput};
+    use ansi_term::Colour::{White, Cyan, Yellow};
+    use serde_json::{self, Value};
 
     use PROGRAM_NAME;
 
@@ -188,4 +295,42 @@ mod tests {
                            Cyan.paint(progname),
                            White.bold().paint("SOT")));
     }
+
+    #[test]
+    fn format_hook() {
+        let mut so = so("redis.default", "opeth is amazing");
+        so.hook = Some("init");
+        so.verbose = Some(false);
+        so.color = Some(false);
+        assert_eq!(format!("{}", so),
+                   "redis.default hook[init]:(SOT): opeth is amazing");
+    }
+
+    #[test]
+    fn format_json() {
+        let content = format!("opeth is {}", Yellow.bold().paint("amazing"));
+        let mut so = so("redis.default", &content);
+        so.hook = Some("init");
+        so.stream = Some(Stream::Stderr);
+        so.json = Some(true);
+        let json: Value = serde_json::from_str(&format!("{}", so)).unwrap();
+        assert_eq!(json["level"], "error");
+        assert_eq!(json["service_group"], "redis.default");
+        assert_eq!(json["hook"], "init");
+        assert_eq!(json["stream"], "stderr");
+        assert_eq!(json["logkey"], "SOT");
+        assert_eq!(json["message"], "opeth is amazing");
+        assert!(json["timestamp"].is_string());
+    }
+
+    #[test]
+    fn format_json_supervisor() {
+        let mut so = so(PROGRAM_NAME.as_str(), "opeth is amazing");
+        so.json = Some(true);
+        let json: Value = serde_json::from_str(&format!("{}", so)).unwrap();
+        assert_eq!(json["level"], "info");
+        assert!(json["service_group"].is_null());
+        assert!(json["hook"].is_null());
+        assert!(json["stream"].is_null());
+    }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::fmt;
 
 use hcore::service::ServiceGroup;
 
@@ -52,6 +53,38 @@ impl<'a> BindGroup<'a> {
     }
 }
 
+/// The secrets of the service group, by name. Values which aren't valid UTF-8 are rendered
+/// lossily.
+#[derive(Clone, Serialize)]
+pub struct Secrets(HashMap<String, String>);
+
+impl Secrets {
+    fn new(census_group: &CensusGroup) -> Self {
+        let mut secrets = HashMap::new();
+        for secret in census_group.secrets() {
+            secrets.insert(secret.name.clone(),
+                           String::from_utf8_lossy(&secret.value).into_owned());
+        }
+        Secrets(secrets)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<HashMap<String, String>> for Secrets {
+    fn from(secrets: HashMap<String, String>) -> Secrets {
+        Secrets(secrets)
+    }
+}
+
+impl fmt::Debug for Secrets {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.0.keys()).finish()
+    }
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct RenderContext<'a> {
     pub sys: &'a Sys,
@@ -59,6 +92,7 @@ pub struct RenderContext<'a> {
     pub cfg: &'a Cfg,
     pub svc: Svc<'a>,
     pub bind: Binds<'a>,
+    pub secret: Secrets,
 }
 
 impl<'a> RenderContext<'a> {
@@ -80,6 +114,7 @@ impl<'a> RenderContext<'a> {
             cfg: cfg,
             svc: Svc::new(census_group),
             bind: Binds::new(bindings, census),
+            secret: Secrets::new(census_group),
         }
     }
 }
@@ -0,0 +1,37 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64;
+use handlebars::{Handlebars, Helper, HelperDef, RenderContext, RenderError};
+
+use super::super::RenderResult;
+
+#[derive(Clone, Copy)]
+pub struct Base64DecodeHelper;
+
+impl HelperDef for Base64DecodeHelper {
+    fn call(&self, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let param = h.param(0)
+            .and_then(|v| v.value().as_str())
+            .ok_or_else(|| {
+                            RenderError::new("Expected 1 string parameter for \"base64Decode\"")
+                        })?;
+        let decoded = base64::decode(param.trim())
+            .map_err(|e| RenderError::new(format!("Can't decode base64 parameter: {}", e)))?;
+        rc.writer.write(decoded.as_ref())?;
+        Ok(())
+    }
+}
+
+pub static BASE64_DECODE: Base64DecodeHelper = Base64DecodeHelper;
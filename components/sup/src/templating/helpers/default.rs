@@ -0,0 +1,42 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use handlebars::{Handlebars, Helper, HelperDef, RenderContext, RenderError};
+use serde_json::Value as Json;
+
+use super::super::RenderResult;
+use super::json_to_string;
+
+#[derive(Clone, Copy)]
+pub struct DefaultHelper;
+
+impl HelperDef for DefaultHelper {
+    fn call(&self, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let param = h.param(0)
+            .ok_or_else(|| RenderError::new("Expected 2 parameters for \"default\""))?
+            .value();
+        let default = h.param(1)
+            .ok_or_else(|| RenderError::new("Expected 2 parameters for \"default\""))?
+            .value();
+        let value = match *param {
+            Json::Null => default,
+            Json::String(ref s) if s.is_empty() => default,
+            _ => param,
+        };
+        rc.writer.write(json_to_string(value).into_bytes().as_ref())?;
+        Ok(())
+    }
+}
+
+pub static DEFAULT: DefaultHelper = DefaultHelper;
@@ -0,0 +1,146 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+use handlebars::{Handlebars, Helper, HelperDef, RenderContext, RenderError};
+
+use super::super::RenderResult;
+
+/// How deeply partials may include each other.
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+thread_local! {
+    /// The partials being rendered on this thread, outermost first. Each included partial is
+    /// rendered with a fresh context, so the chain can't be kept in it.
+    static INCLUDE_CHAIN: RefCell<Vec<PathBuf>> = RefCell::new(Vec::new());
+}
+
+/// Keeps a partial in the include chain for as long as it is being rendered.
+struct Including;
+
+impl Including {
+    fn enter(path: &Path) -> Result<Including, RenderError> {
+        INCLUDE_CHAIN.with(|chain| {
+            let mut chain = chain.borrow_mut();
+            if chain.iter().any(|p| p == path) {
+                return Err(RenderError::new(format!("{} includes itself for \"include\"",
+                                                    path.display())));
+            }
+            if chain.len() >= MAX_INCLUDE_DEPTH {
+                return Err(RenderError::new(format!("Can't include {}, partials are nested \
+                                                     more than {} deep",
+                                                    path.display(),
+                                                    MAX_INCLUDE_DEPTH)));
+            }
+            chain.push(path.to_path_buf());
+            Ok(Including)
+        })
+    }
+}
+
+impl Drop for Including {
+    fn drop(&mut self) {
+        INCLUDE_CHAIN.with(|chain| chain.borrow_mut().pop());
+    }
+}
+
+/// Includes a partial from the config directory. The directory is the one the helper was created
+/// with, which honors `config_from`, or else the `config/` directory of the package being
+/// rendered. Partials may include each other up to `MAX_INCLUDE_DEPTH` deep, but not in a cycle.
+#[derive(Clone, Default)]
+pub struct IncludeHelper {
+    config_root: Option<PathBuf>,
+}
+
+impl IncludeHelper {
+    pub fn new<P: Into<PathBuf>>(config_root: P) -> Self {
+        IncludeHelper { config_root: Some(config_root.into()) }
+    }
+}
+
+impl HelperDef for IncludeHelper {
+    fn call(&self, h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let param =
+            h.param(0)
+                .and_then(|v| v.value().as_str())
+                .ok_or_else(|| RenderError::new("Expected a string path for \"include\""))?;
+        // Partials may only come from the package's own `config/` directory.
+        let relative = Path::new(param);
+        let contained = relative
+            .components()
+            .all(|c| match c {
+                     Component::Normal(_) |
+                     Component::CurDir => true,
+                     _ => false,
+                 });
+        if !contained {
+            return Err(outside_config_dir(param));
+        }
+        let config_root = match self.config_root {
+            Some(ref config_root) => config_root.clone(),
+            None => {
+                rc.context().data()["pkg"]["path"]
+                    .as_str()
+                    .map(|p| PathBuf::from(p).join("config"))
+                    .ok_or_else(|| RenderError::new("Package path not found for \"include\""))?
+            }
+        };
+        // Symlinks are resolved before the check, so they can't lead out of the directory either.
+        let path = config_root
+            .canonicalize()
+            .and_then(|root| {
+                          config_root
+                              .join(relative)
+                              .canonicalize()
+                              .map(|path| (root, path))
+                      })
+            .map_err(|e| {
+                         RenderError::new(format!("Can't read {} for \"include\": {}",
+                                                  config_root.join(relative).display(),
+                                                  e))
+                     })
+            .and_then(|(root, path)| if path.starts_with(&root) {
+                          Ok(path)
+                      } else {
+                          Err(outside_config_dir(param))
+                      })?;
+        let mut content = String::new();
+        File::open(&path)
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .map_err(|e| {
+                         RenderError::new(format!("Can't read {} for \"include\": {}",
+                                                  path.display(),
+                                                  e))
+                     })?;
+        let _including = Including::enter(&path)?;
+        let rendered = r.template_render(&content, rc.context().data())
+            .map_err(|e| {
+                         RenderError::new(format!("Can't render {} for \"include\": {}",
+                                                  path.display(),
+                                                  e))
+                     })?;
+        rc.writer.write(rendered.into_bytes().as_ref())?;
+        Ok(())
+    }
+}
+
+fn outside_config_dir(param: &str) -> RenderError {
+    RenderError::new(format!("Path \"{}\" for \"include\" must be relative to the package's \
+                              config directory",
+                             param))
+}
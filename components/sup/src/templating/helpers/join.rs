@@ -0,0 +1,57 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use handlebars::{Handlebars, Helper, HelperDef, RenderContext, RenderError};
+use serde_json::Value as Json;
+
+use super::super::RenderResult;
+use super::json_to_string;
+
+#[derive(Clone, Copy)]
+pub struct JoinHelper;
+
+impl HelperDef for JoinHelper {
+    fn call(&self, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let list = h.param(0)
+            .and_then(|v| v.value().as_array())
+            .ok_or_else(|| RenderError::new("Expected an array parameter for \"join\""))?;
+        let separator =
+            h.param(1)
+                .and_then(|v| v.value().as_str())
+                .ok_or_else(|| RenderError::new("Expected a string separator for \"join\""))?;
+        // An optional dotted key, like `sys.ip`, picks the value to join out of each element.
+        let key = match h.param(2) {
+            Some(v) => {
+                Some(v.value()
+                         .as_str()
+                         .ok_or_else(|| RenderError::new("Expected a string key for \"join\""))?)
+            }
+            None => None,
+        };
+        let values: Vec<String> = list.iter()
+            .map(|item| match key {
+                     Some(key) => json_to_string(lookup(item, key)),
+                     None => json_to_string(item),
+                 })
+            .collect();
+        rc.writer.write(values.join(separator).into_bytes().as_ref())?;
+        Ok(())
+    }
+}
+
+fn lookup<'a>(value: &'a Json, key: &str) -> &'a Json {
+    key.split('.').fold(value, |value, part| &value[part])
+}
+
+pub static JOIN: JoinHelper = JoinHelper;
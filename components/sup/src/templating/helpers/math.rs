@@ -0,0 +1,61 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use handlebars::{Handlebars, Helper, HelperDef, RenderContext, RenderError};
+
+use super::super::RenderResult;
+use super::json_to_i64;
+
+#[derive(Clone, Copy)]
+pub struct MathHelper;
+
+impl HelperDef for MathHelper {
+    fn call(&self, h: &Helper, _: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let left =
+            h.param(0)
+                .and_then(|v| json_to_i64(v.value()))
+                .ok_or_else(|| RenderError::new("Expected an integer left operand for \"math\""))?;
+        let operator =
+            h.param(1)
+                .and_then(|v| v.value().as_str())
+                .ok_or_else(|| RenderError::new("Expected a string operator for \"math\""))?;
+        let right =
+            h.param(2)
+                .and_then(|v| json_to_i64(v.value()))
+                .ok_or_else(|| RenderError::new("Expected an integer right operand for \"math\""))?;
+        let result = match operator {
+            "+" => left.checked_add(right),
+            "-" => left.checked_sub(right),
+            "*" => left.checked_mul(right),
+            "/" => left.checked_div(right),
+            "%" => left.checked_rem(right),
+            _ => {
+                return Err(RenderError::new(format!("Unknown operator \"{}\" for \"math\", \
+                                                     expected one of +, -, *, / or %",
+                                                    operator)))
+            }
+        };
+        let result = result
+            .ok_or_else(|| {
+                            RenderError::new(format!("Can't compute {} {} {} in \"math\"",
+                                                     left,
+                                                     operator,
+                                                     right))
+                        })?;
+        rc.writer.write(result.to_string().into_bytes().as_ref())?;
+        Ok(())
+    }
+}
+
+pub static MATH: MathHelper = MathHelper;
@@ -12,8 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod base64_decode;
+mod base64_encode;
+mod default;
 mod each_alive;
+mod include;
+mod join;
+mod math;
 mod pkg_path_for;
+mod split;
 mod str_replace;
 mod to_json;
 mod to_lowercase;
@@ -24,8 +31,15 @@ mod to_yaml;
 use serde::Serialize;
 use serde_json::{self, Value as Json};
 
+pub use self::base64_decode::BASE64_DECODE;
+pub use self::base64_encode::BASE64_ENCODE;
+pub use self::default::DEFAULT;
 pub use self::each_alive::EACH_ALIVE;
+pub use self::include::IncludeHelper;
+pub use self::join::JOIN;
+pub use self::math::MATH;
 pub use self::pkg_path_for::PKG_PATH_FOR;
+pub use self::split::SPLIT;
 pub use self::str_replace::STR_REPLACE;
 pub use self::to_json::TO_JSON;
 pub use self::to_lowercase::TO_LOWERCASE;
@@ -58,3 +72,22 @@ fn to_json<T>(src: &T) -> Json
 {
     serde_json::to_value(src).unwrap_or(Json::Null)
 }
+
+/// Renders the given value the way Handlebars would: strings without quotes, nothing for null
+/// and JSON for everything else.
+fn json_to_string(src: &Json) -> String {
+    match *src {
+        Json::Null => String::new(),
+        Json::String(ref s) => s.clone(),
+        ref value => value.to_string(),
+    }
+}
+
+/// Reads an integer out of a number or a string, since values from TOML may be either.
+fn json_to_i64(src: &Json) -> Option<i64> {
+    match *src {
+        Json::Number(ref n) => n.as_i64(),
+        Json::String(ref s) => s.trim().parse().ok(),
+        _ => None,
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use handlebars::{Handlebars, Helper, HelperDef, Renderable, RenderContext, RenderError};
+
+use super::super::RenderResult;
+use super::{json_to_i64, to_json};
+
+#[derive(Clone, Copy)]
+pub struct SplitHelper;
+
+impl HelperDef for SplitHelper {
+    fn call(&self, h: &Helper, r: &Handlebars, rc: &mut RenderContext) -> RenderResult<()> {
+        let param =
+            h.param(0)
+                .and_then(|v| v.value().as_str())
+                .ok_or_else(|| RenderError::new("Expected a string parameter for \"split\""))?;
+        let separator =
+            h.param(1)
+                .and_then(|v| v.value().as_str())
+                .ok_or_else(|| RenderError::new("Expected a string separator for \"split\""))?;
+        let parts: Vec<&str> = param.split(separator).collect();
+        match h.template() {
+            Some(template) => {
+                rc.promote_local_vars();
+                let len = parts.len();
+                for (i, part) in parts.iter().enumerate() {
+                    let mut local_rc = rc.derive();
+                    local_rc.set_local_var("@first".to_string(), to_json(&(i == 0usize)));
+                    local_rc.set_local_var("@last".to_string(), to_json(&(i == len - 1)));
+                    local_rc.set_local_var("@index".to_string(), to_json(&i));
+
+                    if let Some(block_param) = h.block_param() {
+                        let mut map = BTreeMap::new();
+                        map.insert(block_param.to_string(), to_json(part));
+                        local_rc.push_block_context(&map);
+                    }
+
+                    template.render(r, &mut local_rc)?;
+
+                    if h.block_param().is_some() {
+                        local_rc.pop_block_context();
+                    }
+                }
+                rc.demote_local_vars();
+            }
+            None => {
+                let index = h.param(2)
+                    .and_then(|v| json_to_i64(v.value()))
+                    .ok_or_else(|| {
+                                    RenderError::new("Expected an integer index for inline \
+                                                      \"split\"")
+                                })?;
+                // Negative indexes count back from the last part, so `-1` is the last one.
+                let index = if index < 0 {
+                    parts.len() as i64 + index
+                } else {
+                    index
+                };
+                if index >= 0 && (index as usize) < parts.len() {
+                    rc.writer.write(parts[index as usize].as_bytes())?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+pub static SPLIT: SplitHelper = SplitHelper;
@@ -0,0 +1,349 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Finds the variables a template refers to which a render context doesn't define.
+//!
+//! Handlebars renders an undefined variable as an empty string, which makes a typo in a template
+//! easy to miss. Only paths starting at the top of the render context (`sys`, `pkg`, `cfg`, `svc`,
+//! `bind` and `secret`) are checked: any other path is relative to the context of a block or names
+//! a block parameter. The branches of `if` and `unless` blocks the context doesn't take aren't
+//! checked, nor is the content of `each` and `with` blocks.
+
+use std::fmt;
+
+use serde_json::Value as Json;
+
+use super::helpers::JsonTruthy;
+
+/// The top level names of a render context.
+const ROOTS: &'static [&'static str] = &["sys", "pkg", "cfg", "svc", "bind", "secret"];
+
+/// A variable of a template which the render context doesn't define.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UndefinedVariable {
+    pub path: String,
+    pub line: usize,
+}
+
+impl fmt::Display for UndefinedVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: '{}' is undefined", self.line, self.path)
+    }
+}
+
+struct Block {
+    /// Whether the content of the block is checked.
+    checked: bool,
+    /// Whether the content of the block's `else` branch is checked.
+    inverse_checked: bool,
+    /// Whether an `else` has been seen.
+    inverse: bool,
+}
+
+/// Returns the variables of the template which the given render context doesn't define, in the
+/// order they appear in.
+pub fn undefined_variables(template: &str, ctx: &Json) -> Vec<UndefinedVariable> {
+    let mut undefined = Vec::new();
+    let mut blocks: Vec<Block> = Vec::new();
+    for (line, body) in expressions(template) {
+        let checked = match blocks.last() {
+            Some(block) if block.inverse => block.inverse_checked,
+            Some(block) => block.checked,
+            None => true,
+        };
+        let mut check = |path: &str| if checked {
+                            check_path(path, ctx, line, &mut undefined);
+                        };
+        if body == "else" || body == "^" || body.starts_with("else ") {
+            if let Some(block) = blocks.last_mut() {
+                block.inverse = true;
+            }
+            continue;
+        }
+        match body.chars().next() {
+            Some('!') | Some('>') | None => {}
+            Some('/') => {
+                blocks.pop();
+            }
+            Some('#') | Some('^') => {
+                let inverted = body.starts_with('^');
+                let tokens = tokenize(&body[1..]);
+                let (name, params) = if inverted {
+                    ("unless", &tokens[..])
+                } else if tokens.is_empty() {
+                    ("", &tokens[..])
+                } else {
+                    (tokens[0].as_str(), &tokens[1..])
+                };
+                let (checked, inverse_checked) = if name == "if" || name == "unless" {
+                    let truthy = params.first().and_then(|param| truthy(param, ctx));
+                    match truthy.map(|truthy| truthy != (name == "unless")) {
+                        Some(taken) => (checked && taken, checked && !taken),
+                        None => (checked, checked),
+                    }
+                } else {
+                    for param in params {
+                        check_param(param, &mut check);
+                    }
+                    if name == "each" || name == "with" {
+                        (false, checked)
+                    } else {
+                        (checked, checked)
+                    }
+                };
+                blocks.push(Block {
+                                checked: checked,
+                                inverse_checked: inverse_checked,
+                                inverse: false,
+                            });
+            }
+            Some('&') => check_expression(&body[1..], &mut check),
+            Some(_) => check_expression(body, &mut check),
+        }
+    }
+    undefined.dedup();
+    undefined
+}
+
+/// Returns the expressions of a template with the line each starts on, leaving out comments.
+fn expressions(template: &str) -> Vec<(usize, &str)> {
+    let mut expressions = Vec::new();
+    let mut offset = 0;
+    while let Some(start) = template[offset..].find("{{") {
+        let start = offset + start;
+        let line = template[..start].matches('\n').count() + 1;
+        let body_start = start + 2;
+        let comment = template[body_start..].trim_left_matches('~').starts_with("!--");
+        let terminator = if comment { "--}}" } else { "}}" };
+        let end = match template[body_start..].find(terminator) {
+            Some(end) => body_start + end,
+            None => break,
+        };
+        offset = end + terminator.len();
+        if comment {
+            continue;
+        }
+        let body = template[body_start..end]
+            .trim_matches(|c| c == '{' || c == '}' || c == '~')
+            .trim();
+        expressions.push((line, body));
+    }
+    expressions
+}
+
+fn check_expression<F>(expression: &str, check: &mut F)
+    where F: FnMut(&str)
+{
+    let tokens = tokenize(expression);
+    if tokens.len() == 1 {
+        check_param(&tokens[0], check);
+        return;
+    }
+    // The first parameter of `default` is expected to be undefined at times.
+    let skip = if tokens.first().map(String::as_str) == Some("default") {
+        2
+    } else {
+        1
+    };
+    for param in tokens.iter().skip(skip) {
+        check_param(param, check);
+    }
+}
+
+fn check_param<F>(param: &str, check: &mut F)
+    where F: FnMut(&str)
+{
+    if param.starts_with('(') && param.ends_with(')') {
+        check_expression(&param[1..param.len() - 1], check);
+    } else if let Some(index) = param.find('=') {
+        check_param(&param[index + 1..], check);
+    } else {
+        check(param);
+    }
+}
+
+fn check_path(path: &str, ctx: &Json, line: usize, undefined: &mut Vec<UndefinedVariable>) {
+    if let Some(segments) = root_path(path) {
+        if lookup(ctx, &segments).is_none() {
+            undefined.push(UndefinedVariable {
+                               path: path.to_string(),
+                               line: line,
+                           });
+        }
+    }
+}
+
+/// Whether the value of the given parameter is truthy, or `None` when that can't be told.
+fn truthy(param: &str, ctx: &Json) -> Option<bool> {
+    root_path(param).map(|segments| lookup(ctx, &segments).map_or(false, |v| v.is_truthy()))
+}
+
+/// Splits a path starting at the top of the render context into its segments.
+fn root_path(path: &str) -> Option<Vec<&str>> {
+    let segments: Vec<&str> = path.split(|c| c == '.' || c == '/')
+        .map(|s| s.trim_matches(|c| c == '[' || c == ']'))
+        .collect();
+    if ROOTS.contains(&segments[0]) && segments.iter().all(|s| !s.is_empty()) {
+        Some(segments)
+    } else {
+        None
+    }
+}
+
+fn lookup<'a>(ctx: &'a Json, segments: &[&str]) -> Option<&'a Json> {
+    let mut value = ctx;
+    for segment in segments {
+        let next = match *value {
+            Json::Object(ref map) => map.get(*segment),
+            Json::Array(ref list) => segment.parse::<usize>().ok().and_then(|i| list.get(i)),
+            _ => None,
+        };
+        match next {
+            Some(next) => value = next,
+            None => return None,
+        }
+    }
+    Some(value)
+}
+
+/// Splits an expression into its whitespace separated tokens, keeping string literals and
+/// subexpressions whole and dropping any block parameters.
+fn tokenize(expression: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut quote = None;
+    let mut depth = 0;
+    for c in expression.chars() {
+        match (quote, c) {
+            (Some(q), _) => {
+                token.push(c);
+                if c == q {
+                    quote = None;
+                }
+            }
+            (None, '"') | (None, '\'') => {
+                token.push(c);
+                quote = Some(c);
+            }
+            (None, '(') => {
+                token.push(c);
+                depth += 1;
+            }
+            (None, ')') => {
+                token.push(c);
+                depth -= 1;
+            }
+            (None, c) if c.is_whitespace() && depth == 0 => {
+                if !token.is_empty() {
+                    tokens.push(token);
+                    token = String::new();
+                }
+            }
+            (None, c) => token.push(c),
+        }
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+    if let Some(index) = tokens.iter().position(|t| t == "as") {
+        tokens.truncate(index);
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{self, Value as Json};
+
+    use super::*;
+
+    fn ctx() -> Json {
+        serde_json::from_str(r#"{
+            "sys": {"ip": "10.0.0.4"},
+            "pkg": {"name": "redis"},
+            "cfg": {"port": 6379, "tls": false, "servers": [{"host": "a"}]},
+            "svc": {"members": []},
+            "bind": {}
+        }"#)
+                .unwrap()
+    }
+
+    fn paths(template: &str) -> Vec<String> {
+        undefined_variables(template, &ctx())
+            .into_iter()
+            .map(|v| v.path)
+            .collect()
+    }
+
+    #[test]
+    fn reports_undefined_root_paths() {
+        assert_eq!(paths("bind {{sys.ip}}:{{cfg.prot}}"), vec!["cfg.prot".to_string()]);
+        assert_eq!(paths("{{cfg.servers.0.host}} {{cfg.servers.[1].host}}"),
+                   vec!["cfg.servers.[1].host".to_string()]);
+        assert_eq!(paths("{{{cfg.missing}}} {{~cfg.port~}}"),
+                   vec!["cfg.missing".to_string()]);
+    }
+
+    #[test]
+    fn reports_line_numbers() {
+        let undefined = undefined_variables("port {{cfg.port}}\nhost {{sys.host}}\n", &ctx());
+        assert_eq!(undefined,
+                   vec![UndefinedVariable {
+                            path: "sys.host".to_string(),
+                            line: 2,
+                        }]);
+    }
+
+    #[test]
+    fn checks_helper_parameters() {
+        let template = "{{strReplace cfg.nme \"a\" \"b\"}} {{toJson (math cfg.port \"+\" cfg.x)}}";
+        assert_eq!(paths(template),
+                   vec!["cfg.nme".to_string(), "cfg.x".to_string()]);
+        assert!(paths("{{default cfg.missing 8080}} {{toUppercase \"cfg.literal\"}}").is_empty());
+    }
+
+    #[test]
+    fn ignores_relative_paths_and_block_params() {
+        let template = "{{#each cfg.servers}}{{host}}{{cfg.nope}}{{/each}}\
+                        {{#eachAlive svc.members as |member|}}{{member.sys.ip}}{{/eachAlive}}\
+                        {{#with cfg}}{{port}}{{/with}}{{@index}}{{this}}";
+        assert!(paths(template).is_empty());
+    }
+
+    #[test]
+    fn checks_only_branches_taken() {
+        assert!(paths("{{#if cfg.tls}}{{cfg.tls.cert}}{{/if}}").is_empty());
+        assert_eq!(paths("{{#if cfg.tls}}{{cfg.a}}{{else}}{{cfg.b}}{{/if}}"),
+                   vec!["cfg.b".to_string()]);
+        assert_eq!(paths("{{#unless cfg.port}}{{cfg.a}}{{else}}{{cfg.b}}{{/unless}}"),
+                   vec!["cfg.b".to_string()]);
+        assert!(paths("{{^cfg.port}}{{cfg.a}}{{/cfg.port}}").is_empty());
+    }
+
+    #[test]
+    fn checks_block_helper_parameters() {
+        assert_eq!(paths("{{#each cfg.hosts}}{{this}}{{else}}{{cfg.port}}{{/each}}"),
+                   vec!["cfg.hosts".to_string()]);
+    }
+
+    #[test]
+    fn skips_comments_and_partials() {
+        assert!(paths("{{!-- {{cfg.nope}} --}}{{! cfg.nope }}{{> cfg.nope}}").is_empty());
+    }
+
+    #[test]
+    fn checks_secrets() {
+        assert_eq!(paths("{{secret.password}}"), vec!["secret.password".to_string()]);
+    }
+}
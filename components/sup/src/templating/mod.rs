@@ -13,24 +13,37 @@
 // limitations under the License.
 
 pub mod helpers;
+pub mod lint;
 mod context;
 
+use std::collections::HashMap;
 use std::fmt;
+use std::fs::File;
+use std::io::Read;
 use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
 use std::result;
 
-use handlebars::{Handlebars, RenderError};
+use handlebars::{Handlebars, RenderError, TemplateError, TemplateFileError};
 use serde::Serialize;
 use serde_json;
 
-pub use self::context::RenderContext;
+pub use self::context::{RenderContext, Secrets};
 use error::{Error, Result};
 
 static LOGKEY: &'static str = "TP";
 
 pub type RenderResult<T> = result::Result<T, RenderError>;
 
-pub struct TemplateRenderer(Handlebars);
+/// Renders templates with the Supervisor's helpers, keeping the source of each registered
+/// template so it can be checked for undefined variables.
+pub struct TemplateRenderer(Handlebars, HashMap<String, TemplateSource>);
+
+struct TemplateSource {
+    /// The file the template was read from, or its name.
+    origin: String,
+    content: String,
+}
 
 impl TemplateRenderer {
     pub fn new() -> Self {
@@ -43,9 +56,58 @@ impl TemplateRenderer {
         handlebars.register_helper("toJson", Box::new(helpers::TO_JSON));
         handlebars.register_helper("toToml", Box::new(helpers::TO_TOML));
         handlebars.register_helper("toYaml", Box::new(helpers::TO_YAML));
+        handlebars.register_helper("default", Box::new(helpers::DEFAULT));
+        handlebars.register_helper("math", Box::new(helpers::MATH));
+        handlebars.register_helper("join", Box::new(helpers::JOIN));
+        handlebars.register_helper("split", Box::new(helpers::SPLIT));
+        handlebars.register_helper("base64Encode", Box::new(helpers::BASE64_ENCODE));
+        handlebars.register_helper("base64Decode", Box::new(helpers::BASE64_DECODE));
+        handlebars.register_helper("include", Box::new(helpers::IncludeHelper::default()));
 
         handlebars.register_escape_fn(never_escape);
-        TemplateRenderer(handlebars)
+        TemplateRenderer(handlebars, HashMap::new())
+    }
+
+    /// Makes `include` read partials from `config_root`, rather than from the `config/` directory
+    /// of the package being rendered.
+    pub fn include_from<P: Into<PathBuf>>(&mut self, config_root: P) {
+        self.0
+            .register_helper("include", Box::new(helpers::IncludeHelper::new(config_root)));
+    }
+
+    pub fn register_template_file<P>(&mut self,
+                                     name: &str,
+                                     path: P)
+                                     -> result::Result<(), TemplateFileError>
+        where P: AsRef<Path>
+    {
+        self.0.register_template_file(name, path.as_ref())?;
+        let mut content = String::new();
+        if File::open(path.as_ref())
+               .and_then(|mut file| file.read_to_string(&mut content))
+               .is_ok() {
+            self.1.insert(name.to_string(),
+                          TemplateSource {
+                              origin: path.as_ref().display().to_string(),
+                              content: content,
+                          });
+        }
+        Ok(())
+    }
+
+    pub fn register_template_string<S>(&mut self,
+                                       name: &str,
+                                       content: S)
+                                       -> result::Result<(), TemplateError>
+        where S: AsRef<str>
+    {
+        self.0.register_template_string(name, content.as_ref())?;
+        self.1.insert(name.to_string(),
+                      TemplateSource {
+                          origin: name.to_string(),
+                          content: content.as_ref().to_string(),
+                      });
+        Ok(())
     }
 
     pub fn render<T>(&self, template: &str, ctx: &T) -> Result<String>
@@ -53,9 +115,31 @@ impl TemplateRenderer {
     {
         let raw = serde_json::to_value(ctx)
             .map_err(|e| sup_error!(Error::RenderContextSerialization(e)))?;
-        debug!("Rendering template with context, {}, {}", template, raw);
+        self.render_json(template, &raw)
+    }
+
+    /// Renders a template like `render` does, but fails instead when the template refers to a
+    /// variable the context doesn't define.
+    pub fn render_strict<T>(&self, template: &str, ctx: &T) -> Result<String>
+        where T: Serialize
+    {
+        let raw = serde_json::to_value(ctx)
+            .map_err(|e| sup_error!(Error::RenderContextSerialization(e)))?;
+        if let Some(source) = self.1.get(template) {
+            let undefined = lint::undefined_variables(&source.content, &raw);
+            if !undefined.is_empty() {
+                return Err(sup_error!(Error::UndefinedTemplateVariables(source.origin.clone(),
+                                                                        undefined)));
+            }
+        }
+        self.render_json(template, &raw)
+    }
+
+    fn render_json(&self, template: &str, raw: &serde_json::Value) -> Result<String> {
+        // The context isn't logged as it holds the service's secrets.
+        debug!("Rendering template {}", template);
         self.0
-            .render(template, &raw)
+            .render(template, raw)
             .map_err(|e| sup_error!(Error::TemplateRenderError(e)))
     }
 }
@@ -88,10 +172,11 @@ fn never_escape(data: &str) -> String {
 #[cfg(test)]
 mod test {
     use std::fs::File;
-    use std::io::Read;
+    use std::io::{Read, Write};
     use std::path::PathBuf;
     use std::collections::BTreeMap;
     use serde_json;
+    use tempdir::TempDir;
     use toml;
 
     use super::*;
@@ -296,4 +381,275 @@ test: something"#
 
         assert_eq!(each_alive_render, each_if_render);
     }
+
+    #[test]
+    fn default_helper() {
+        let content = "{{default var \"fallback\"}} {{default empty 8080}} {{default missing 1}}"
+            .to_string();
+        let mut renderer = TemplateRenderer::new();
+        renderer.register_template_string("t", content).unwrap();
+
+        let mut m: BTreeMap<String, String> = BTreeMap::new();
+        m.insert("var".into(), "value".into());
+        m.insert("empty".into(), "".into());
+        let rendered = renderer.render("t", &m).unwrap();
+        assert_eq!(rendered, "value 8080 1".to_string());
+    }
+
+    #[test]
+    fn math_helper() {
+        let content = "{{math sys.cpus \"*\" 2}} {{math sys.gossip_port \"+\" 1}}".to_string();
+        let mut renderer = TemplateRenderer::new();
+        renderer.register_template_string("t", content).unwrap();
+
+        let data = service_config_json_from_toml_file("complex_config.toml");
+        let rendered = renderer.render("t", &data).unwrap();
+        assert_eq!(rendered, "8 9639".to_string());
+    }
+
+    #[test]
+    fn math_helper_errors() {
+        let mut renderer = TemplateRenderer::new();
+        renderer
+            .register_template_string("divide", "{{math 1 \"/\" 0}}".to_string())
+            .unwrap();
+        renderer
+            .register_template_string("operator", "{{math 1 \"^\" 2}}".to_string())
+            .unwrap();
+
+        let data = service_config_json_from_toml_file("complex_config.toml");
+        assert!(renderer.render("divide", &data).is_err());
+        assert!(renderer.render("operator", &data).is_err());
+    }
+
+    #[test]
+    fn join_helper() {
+        let content = "{{join svc.members \",\" \"sys.ip\"}}".to_string();
+        let mut renderer = TemplateRenderer::new();
+        renderer.register_template_string("t", content).unwrap();
+
+        let data = service_config_json_from_toml_file("multiple_supervisors_config.toml");
+        let rendered = renderer.render("t", &data).unwrap();
+        assert_eq!(rendered, "10.0.0.4,10.0.0.4,10.0.0.4".to_string());
+    }
+
+    #[test]
+    fn join_helper_scalars() {
+        let content = "{{join var \", \"}}".to_string();
+        let mut renderer = TemplateRenderer::new();
+        renderer.register_template_string("t", content).unwrap();
+
+        let mut m: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        m.insert("var".into(), vec!["a".into(), "b".into(), "c".into()]);
+        let rendered = renderer.render("t", &m).unwrap();
+        assert_eq!(rendered, "a, b, c".to_string());
+    }
+
+    #[test]
+    fn split_helper_block() {
+        let content = "{{#split var \",\" as |part|}}\
+                       {{@index}}={{part}}{{#unless @last}};{{/unless}}\
+                       {{/split}}"
+                .to_string();
+        let mut renderer = TemplateRenderer::new();
+        renderer.register_template_string("t", content).unwrap();
+
+        let mut m: BTreeMap<String, String> = BTreeMap::new();
+        m.insert("var".into(), "a,b,c".into());
+        let rendered = renderer.render("t", &m).unwrap();
+        assert_eq!(rendered, "0=a;1=b;2=c".to_string());
+    }
+
+    #[test]
+    fn split_helper_inline() {
+        let content = "{{split var \":\" 0}} {{split var \":\" -1}} [{{split var \":\" 5}}]"
+            .to_string();
+        let mut renderer = TemplateRenderer::new();
+        renderer.register_template_string("t", content).unwrap();
+
+        let mut m: BTreeMap<String, String> = BTreeMap::new();
+        m.insert("var".into(), "host:port:path".into());
+        let rendered = renderer.render("t", &m).unwrap();
+        assert_eq!(rendered, "host path []".to_string());
+    }
+
+    #[test]
+    fn base64_helpers() {
+        let content = "{{base64Encode var}} {{base64Decode encoded}}".to_string();
+        let mut renderer = TemplateRenderer::new();
+        renderer.register_template_string("t", content).unwrap();
+
+        let mut m: BTreeMap<String, String> = BTreeMap::new();
+        m.insert("var".into(), "user:secret".into());
+        m.insert("encoded".into(), "aGVsbG8gd29ybGQ=".into());
+        let rendered = renderer.render("t", &m).unwrap();
+        assert_eq!(rendered, "dXNlcjpzZWNyZXQ= hello world".to_string());
+    }
+
+    #[test]
+    fn include_helper() {
+        let content = "{{include \"partials/upstream.conf\"}}".to_string();
+        let mut renderer = TemplateRenderer::new();
+        renderer.register_template_string("t", content).unwrap();
+
+        let mut data = service_config_json_from_toml_file("complex_config.toml");
+        data["pkg"]["path"] =
+            serde_json::Value::String(fixtures().join("include").to_string_lossy().into_owned());
+        let rendered = renderer.render("t", &data).unwrap();
+        assert_eq!(rendered, "server ef8549a34328:9631\n".to_string());
+    }
+
+    #[test]
+    fn include_helper_outside_config_dir() {
+        let mut renderer = TemplateRenderer::new();
+        renderer
+            .register_template_string("parent", "{{include \"../plan.sh\"}}".to_string())
+            .unwrap();
+        renderer
+            .register_template_string("absolute", "{{include \"/etc/passwd\"}}".to_string())
+            .unwrap();
+
+        let data = service_config_json_from_toml_file("complex_config.toml");
+        assert!(renderer.render("parent", &data).is_err());
+        assert!(renderer.render("absolute", &data).is_err());
+    }
+
+    #[test]
+    fn include_helper_from_config_root() {
+        let content = "{{include \"partials/upstream.conf\"}}".to_string();
+        let mut renderer = TemplateRenderer::new();
+        renderer.include_from(fixtures().join("include").join("config"));
+        renderer.register_template_string("t", content).unwrap();
+
+        // The package path is ignored once a config root is set.
+        let mut data = service_config_json_from_toml_file("complex_config.toml");
+        data["pkg"]["path"] = serde_json::Value::String("/nonexistent".to_string());
+        let rendered = renderer.render("t", &data).unwrap();
+        assert_eq!(rendered, "server ef8549a34328:9631\n".to_string());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn include_helper_symlink_out_of_config_dir() {
+        use std::os::unix::fs::symlink;
+
+        let tmp = TempDir::new("include").unwrap();
+        let config = tmp.path().join("config");
+        ::std::fs::create_dir(&config).unwrap();
+        symlink(fixtures().join("include").join("config").join("partials"),
+                config.join("partials"))
+            .unwrap();
+        let mut renderer = TemplateRenderer::new();
+        renderer.include_from(&config);
+        renderer
+            .register_template_string("t", "{{include \"partials/upstream.conf\"}}".to_string())
+            .unwrap();
+
+        let data = service_config_json_from_toml_file("complex_config.toml");
+        assert!(renderer.render("t", &data).is_err());
+    }
+
+    /// Returns a renderer including partials from a new config directory holding the given ones.
+    fn renderer_with_partials(partials: &[(&str, &str)]) -> (TemplateRenderer, TempDir) {
+        let tmp = TempDir::new("include").unwrap();
+        for &(name, content) in partials {
+            File::create(tmp.path().join(name))
+                .and_then(|mut file| file.write_all(content.as_bytes()))
+                .unwrap();
+        }
+        let mut renderer = TemplateRenderer::new();
+        renderer.include_from(tmp.path());
+        (renderer, tmp)
+    }
+
+    #[test]
+    fn include_helper_self_include() {
+        let (mut renderer, _tmp) = renderer_with_partials(&[("self.conf",
+                                                             "{{include \"self.conf\"}}")]);
+        renderer
+            .register_template_string("t", "{{include \"self.conf\"}}".to_string())
+            .unwrap();
+
+        let data = service_config_json_from_toml_file("complex_config.toml");
+        assert!(renderer.render("t", &data).is_err());
+    }
+
+    #[test]
+    fn include_helper_mutual_include() {
+        let (mut renderer, _tmp) = renderer_with_partials(&[("a.conf", "{{include \"b.conf\"}}"),
+                                                            ("b.conf", "{{include \"a.conf\"}}")]);
+        renderer
+            .register_template_string("t", "{{include \"a.conf\"}}".to_string())
+            .unwrap();
+
+        let data = service_config_json_from_toml_file("complex_config.toml");
+        assert!(renderer.render("t", &data).is_err());
+    }
+
+    #[test]
+    fn include_helper_nested_too_deep() {
+        let partials: Vec<(String, String)> = (0..10)
+            .map(|i| (format!("{}.conf", i), format!("{{{{include \"{}.conf\"}}}}", i + 1)))
+            .collect();
+        let mut partials: Vec<(&str, &str)> = partials
+            .iter()
+            .map(|&(ref name, ref content)| (name.as_str(), content.as_str()))
+            .collect();
+        partials.push(("10.conf", "deep"));
+        let (mut renderer, _tmp) = renderer_with_partials(&partials);
+        renderer
+            .register_template_string("shallow", "{{include \"3.conf\"}}".to_string())
+            .unwrap();
+        renderer
+            .register_template_string("deep", "{{include \"0.conf\"}}".to_string())
+            .unwrap();
+
+        let data = service_config_json_from_toml_file("complex_config.toml");
+        assert_eq!(renderer.render("shallow", &data).unwrap(), "deep");
+        assert!(renderer.render("deep", &data).is_err());
+        // A failed render leaves nothing behind in the include chain.
+        assert_eq!(renderer.render("shallow", &data).unwrap(), "deep");
+    }
+
+    #[test]
+    fn render_strict_with_defined_variables() {
+        let content = "port={{cfg.port}}".to_string();
+        let mut renderer = TemplateRenderer::new();
+        renderer.register_template_string("t", content).unwrap();
+
+        let mut cfg: BTreeMap<String, String> = BTreeMap::new();
+        cfg.insert("port".into(), "6379".into());
+        let mut m: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+        m.insert("cfg".into(), cfg);
+        let rendered = renderer.render_strict("t", &m).unwrap();
+        assert_eq!(rendered, "port=6379".to_string());
+    }
+
+    #[test]
+    fn render_strict_with_undefined_variables() {
+        let mut renderer = TemplateRenderer::new();
+        renderer
+            .register_template_file("each_alive", templates().join("each_alive.txt"))
+            .unwrap();
+        renderer
+            .register_template_string("t", "host={{cfg.databse.host}}".to_string())
+            .unwrap();
+
+        let data = service_config_json_from_toml_file("multiple_supervisors_config.toml");
+        assert!(renderer.render_strict("each_alive", &data).is_ok());
+        assert_eq!(renderer.render("t", &data).unwrap(), "host=".to_string());
+        match renderer.render_strict("t", &data) {
+            Err(e) => {
+                match e.err {
+                    Error::UndefinedTemplateVariables(template, variables) => {
+                        assert_eq!(template, "t");
+                        assert_eq!(variables.len(), 1);
+                        assert_eq!(variables[0].path, "cfg.databse.host");
+                    }
+                    wrong => panic!("Unexpected error returned: {:?}", wrong),
+                }
+            }
+            Ok(_) => panic!("Undefined variables should fail to render"),
+        }
+    }
 }
@@ -80,7 +80,9 @@ pub fn install_from_spec(ui: &mut UI, spec: &ServiceSpec) -> Result<PackageInsta
         Ok(package) => {
             match spec.update_strategy {
                 UpdateStrategy::AtOnce => Ok(maybe_install_newer(ui, spec, package)?),
-                UpdateStrategy::None | UpdateStrategy::Rolling => Ok(package),
+                UpdateStrategy::None |
+                UpdateStrategy::Rolling |
+                UpdateStrategy::Canary => Ok(package),
             }
         }
         Err(_) => {